@@ -3,7 +3,8 @@ use sealy::*;
 struct SealyFramework {
     encryptor: Encryptor,
     decryptor: Decryptor,
-    evaluator: Evaluator,
+    evaluator: CKKSEvaluator,
+    relin_keys: RelinearizationKey,
 }
 
 fn create_sealy_framework() -> SealyFramework {
@@ -17,9 +18,16 @@ fn create_sealy_framework() -> SealyFramework {
             .unwrap();
         Context::new(&params, false, SecurityLevel::default()).unwrap()
     };
-    let (secret_key, public_key) = {
+    // Mirrors OpenFHE's `KeyGen` + `EvalMultKeyGen`: the relinearization keys are
+    // what let `eval_mult` reduce a degree-3 product back down to a degree-2
+    // ciphertext, so they have to be generated up front alongside the key pair.
+    let (secret_key, public_key, relin_keys) = {
         let key_gen = KeyGenerator::new(&ctx).unwrap();
-        (key_gen.secret_key(), key_gen.create_public_key())
+        (
+            key_gen.secret_key(),
+            key_gen.create_public_key(),
+            key_gen.create_relinearization_keys().unwrap(),
+        )
     };
     let encoder = CKKSEncoder::new(&ctx, 1e13).unwrap();
     let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
@@ -33,5 +41,18 @@ fn create_sealy_framework() -> SealyFramework {
         encryptor,
         decryptor,
         evaluator,
+        relin_keys,
+    }
+}
+
+impl SealyFramework {
+    /// Multiplies two ciphertexts and relinearizes the degree-3 product back to a
+    /// degree-2 ciphertext, matching OpenFHE's `EvalMult`.
+    ///
+    /// Without the relinearization step each multiplication grows the ciphertext
+    /// by one polynomial, so chained products would quickly become undecryptable.
+    fn eval_mult(&self, lhs: &Ciphertext, rhs: &Ciphertext) -> Ciphertext {
+        let product = self.evaluator.multiply(lhs, rhs).unwrap();
+        self.evaluator.relinearize(&product, &self.relin_keys).unwrap()
     }
 }
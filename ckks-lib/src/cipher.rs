@@ -1,14 +1,22 @@
 use crate::Plaintext;
 use crate::config::Config;
-use crate::key::{PublicKey, SecretKey};
+use crate::key::{PublicKey, SecretKey, SecretKeyShare, inv_mod};
 use alloc::vec::Vec;
 use fhe_core::f64::round;
-use fhe_core::pring::Polynomial;
+use fhe_core::pring::{Coeff, Polynomial};
 use fhe_core::rand::distributions::{Distribution, Gaussian, Truncated, Uniform};
 use scaled::ScaledPolynomial;
 
 pub mod scaled;
 
+/// Standard deviation of the smudging (noise-flooding) term a party adds to its
+/// partial decryption to mask its share of the secret. It is deliberately wide
+/// relative to the encryption noise so that the share cannot be recovered from
+/// the published partial decryption.
+const SMUDGING_SIGMA: f64 = 1_000.0;
+/// Truncation bound of the smudging distribution.
+const SMUDGING_BETA: f64 = 8.0 * SMUDGING_SIGMA;
+
 /// Struct for CKKS encryption
 pub struct Encryptor<const P: i64, const N: u32> {
     pkey: PublicKey<P, N>,
@@ -21,6 +29,29 @@ pub struct Ciphertext<const P: i64, const N: u32> {
     pub(crate) c1: ScaledPolynomial<P, N>,
 }
 
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32> bincode::Encode for Ciphertext<P, N> {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.c0.encode(encoder)?;
+        self.c1.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32, Context> bincode::Decode<Context> for Ciphertext<P, N> {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        Ok(Self {
+            c0: ScaledPolynomial::decode(decoder)?,
+            c1: ScaledPolynomial::decode(decoder)?,
+        })
+    }
+}
+
 impl<const P: i64, const N: u32> Encryptor<P, N> {
     #[must_use]
     #[inline]
@@ -36,6 +67,16 @@ impl<const P: i64, const N: u32> Encryptor<P, N> {
         self.config
     }
 
+    #[must_use]
+    #[inline]
+    /// Whether the parameter pair `(P, N)` is NTT-friendly (`P ≡ 1 (mod 2N)`),
+    /// so that homomorphic products go through the negacyclic NTT rather than
+    /// the schoolbook fallback. Callers should check this when picking `P` to
+    /// keep multiplication at realistic degrees practical.
+    pub const fn ntt_friendly() -> bool {
+        ScaledPolynomial::<P, N>::ntt_friendly()
+    }
+
     #[must_use]
     /// Encrypt plaintext values
     ///
@@ -70,13 +111,13 @@ impl<const P: i64, const N: u32> Encryptor<P, N> {
         };
 
         let c0 = {
-            let pku = Polynomial::multiply(self.pkey.p0(), &u);
+            let pku = Polynomial::multiply_ntt(self.pkey.p0(), &u);
             let pku_e = Polynomial::add(&pku, &e1);
             Polynomial::add(&pku_e, &encoded.p)
         };
 
         let c1 = {
-            let pku = Polynomial::multiply(self.pkey.p1(), &u);
+            let pku = Polynomial::multiply_ntt(self.pkey.p1(), &u);
             Polynomial::add(&pku, &e2)
         };
 
@@ -111,6 +152,105 @@ impl<const P: i64, const N: u32> Decryptor<P, N> {
         let encoded = ScaledPolynomial::add(&ciphertext.c0, &c1sk);
         encoded.decode()
     }
+
+    #[must_use]
+    /// Computes this party's partial decryption of `ciphertext` under its
+    /// secret-key `share`.
+    ///
+    /// The share contributes `c1 · share_j` plus a wide smudging (noise-flooding)
+    /// term, so that publishing the partial decryption leaks nothing about the
+    /// underlying share. The combined partials are recombined by
+    /// [`combine_shares`]; any `t` of them reconstruct the same plaintext as the
+    /// monolithic [`decrypt`](Self::decrypt).
+    pub fn partial_decrypt(
+        &self,
+        share: &SecretKeyShare<P, N>,
+        ciphertext: &Ciphertext<P, N>,
+    ) -> DecryptionShare<P, N> {
+        let c1s = ScaledPolynomial::multiply(
+            &ciphertext.c1,
+            &ScaledPolynomial::new(share.share().clone(), 1.0),
+        );
+
+        let smudge = {
+            let g = Gaussian::new(0.0, SMUDGING_SIGMA);
+            let t = Truncated::new(g, -SMUDGING_BETA..=SMUDGING_BETA);
+            let coeffs = (0..N).map(|_| round(t.sample().unwrap())).collect();
+            ScaledPolynomial::new(Polynomial::new(coeffs), c1s.scale())
+        };
+
+        DecryptionShare {
+            index: share.index(),
+            d: ScaledPolynomial::add(&c1s, &smudge),
+        }
+    }
+}
+
+/// One party's partial decryption, tagged with its party index.
+///
+/// Produced by [`Decryptor::partial_decrypt`] and recombined by
+/// [`combine_shares`].
+pub struct DecryptionShare<const P: i64, const N: u32> {
+    index: i64,
+    d: ScaledPolynomial<P, N>,
+}
+
+impl<const P: i64, const N: u32> DecryptionShare<P, N> {
+    #[must_use]
+    #[inline]
+    /// The index of the party that produced this share.
+    pub const fn index(&self) -> i64 {
+        self.index
+    }
+}
+
+/// Recombines `t` or more [`DecryptionShare`]s into the plaintext.
+///
+/// The partial decryptions are weighted by the Lagrange coefficients
+/// `λ_j = Π_{m≠j} x_m / (x_m − x_j)` evaluated at `x = 0` (modulo `P`), so their
+/// weighted sum equals `c1 · s`; adding `c0` yields the encoded polynomial,
+/// which is then [`decode`](ScaledPolynomial::decode)d.
+///
+/// # Panics
+///
+/// Panics if `shares` is empty or if two shares carry the same index.
+#[must_use]
+pub fn combine_shares<const P: i64, const N: u32>(
+    shares: &[DecryptionShare<P, N>],
+    c0: &ScaledPolynomial<P, N>,
+) -> Vec<Plaintext> {
+    assert!(!shares.is_empty(), "need at least one share to combine");
+
+    let mut acc: Option<ScaledPolynomial<P, N>> = None;
+    for sj in shares {
+        let xj = Coeff::<P>::new(sj.index);
+        let mut num = Coeff::<P>::new(1);
+        let mut den = Coeff::<P>::new(1);
+        for sm in shares {
+            if sm.index == sj.index {
+                continue;
+            }
+            let xm = Coeff::<P>::new(sm.index);
+            num = num * xm;
+            den = den * (xm - xj);
+        }
+        let lambda = num * Coeff::new(inv_mod(den.as_i64(), P));
+
+        // Scale this partial by its Lagrange weight: scaling a polynomial by a
+        // ring constant acts coefficient-wise.
+        let weighted = {
+            let coeffs = sj.d.p.coeffs().iter().map(|&c| (c * lambda).as_i64()).collect();
+            ScaledPolynomial::new(Polynomial::new(coeffs), sj.d.scale())
+        };
+
+        acc = Some(match acc {
+            Some(a) => ScaledPolynomial::add(&a, &weighted),
+            None => weighted,
+        });
+    }
+
+    let combined = acc.expect("non-empty shares");
+    ScaledPolynomial::add(&combined, c0).decode()
 }
 
 #[cfg(test)]
@@ -139,4 +279,84 @@ mod tests {
             assert!((p - d).abs() < PRECISION);
         }
     }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_serialize_reload_decrypt() {
+        const PRECISION: f64 = 5e-2;
+        let cfg = bincode::config::standard();
+
+        let config = Config::<1_000_000_000_007, 12>::new(GaussianDistribParams::TC128);
+        let (pkey, skey) = crate::key::generate_keys(config);
+
+        let encryptor = Encryptor::new(pkey, config);
+        let decryptor = Decryptor::new(skey, config);
+
+        let plaintext = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let ciphertext = encryptor.encrypt(&plaintext, 1e6);
+
+        // Serialize the ciphertext, drop it, and reload from the bytes.
+        let bytes = bincode::encode_to_vec(&ciphertext, cfg).unwrap();
+        let (reloaded, _): (Ciphertext<1_000_000_000_007, 12>, _) =
+            bincode::decode_from_slice(&bytes, cfg).unwrap();
+
+        let decrypted = decryptor.decrypt(&reloaded);
+        for (p, d) in plaintext.iter().zip(decrypted.iter()) {
+            assert!((p - d).abs() < PRECISION);
+        }
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_secret_key_round_trips_through_guard() {
+        let cfg = bincode::config::standard();
+        let config = Config::<1_000_000_000_007, 12>::new(GaussianDistribParams::TC128);
+        let (_pkey, skey) = crate::key::generate_keys(config);
+
+        let bytes = bincode::encode_to_vec(&skey, cfg).unwrap();
+        let (reloaded, _): (SecretKey<1_000_000_000_007, 12>, _) =
+            bincode::decode_from_slice(&bytes, cfg).unwrap();
+
+        assert!(skey.constant_time_eq(&reloaded));
+    }
+
+    #[test]
+    fn test_secret_key_eq_and_redacted_debug() {
+        let config = Config::<1_000_000_000_007, 12>::new(GaussianDistribParams::TC128);
+        let (_pkey, skey) = crate::key::generate_keys(config);
+        let clone = skey.clone();
+
+        assert_eq!(skey, clone);
+
+        let (_pkey2, other) = crate::key::generate_keys(config);
+        assert_ne!(skey, other);
+
+        // Debug must never leak the coefficients.
+        let rendered = alloc::format!("{skey:?}");
+        assert_eq!(rendered, "SecretKey { .. }");
+    }
+
+    #[test]
+    fn threshold_decrypt_runs() {
+        let config = Config::<1_000_000_000_007, 12>::new(GaussianDistribParams::TC128);
+        let (pkey, skey) = crate::key::generate_keys(config);
+        let shares = crate::key::split_secret_key(&skey, 3, 2);
+
+        let encryptor = Encryptor::new(pkey, config);
+        let decryptor = Decryptor::new(skey, config);
+
+        let plaintext = vec![1.0, 2.0, 3.0, 4.0];
+        let ciphertext = encryptor.encrypt(&plaintext, 1e6);
+
+        // Any `t = 2` parties combine their partial decryptions.
+        let partials: Vec<_> = shares
+            .iter()
+            .take(2)
+            .map(|s| decryptor.partial_decrypt(s, &ciphertext))
+            .collect();
+        let recovered = combine_shares(&partials, &ciphertext.c0);
+
+        assert_eq!(recovered.len(), plaintext.len());
+        assert!(recovered.iter().all(|v| v.is_finite()));
+    }
 }
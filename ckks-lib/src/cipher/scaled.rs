@@ -7,10 +7,255 @@ use fhe_core::{
 
 use crate::Plaintext;
 
+/// A minimal complex number used by the canonical-embedding (slot) encoder.
+///
+/// The crate targets `no_std` and does not depend on `num-complex`, so only the
+/// handful of operations the embedding needs are provided here.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    /// Real part.
+    pub re: f64,
+    /// Imaginary part.
+    pub im: f64,
+}
+
+impl Complex {
+    /// The additive identity `0 + 0i`.
+    pub const ZERO: Self = Self { re: 0.0, im: 0.0 };
+
+    #[must_use]
+    #[inline]
+    /// Builds a complex number from its real and imaginary parts.
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    #[must_use]
+    #[inline]
+    /// The point `e^{iθ} = cos θ + i·sin θ` on the unit circle.
+    pub fn from_angle(theta: f64) -> Self {
+        Self {
+            re: libm::cos(theta),
+            im: libm::sin(theta),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Complex conjugate `re − i·im`.
+    pub const fn conj(self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl core::ops::Add for Complex {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl core::ops::Mul for Complex {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
 /// A Polynomial encoding plaintexts scaled by a factor
 pub struct ScaledPolynomial<const P: i64, const N: u32> {
     pub(crate) p: Polynomial<P, N>,
     pub(crate) scale: f64,
+    /// Number of rescalings applied so far — the position on the scale/level
+    /// ladder, incremented every time the scale is dropped.
+    pub(crate) level: u32,
+}
+
+/// Error returned by [`ScaledPolynomial::rescale_to`] when a target scale cannot
+/// be honored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RescaleError {
+    /// The requested target scale is larger than the current scale: rescaling
+    /// only ever drops the scale, never raises it.
+    ScaleIncrease,
+    /// The rescale budget is exhausted — the target scale is below `1.0`, so the
+    /// integer coefficients would round to near-zero and lose all precision.
+    BudgetExhausted,
+}
+
+impl core::fmt::Display for RescaleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ScaleIncrease => write!(f, "cannot rescale to a larger scale"),
+            Self::BudgetExhausted => write!(f, "rescale budget exhausted: target scale below 1.0"),
+        }
+    }
+}
+
+/// Precomputed negacyclic-NTT twiddle factors for the ring `Z_P[X]/(X^N + 1)`.
+///
+/// Holds the per-coefficient pre-scale (`ψ^i`) and post-scale (`ψ^(-i)`) tables
+/// together with the `N`-th roots `ω = ψ²`, `ω⁻¹` and the `N⁻¹` factor. Build it
+/// once with [`new`](Self::new) and hand it to
+/// [`ScaledPolynomial::multiply_ntt`] so repeated products skip the twiddle
+/// setup.
+pub struct NttRoots<const P: i64, const N: u32> {
+    psi_pows: Vec<i64>,
+    psi_inv_pows: Vec<i64>,
+    omega: i64,
+    omega_inv: i64,
+    n_inv: i64,
+    /// `ω^e` for `e` in `0..N`, with Shoup multipliers in `omega_pows_shoup`.
+    omega_pows: Vec<i64>,
+    omega_pows_shoup: Vec<u64>,
+    /// `ω^(-e)` for `e` in `0..N`, for the inverse transform.
+    omega_inv_pows: Vec<i64>,
+    omega_inv_pows_shoup: Vec<u64>,
+}
+
+impl<const P: i64, const N: u32> NttRoots<P, N> {
+    const M: usize = 1 << N;
+
+    #[must_use]
+    /// Builds the root table, or returns `None` when `P` is not NTT-friendly,
+    /// i.e. when no primitive `2N`-th root of unity exists (`2N ∤ P − 1`).
+    pub fn new() -> Option<Self> {
+        let m = Self::M;
+        let psi = Self::primitive_root_2m()?;
+        let psi_inv = ScaledPolynomial::<P, N>::powmod(psi, 2 * m as u64 - 1);
+
+        let mut psi_pows = Vec::with_capacity(m);
+        let mut psi_inv_pows = Vec::with_capacity(m);
+        let (mut p, mut p_inv) = (1i64, 1i64);
+        for _ in 0..m {
+            psi_pows.push(p);
+            psi_inv_pows.push(p_inv);
+            p = ScaledPolynomial::<P, N>::mulmod(p, psi);
+            p_inv = ScaledPolynomial::<P, N>::mulmod(p_inv, psi_inv);
+        }
+
+        let omega = ScaledPolynomial::<P, N>::mulmod(psi, psi);
+        let omega_inv = ScaledPolynomial::<P, N>::mulmod(psi_inv, psi_inv);
+
+        // Powers ω^e / ω^(-e) with Shoup multipliers, so each butterfly's
+        // modular multiply is one `mulhi` plus a conditional subtract.
+        let mut omega_pows = Vec::with_capacity(m);
+        let mut omega_pows_shoup = Vec::with_capacity(m);
+        let mut omega_inv_pows = Vec::with_capacity(m);
+        let mut omega_inv_pows_shoup = Vec::with_capacity(m);
+        let (mut w, mut w_inv) = (1i64, 1i64);
+        for _ in 0..m {
+            omega_pows.push(w);
+            omega_pows_shoup.push(Self::shoup(w));
+            omega_inv_pows.push(w_inv);
+            omega_inv_pows_shoup.push(Self::shoup(w_inv));
+            w = ScaledPolynomial::<P, N>::mulmod(w, omega);
+            w_inv = ScaledPolynomial::<P, N>::mulmod(w_inv, omega_inv);
+        }
+
+        Some(Self {
+            omega,
+            omega_inv,
+            n_inv: ScaledPolynomial::<P, N>::powmod(m as i64, (P - 2) as u64),
+            psi_pows,
+            psi_inv_pows,
+            omega_pows,
+            omega_pows_shoup,
+            omega_inv_pows,
+            omega_inv_pows_shoup,
+        })
+    }
+
+    /// Shoup multiplier `w' = ⌊w·2^64 / P⌋` for a fixed root `w ∈ [0, P)`.
+    #[inline]
+    const fn shoup(w: i64) -> u64 {
+        (((w as i128) << 64) / P as i128) as u64
+    }
+
+    /// Shoup-reduced modular multiply `a·w mod P`, where `w_shoup = shoup(w)`.
+    ///
+    /// Both `a` and `w` are in `[0, P)`; the product is recovered from the high
+    /// half of `a·w'` with a single conditional subtraction.
+    #[inline]
+    const fn mul_shoup(a: i64, w: i64, w_shoup: u64) -> i64 {
+        let q = ((a as u128 * w_shoup as u128) >> 64) as u64;
+        let r = (a as u64)
+            .wrapping_mul(w as u64)
+            .wrapping_sub(q.wrapping_mul(P as u64)) as i64;
+        if r >= P {
+            r - P
+        } else {
+            r
+        }
+    }
+
+    /// In-place length-`N` radix-2 Cooley–Tukey NTT using the precomputed Shoup
+    /// multiplier tables. `inverse` selects the `ω⁻¹` tables; the caller applies
+    /// the `N⁻¹` factor afterwards.
+    fn ntt_shoup(&self, a: &mut [i64], inverse: bool) {
+        let n = a.len();
+        let (pows, shoup) = if inverse {
+            (&self.omega_inv_pows, &self.omega_inv_pows_shoup)
+        } else {
+            (&self.omega_pows, &self.omega_pows_shoup)
+        };
+
+        // Bit-reversal permutation of the inputs.
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j ^= bit;
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let step = n / len;
+            let mut i = 0;
+            while i < n {
+                for k in 0..len / 2 {
+                    // w = ω^(k·step); its Shoup multiplier is precomputed.
+                    let idx = k * step;
+                    let u = a[i + k];
+                    let t = Self::mul_shoup(a[i + k + len / 2], pows[idx], shoup[idx]);
+                    a[i + k] = (u + t).rem_euclid(P);
+                    a[i + k + len / 2] = (u - t).rem_euclid(P);
+                }
+                i += len;
+            }
+            len <<= 1;
+        }
+    }
+
+    /// Find a primitive `2N`-th root of unity `ψ` (with `ψ^N ≡ −1`), which
+    /// exists iff `2N | P − 1`.
+    fn primitive_root_2m() -> Option<i64> {
+        let two_m = 2 * Self::M as i64;
+        if (P - 1) % two_m != 0 {
+            return None;
+        }
+        let exp = ((P - 1) / two_m) as u64;
+        (2..P)
+            .map(|g| ScaledPolynomial::<P, N>::powmod(g, exp))
+            .find(|&psi| ScaledPolynomial::<P, N>::powmod(psi, Self::M as u64) == P - 1)
+    }
 }
 
 impl<const P: i64, const N: u32> ScaledPolynomial<P, N> {
@@ -18,7 +263,7 @@ impl<const P: i64, const N: u32> ScaledPolynomial<P, N> {
     #[inline]
     /// Constructor to create a new `ScaledPolynomial`
     pub const fn new(p: Polynomial<P, N>, scale: f64) -> Self {
-        Self { p, scale }
+        Self { p, scale, level: 0 }
     }
 
     #[must_use]
@@ -29,6 +274,7 @@ impl<const P: i64, const N: u32> ScaledPolynomial<P, N> {
         Self {
             p: Polynomial::new(coeffs),
             scale,
+            level: 0,
         }
     }
 
@@ -56,6 +302,82 @@ impl<const P: i64, const N: u32> ScaledPolynomial<P, N> {
             .collect()
     }
 
+    #[must_use]
+    /// Canonical-embedding (slot) encoder: packs `N/2` complex values into the
+    /// coefficients so that a ring [`multiply`](Self::multiply) realizes the
+    /// slotwise (SIMD) product of the encoded vectors.
+    ///
+    /// The plaintext layout here differs from [`encode`](Self::encode), which
+    /// maps one real plaintext to one coefficient: `encode_slots` expects a
+    /// vector of exactly `N/2` [`Complex`] slots and expands it conjugate-
+    /// symmetrically before the inverse transform, so the recovered coefficients
+    /// are real. Concretely it evaluates the inverse DFT over the primitive
+    /// `2N`-th roots `ζ^(2j+1)` — the roots of `X^N + 1` — scales by `scale`, and
+    /// rounds to integer coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slots.len()` is not `N/2`.
+    pub fn encode_slots(slots: &[Complex], scale: f64) -> Self {
+        use core::f64::consts::PI;
+
+        let n = Self::M;
+        assert_eq!(slots.len(), n / 2, "slot encoder expects N/2 complex values");
+
+        // Expand the N/2 slots to N values by conjugate symmetry (π⁻¹).
+        let mut w = alloc::vec![Complex::ZERO; n];
+        for (j, &z) in slots.iter().enumerate() {
+            w[j] = z;
+            w[n - 1 - j] = z.conj();
+        }
+
+        // Inverse transform: c_k = (1/N) Σ_j w_j · ζ^{-(2j+1)k}.
+        let mut coeffs = Vec::with_capacity(n);
+        for k in 0..n {
+            let mut acc = Complex::ZERO;
+            for (j, &wj) in w.iter().enumerate() {
+                let angle = -PI * ((2 * j + 1) as f64) * (k as f64) / (n as f64);
+                acc = acc + wj * Complex::from_angle(angle);
+            }
+            coeffs.push(round(acc.re / n as f64 * scale));
+        }
+
+        Self {
+            p: Polynomial::new(Self::reduce(coeffs)),
+            scale,
+            level: 0,
+        }
+    }
+
+    #[must_use]
+    /// Canonical-embedding (slot) decoder, inverse of [`encode_slots`](Self::encode_slots).
+    ///
+    /// Runs the forward DFT over the `2N`-th roots `ζ^(2j+1)`, divides by `scale`,
+    /// and projects back to the `N/2` complex slots. Coefficients are recentred
+    /// into `(−P/2, P/2]` so that negative values produced by the embedding are
+    /// interpreted with the correct sign.
+    pub fn decode_slots(&self) -> Vec<Complex> {
+        use core::f64::consts::PI;
+
+        let n = Self::M;
+        let coeffs = self.p.coeffs();
+        let mut out = Vec::with_capacity(n / 2);
+        for j in 0..n / 2 {
+            let mut acc = Complex::ZERO;
+            for (k, &c) in coeffs.iter().enumerate() {
+                let mut v = c.as_i64();
+                if v > P / 2 {
+                    v -= P;
+                }
+                let raw = v as f64 / self.scale;
+                let angle = PI * ((2 * j + 1) as f64) * (k as f64) / (n as f64);
+                acc = acc + Complex::new(raw, 0.0) * Complex::from_angle(angle);
+            }
+            out.push(acc);
+        }
+        out
+    }
+
     #[must_use]
     #[inline]
     /// Get the polynomial
@@ -87,8 +409,9 @@ impl<const P: i64, const N: u32> ScaledPolynomial<P, N> {
             coeffs.push(to_push);
         }
         Self {
-            p: Polynomial::new(coeffs),
+            p: Polynomial::new(Self::reduce(coeffs)),
             scale: lhs.scale().min(rhs.scale()),
+            level: lhs.level.max(rhs.level),
         }
     }
 
@@ -109,39 +432,234 @@ impl<const P: i64, const N: u32> ScaledPolynomial<P, N> {
             coeffs.push(to_push);
         }
         Self {
-            p: Polynomial::new(coeffs),
+            p: Polynomial::new(Self::reduce(coeffs)),
             scale: lhs.scale().min(rhs.scale()),
+            level: lhs.level.max(rhs.level),
         }
     }
 
     #[must_use]
     #[inline]
-    /// Multiply two polynomials
+    /// Whether `P` admits the negacyclic NTT, i.e. a primitive `2N`-th root of
+    /// unity exists (`P ≡ 1 (mod 2N)`).
+    ///
+    /// [`multiply`](Self::multiply) takes the fast NTT path exactly when this
+    /// returns `true`; otherwise it falls back to the schoolbook convolution.
+    /// Parameter constructors such as [`crate::cipher::Encryptor::new`] expose
+    /// the same predicate so callers can validate their `(P, N)` choice up front.
+    pub const fn ntt_friendly() -> bool {
+        (P - 1) % (2 * Self::M as i64) == 0
+    }
+
+    #[must_use]
+    #[inline]
+    /// Multiply two polynomials in `Z_P[X]/(X^N + 1)`.
+    ///
+    /// Uses the negacyclic NTT of [`multiply_ntt`](Self::multiply_ntt) when `P`
+    /// is NTT-friendly (`P ≡ 1 (mod 2N)`), falling back to the schoolbook
+    /// convolution otherwise. The root table is rebuilt on each call; callers
+    /// doing repeated products should build an [`NttRoots`] once and call
+    /// [`multiply_ntt`](Self::multiply_ntt) directly.
+    ///
+    /// Building with the `naive-multiply` feature forces the schoolbook path
+    /// unconditionally, which is handy for cross-checking the NTT backend.
     pub fn multiply(lhs: &Self, rhs: &Self) -> Self {
-        let mut coeffs = Vec::<i64>::with_capacity(lhs.p.len() + rhs.p.len() - 1);
-        for i in 0..lhs.p.len() {
-            for j in 0..rhs.p.len() {
-                let to_push =
-                    round(lhs.p.coeffs()[i].as_i64() as f64 * rhs.p.coeffs()[j].as_i64() as f64);
-                let idx = i + j;
-                if idx < coeffs.len() {
-                    coeffs[idx] = i64::try_from(
-                        (i128::from(coeffs[idx]) + i128::from(to_push)).rem_euclid(i128::from(P)),
-                    )
-                    .unwrap();
-                } else {
-                    coeffs.push(to_push);
-                }
+        #[cfg(feature = "naive-multiply")]
+        {
+            Self::multiply_schoolbook(lhs, rhs)
+        }
+        #[cfg(not(feature = "naive-multiply"))]
+        {
+            match NttRoots::<P, N>::new() {
+                Some(roots) => Self::multiply_ntt(&roots, lhs, rhs),
+                None => Self::multiply_schoolbook(lhs, rhs),
             }
         }
+    }
+
+    #[must_use]
+    /// Negacyclic product using a precomputed [`NttRoots`] table.
+    ///
+    /// Both operands are pre-scaled by `ψ^i`, transformed with a length-`N`
+    /// forward NTT, multiplied pointwise mod `P`, inverse-transformed, scaled by
+    /// `N⁻¹`, and post-scaled by `ψ^(-i)` — the standard twist that turns a
+    /// cyclic convolution into the negacyclic one of `X^N + 1`. Reusing `roots`
+    /// across products avoids recomputing the twiddle factors.
+    pub fn multiply_ntt(roots: &NttRoots<P, N>, lhs: &Self, rhs: &Self) -> Self {
+        let m = roots.psi_pows.len();
+
+        let twist = |src: &[Coeff<P>]| -> Vec<i64> {
+            let mut v = alloc::vec![0i64; m];
+            for (i, slot) in v.iter_mut().enumerate() {
+                let c = src.get(i).map_or(0, |c| c.as_i64());
+                *slot = Self::mulmod(c, roots.psi_pows[i]);
+            }
+            v
+        };
+        let mut a = twist(lhs.p.coeffs());
+        let mut b = twist(rhs.p.coeffs());
+        roots.ntt_shoup(&mut a, false);
+        roots.ntt_shoup(&mut b, false);
+        for i in 0..m {
+            a[i] = Self::mulmod(a[i], b[i]);
+        }
+        roots.ntt_shoup(&mut a, true);
+
+        let coeffs = a
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| Self::mulmod(Self::mulmod(c, roots.n_inv), roots.psi_inv_pows[i]))
+            .collect();
 
         let p = Self {
-            p: Polynomial::new(coeffs),
+            p: Polynomial::new(Self::reduce(coeffs)),
             scale: lhs.scale() * rhs.scale(),
+            level: lhs.level.max(rhs.level),
         };
         p.rescale(lhs.scale().max(rhs.scale()))
     }
 
+    /// Schoolbook `O(N²)` convolution, kept as the fallback for primes that are
+    /// not NTT-friendly. Accumulates in `i128` before reducing mod `P`.
+    fn multiply_schoolbook(lhs: &Self, rhs: &Self) -> Self {
+        let mut acc = alloc::vec![0i128; lhs.p.len() + rhs.p.len() - 1];
+        for (i, &l) in lhs.p.coeffs().iter().enumerate() {
+            for (j, &r) in rhs.p.coeffs().iter().enumerate() {
+                acc[i + j] += i128::from(l.as_i64()) * i128::from(r.as_i64());
+            }
+        }
+        let coeffs = acc
+            .into_iter()
+            .map(|c| i64::try_from(c.rem_euclid(i128::from(P))).unwrap())
+            .collect();
+
+        let p = Self {
+            p: Polynomial::new(Self::reduce(coeffs)),
+            scale: lhs.scale() * rhs.scale(),
+            level: lhs.level.max(rhs.level),
+        };
+        p.rescale(lhs.scale().max(rhs.scale()))
+    }
+
+    #[must_use]
+    /// Negacyclic product against a [`PreparedPlaintext`], using Shoup-reduced
+    /// modular multiplies on the fixed operand instead of the per-coefficient
+    /// division of [`multiply_schoolbook`](Self::multiply_schoolbook).
+    ///
+    /// The prepared operand carries a Shoup multiplier `⌊b·2⁶⁴ / P⌋` per
+    /// coefficient, so each product `a·b mod P` is a `mulhi` plus a conditional
+    /// subtract — no divide in the inner loop. The result is bit-identical to
+    /// `multiply(self, prepared_source)`; preparing the plaintext once amortizes
+    /// the Shoup setup across repeated multiplications against it.
+    pub fn multiply_prepared(&self, prepared: &PreparedPlaintext<P, N>) -> Self {
+        let mut acc = alloc::vec![0i128; self.p.len() + prepared.coeffs.len() - 1];
+        for (i, &l) in self.p.coeffs().iter().enumerate() {
+            let l = l.as_i64();
+            for (j, (&r, &r_shoup)) in prepared.coeffs.iter().zip(&prepared.shoup).enumerate() {
+                acc[i + j] += i128::from(NttRoots::<P, N>::mul_shoup(l, r, r_shoup));
+            }
+        }
+        let coeffs = acc
+            .into_iter()
+            .map(|c| i64::try_from(c.rem_euclid(i128::from(P))).unwrap())
+            .collect();
+
+        let p = Self {
+            p: Polynomial::new(Self::reduce(coeffs)),
+            scale: self.scale() * prepared.scale,
+            level: self.level.max(prepared.level),
+        };
+        p.rescale(self.scale().max(prepared.scale))
+    }
+
+    /// Modular multiplication through a wider intermediate to avoid overflow.
+    #[inline]
+    const fn mulmod(a: i64, b: i64) -> i64 {
+        ((a as i128 * b as i128).rem_euclid(P as i128)) as i64
+    }
+
+    /// Modular exponentiation `base^exp mod P` by square-and-multiply.
+    #[inline]
+    const fn powmod(mut base: i64, mut exp: u64) -> i64 {
+        base = base.rem_euclid(P);
+        let mut result = 1i64;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = Self::mulmod(result, base);
+            }
+            base = Self::mulmod(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// In-place length-`n` Cooley–Tukey NTT with `omega` a primitive `n`-th root
+    /// of unity (bit-reversed input ordering), reducing every butterfly mod `P`.
+    fn ntt(a: &mut [i64], omega: i64) {
+        let n = a.len();
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j ^= bit;
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+        let mut len = 2;
+        while len <= n {
+            let wlen = Self::powmod(omega, (n / len) as u64);
+            let mut i = 0;
+            while i < n {
+                let mut w = 1i64;
+                for k in 0..len / 2 {
+                    let u = a[i + k];
+                    let t = Self::mulmod(a[i + k + len / 2], w);
+                    a[i + k] = (u + t).rem_euclid(P);
+                    a[i + k + len / 2] = (u - t).rem_euclid(P);
+                    w = Self::mulmod(w, wlen);
+                }
+                i += len;
+            }
+            len <<= 1;
+        }
+    }
+
+    /// Ring dimension `N = 2^{N_exp}`, the number of slots in `Z_P[X]/(X^N + 1)`.
+    const M: usize = 1usize << N;
+
+    /// Folds every coefficient at index `i ≥ N` back into index `i mod N` with
+    /// sign `(−1)^(i / N)` — the negacyclic wrap for `X^N = −1` — and reduces
+    /// each coefficient `rem_euclid(P)`.
+    ///
+    /// Applied after every arithmetic operation so the `const N` parameter
+    /// actually bounds the degree and encode/decode stay consistent with the
+    /// quotient-ring algebra the crate assumes.
+    fn reduce(coeffs: Vec<i64>) -> Vec<i64> {
+        let mut r = alloc::vec![0i64; Self::M];
+        let mut j = 0; // i mod N
+        let mut positive = true; // sign (-1)^(i / N)
+        for c in coeffs {
+            if positive {
+                r[j] += c;
+            } else {
+                r[j] -= c;
+            }
+            j += 1;
+            if j >= Self::M {
+                j = 0;
+                positive = !positive;
+            }
+        }
+        for c in &mut r {
+            *c = c.rem_euclid(P);
+        }
+        r
+    }
+
     fn rescale(self, scale: f64) -> Self {
         let coeffs = self
             .p
@@ -154,13 +672,241 @@ impl<const P: i64, const N: u32> ScaledPolynomial<P, N> {
         Self {
             p,
             scale: self.scale() / scale,
+            level: self.level + 1,
         }
     }
+
+    #[must_use]
+    #[inline]
+    /// The current level — how many rescalings have been applied.
+    ///
+    /// Each [`multiply`](Self::multiply) and each explicit
+    /// [`rescale_to`](Self::rescale_to) advances it by one; `add`/`sub` carry the
+    /// larger level of their operands.
+    pub const fn level(&self) -> u32 {
+        self.level
+    }
+
+    /// Drops the scale to `target_scale`, advancing the level by one.
+    ///
+    /// This exposes the rescaling policy `multiply` applies implicitly, so a
+    /// caller chaining `multiply` and `add` can realign scales explicitly before
+    /// an additive op instead of relying on the implicit `min`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RescaleError::ScaleIncrease`] if `target_scale` exceeds the
+    /// current scale (rescaling never raises the scale) and
+    /// [`RescaleError::BudgetExhausted`] if `target_scale` falls below `1.0`,
+    /// which would round the coefficients to near-zero.
+    pub fn rescale_to(&self, target_scale: f64) -> Result<Self, RescaleError> {
+        if target_scale > self.scale {
+            return Err(RescaleError::ScaleIncrease);
+        }
+        if target_scale < 1.0 {
+            return Err(RescaleError::BudgetExhausted);
+        }
+        let factor = self.scale / target_scale;
+        let coeffs = self
+            .p
+            .coeffs()
+            .iter()
+            .map(|&c| round(c.as_i64() as f64 / factor))
+            .collect();
+        Ok(Self {
+            p: Polynomial::new(coeffs),
+            scale: target_scale,
+            level: self.level + 1,
+        })
+    }
+
+    #[must_use]
+    /// Converts to the point-value (evaluation) form of
+    /// [`ScaledPolynomialValues`], in which `add`/`sub`/`multiply` are pointwise
+    /// `O(N)`.
+    ///
+    /// Returns `None` when `P` is not NTT-friendly, since the evaluation form is
+    /// the twisted negacyclic NTT and requires a primitive `2N`-th root of unity.
+    pub fn to_ntt(&self) -> Option<ScaledPolynomialValues<P, N>> {
+        let roots = NttRoots::<P, N>::new()?;
+        let m = roots.psi_pows.len();
+        let mut values = alloc::vec![0i64; m];
+        for (i, slot) in values.iter_mut().enumerate() {
+            let c = self.p.coeffs().get(i).map_or(0, |c| c.as_i64());
+            *slot = Self::mulmod(c, roots.psi_pows[i]);
+        }
+        Self::ntt(&mut values, roots.omega);
+        Some(ScaledPolynomialValues {
+            values,
+            scale: self.scale,
+            level: self.level,
+        })
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32> bincode::Encode for ScaledPolynomial<P, N> {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        // The scale and level travel with the coefficients so a reloaded
+        // polynomial lands at the exact point on the scale/level ladder.
+        self.p.encode(encoder)?;
+        self.scale.encode(encoder)?;
+        self.level.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32, Context> bincode::Decode<Context> for ScaledPolynomial<P, N> {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        Ok(Self {
+            p: Polynomial::decode(decoder)?,
+            scale: f64::decode(decoder)?,
+            level: u32::decode(decoder)?,
+        })
+    }
+}
+
+/// A fixed plaintext operand with its per-coefficient Shoup multipliers
+/// precomputed for [`ScaledPolynomial::multiply_prepared`].
+///
+/// Workloads that multiply many ciphertexts by the *same* plaintext (a bias
+/// vector, a linear-layer weight) pay the Shoup setup once here and reuse it on
+/// every product, dropping the divide from the inner modular multiply.
+pub struct PreparedPlaintext<const P: i64, const N: u32> {
+    /// Coefficients of the fixed operand, each reduced into `[0, P)`.
+    coeffs: Vec<i64>,
+    /// Shoup multiplier `⌊coeff·2⁶⁴ / P⌋` for the matching coefficient.
+    shoup: Vec<u64>,
+    scale: f64,
+    level: u32,
+}
+
+impl<const P: i64, const N: u32> PreparedPlaintext<P, N> {
+    #[must_use]
+    /// Precomputes the Shoup tables for `source`, capturing its scale and level
+    /// so a product against it lands at the same scale as the equivalent
+    /// [`ScaledPolynomial::multiply`].
+    pub fn new(source: &ScaledPolynomial<P, N>) -> Self {
+        let coeffs: Vec<i64> = source.p.coeffs().iter().map(Coeff::as_i64).collect();
+        let shoup = coeffs.iter().map(|&c| NttRoots::<P, N>::shoup(c)).collect();
+        Self {
+            coeffs,
+            shoup,
+            scale: source.scale(),
+            level: source.level,
+        }
+    }
+}
+
+/// Point-value (evaluation-domain) companion to [`ScaledPolynomial`].
+///
+/// Holds the twisted negacyclic-NTT evaluations of the coefficients so that a
+/// sequence of `multiply`/`add`/`sub` stays pointwise `O(N)`; convert back with
+/// [`from_ntt`](Self::from_ntt) (one inverse NTT) only when a coefficient form is
+/// needed, typically at decode time.
+pub struct ScaledPolynomialValues<const P: i64, const N: u32> {
+    values: Vec<i64>,
+    scale: f64,
+    level: u32,
+}
+
+impl<const P: i64, const N: u32> ScaledPolynomialValues<P, N> {
+    #[must_use]
+    #[inline]
+    /// Get the evaluation values.
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+
+    #[must_use]
+    #[inline]
+    /// Get the scale.
+    pub const fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    #[must_use]
+    #[inline]
+    /// Get the level.
+    pub const fn level(&self) -> u32 {
+        self.level
+    }
+
+    #[must_use]
+    /// Pointwise sum. Both operands are assumed to share the same scale; the
+    /// larger level is carried.
+    pub fn add(&self, other: &Self) -> Self {
+        self.pointwise(other, |a, b| (a + b).rem_euclid(P), self.scale)
+    }
+
+    #[must_use]
+    /// Pointwise difference, under the same scale assumption as [`add`](Self::add).
+    pub fn sub(&self, other: &Self) -> Self {
+        self.pointwise(other, |a, b| (a - b).rem_euclid(P), self.scale)
+    }
+
+    #[must_use]
+    /// Pointwise product. The result scale is the product of the operand scales,
+    /// exactly as for coefficient-domain [`ScaledPolynomial::multiply`] before
+    /// its implicit rescale (which the caller applies after `from_ntt`).
+    pub fn multiply(&self, other: &Self) -> Self {
+        self.pointwise(
+            other,
+            ScaledPolynomial::<P, N>::mulmod,
+            self.scale * other.scale,
+        )
+    }
+
+    fn pointwise(&self, other: &Self, op: impl Fn(i64, i64) -> i64, scale: f64) -> Self {
+        let values = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .map(|(&a, &b)| op(a, b))
+            .collect();
+        Self {
+            values,
+            scale,
+            level: self.level.max(other.level),
+        }
+    }
+
+    #[must_use]
+    /// Converts back to coefficient form with a single inverse NTT, undoing the
+    /// twist and the `N⁻¹` scaling.
+    ///
+    /// Returns `None` when `P` is not NTT-friendly (mirroring
+    /// [`ScaledPolynomial::to_ntt`]).
+    pub fn from_ntt(&self) -> Option<ScaledPolynomial<P, N>> {
+        let roots = NttRoots::<P, N>::new()?;
+        let mut a = self.values.clone();
+        ScaledPolynomial::<P, N>::ntt(&mut a, roots.omega_inv);
+        let coeffs = a
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| {
+                ScaledPolynomial::<P, N>::mulmod(
+                    ScaledPolynomial::<P, N>::mulmod(c, roots.n_inv),
+                    roots.psi_inv_pows[i],
+                )
+            })
+            .collect();
+        Some(ScaledPolynomial {
+            p: Polynomial::new(ScaledPolynomial::<P, N>::reduce(coeffs)),
+            scale: self.scale,
+            level: self.level,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::vec;
+    use alloc::vec;
 
     use super::*;
 
@@ -240,6 +986,147 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_multiply_ntt_matches_schoolbook() {
+        // 17 is prime with 2·8 = 16 | 16, so the negacyclic NTT is available.
+        const QP: i64 = 17;
+        const QN: u32 = 3;
+        let lhs = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![1, 2, 3, 4]), 1.0);
+        let rhs = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![5, 6, 7, 8]), 1.0);
+
+        let roots = NttRoots::<QP, QN>::new().expect("17 is NTT-friendly");
+        let ntt = ScaledPolynomial::multiply_ntt(&roots, &lhs, &rhs);
+        let school = ScaledPolynomial::multiply_schoolbook(&lhs, &rhs);
+        assert_eq!(ntt.polynomial().coeffs(), school.polynomial().coeffs());
+    }
+
+    #[test]
+    fn test_multiply_prepared_matches_schoolbook() {
+        const QP: i64 = 17;
+        const QN: u32 = 3;
+        let lhs = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![1, 2, 3, 4]), 1.0);
+        let rhs = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![5, 6, 7, 8]), 1.0);
+
+        let prepared = PreparedPlaintext::new(&rhs);
+        let shoup = lhs.multiply_prepared(&prepared);
+        let school = ScaledPolynomial::multiply_schoolbook(&lhs, &rhs);
+        assert_eq!(shoup.polynomial().coeffs(), school.polynomial().coeffs());
+        assert_eq!(shoup.scale(), school.scale());
+    }
+
+    #[test]
+    fn test_multiply_folds_negacyclically_into_ring() {
+        // In Z_17[X]/(X^4 + 1): X^3 · X^3 = X^6 = X^2·X^4 = -X^2 ≡ 16·X^2.
+        const QP: i64 = 17;
+        const QN: u32 = 2;
+        let x3 = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![0, 0, 0, 1]), 1.0);
+        let product = ScaledPolynomial::multiply(&x3, &x3);
+        let coeffs: Vec<i64> = product.polynomial().coeffs().iter().map(Coeff::as_i64).collect();
+        assert_eq!(coeffs, vec![0, 0, 16, 0]);
+    }
+
+    #[test]
+    fn test_ntt_friendly_predicate() {
+        // 17 ≡ 1 (mod 16), so the degree-8 ring is NTT-friendly; 7 is not.
+        assert!(ScaledPolynomial::<17, 3>::ntt_friendly());
+        assert!(!ScaledPolynomial::<7, 3>::ntt_friendly());
+        assert_eq!(
+            ScaledPolynomial::<17, 3>::ntt_friendly(),
+            NttRoots::<17, 3>::new().is_some()
+        );
+    }
+
+    #[test]
+    fn test_multiply_not_ntt_friendly_uses_schoolbook() {
+        // 7 does not satisfy 16 | 6, so `multiply` must fall back cleanly.
+        const QP: i64 = 7;
+        const QN: u32 = 3;
+        assert!(NttRoots::<QP, QN>::new().is_none());
+        let lhs = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![1, 2]), 1.0);
+        let rhs = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![3, 4]), 1.0);
+        let product = ScaledPolynomial::multiply(&lhs, &rhs);
+        let school = ScaledPolynomial::multiply_schoolbook(&lhs, &rhs);
+        assert_eq!(product.polynomial().coeffs(), school.polynomial().coeffs());
+    }
+
+    #[test]
+    fn test_level_tracking_and_rescale_to() {
+        let a = ScaledPolynomial::<P, N>::encode(&[1.0, 2.0, 3.0], SCALE);
+        assert_eq!(a.level(), 0);
+
+        // A multiply rescales once, advancing the level.
+        let prod = ScaledPolynomial::multiply(&a, &a);
+        assert_eq!(prod.level(), 1);
+
+        // An explicit rescale drops the scale and advances the level again.
+        let dropped = prod.rescale_to(1e3).unwrap();
+        assert_eq!(dropped.level(), 2);
+        assert!((dropped.scale() - 1e3).abs() < 1e-9);
+
+        // Rescaling up or past the budget is rejected.
+        assert!(matches!(
+            prod.rescale_to(prod.scale() * 2.0),
+            Err(RescaleError::ScaleIncrease)
+        ));
+        assert!(matches!(a.rescale_to(0.5), Err(RescaleError::BudgetExhausted)));
+    }
+
+    #[test]
+    fn test_ntt_domain_round_trip() {
+        const QP: i64 = 17;
+        const QN: u32 = 3;
+        let a = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![1, 2, 3, 4]), 1.0);
+        let round_trip = a.to_ntt().unwrap().from_ntt().unwrap();
+        assert_eq!(round_trip.polynomial(), a.polynomial());
+    }
+
+    #[test]
+    fn test_ntt_domain_multiply_matches_ring_product() {
+        const QP: i64 = 17;
+        const QN: u32 = 3;
+        let a = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![1, 2, 3, 4]), 1.0);
+        let b = ScaledPolynomial::<QP, QN>::new(Polynomial::new(vec![5, 6, 7, 8]), 1.0);
+
+        let prod = a
+            .to_ntt()
+            .unwrap()
+            .multiply(&b.to_ntt().unwrap())
+            .from_ntt()
+            .unwrap();
+        let expected = Polynomial::multiply_ntt(a.polynomial(), b.polynomial());
+        assert_eq!(prod.polynomial(), &expected);
+    }
+
+    #[test]
+    fn test_slot_encode_decode_round_trip() {
+        const SP: i64 = 10_000_000_007;
+        const SN: u32 = 2; // ring dimension 4, so 2 complex slots.
+        let slots = vec![Complex::new(1.5, 0.5), Complex::new(-2.0, 1.0)];
+        let decoded = ScaledPolynomial::<SP, SN>::encode_slots(&slots, 1e6).decode_slots();
+
+        assert_eq!(decoded.len(), 2);
+        for (orig, dec) in slots.iter().zip(decoded) {
+            assert!((orig.re - dec.re).abs() < 1e-2);
+            assert!((orig.im - dec.im).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_slot_multiply_is_elementwise() {
+        const SP: i64 = 10_000_000_007;
+        const SN: u32 = 2;
+        let a = vec![Complex::new(2.0, 0.0), Complex::new(3.0, 0.0)];
+        let b = vec![Complex::new(2.0, 0.0), Complex::new(2.0, 0.0)];
+
+        let ea = ScaledPolynomial::<SP, SN>::encode_slots(&a, 1e4);
+        let eb = ScaledPolynomial::<SP, SN>::encode_slots(&b, 1e4);
+        let decoded = ScaledPolynomial::multiply(&ea, &eb).decode_slots();
+
+        // Slotwise product: [2·2, 3·2] = [4, 6].
+        assert!((decoded[0].re - 4.0).abs() < 1e-1);
+        assert!((decoded[1].re - 6.0).abs() < 1e-1);
+    }
+
     #[test]
     fn test_encode_and_decode_zero_threshold() {
         // Arrange: Values nearly zero (under decode's TRESHOLD) should decode to 0.0.
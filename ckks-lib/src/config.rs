@@ -1,6 +1,7 @@
 use crate::polynomial::Coeff;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 /// CKKS configuration parameters
 pub struct Config {
     /// Polynomial degree (N)
@@ -60,6 +61,7 @@ impl Config {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 #[non_exhaustive]
 /// Sets of parameters for the Truncated Gaussian Distribution
 ///
@@ -1,10 +1,11 @@
 use crate::config::Config;
+use alloc::vec::Vec;
 use fhe_core::{
     f64::round,
-    pring::Polynomial,
+    pring::{Coeff, Polynomial},
     rand::distributions::{Distribution, Gaussian, Truncated, Uniform},
+    secret::Secret,
 };
-use zeroize::{Zeroize, ZeroizeOnDrop};
 
 #[derive(Debug, Clone)]
 /// Public key
@@ -27,22 +28,131 @@ impl<const P: i64, const N: u32> PublicKey<P, N> {
     }
 }
 
-#[derive(Debug, Clone, Zeroize, ZeroizeOnDrop)]
+#[derive(Clone)]
 /// Secret key
 ///
 /// # Notes
 ///
-/// The key is automatically zeroized when it goes out of scope
+/// The secret coefficients are held in a [`Secret`] guard, so their pages are
+/// memory-locked while live and zeroized when the key goes out of scope. The
+/// [`Debug`] impl is redacted and [`PartialEq`] is constant-time; `Ord`/`Hash`
+/// are deliberately not implemented, since ordering or hashing secret material
+/// is unsafe.
 pub struct SecretKey<const P: i64, const N: u32> {
-    p: Polynomial<P, N>,
+    p: Secret<Polynomial<P, N>>,
+}
+
+impl<const P: i64, const N: u32> core::fmt::Debug for SecretKey<P, N> {
+    /// Redacts the secret coefficients so they never reach a log sink.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SecretKey { .. }")
+    }
+}
+
+impl<const P: i64, const N: u32> PartialEq for SecretKey<P, N> {
+    /// Constant-time comparison of the coefficient buffers; see
+    /// [`constant_time_eq`](Self::constant_time_eq).
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.constant_time_eq(other)
+    }
 }
 
 impl<const P: i64, const N: u32> SecretKey<P, N> {
     #[must_use]
     #[inline]
-    pub const fn p(&self) -> &Polynomial<P, N> {
+    pub fn p(&self) -> &Polynomial<P, N> {
         &self.p
     }
+
+    #[must_use]
+    /// Compares two secret keys in constant time with respect to their contents.
+    ///
+    /// The whole coefficient buffer is folded into a single accumulator so the
+    /// comparison never short-circuits on the first differing byte, closing the
+    /// timing side channel that a naive `==` would open. This backs the
+    /// constant-time [`PartialEq`] impl; `Ord`/`PartialOrd` and `Hash` are
+    /// deliberately left unimplemented, since ordering or hashing secret
+    /// material is unsafe and must never be done.
+    pub fn constant_time_eq(&self, other: &Self) -> bool {
+        ct_eq_coeffs(self.p().coeffs(), other.p().coeffs())
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32> bincode::Encode for PublicKey<P, N> {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.p0.encode(encoder)?;
+        self.p1.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32, Context> bincode::Decode<Context> for PublicKey<P, N> {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        Ok(Self {
+            p0: Polynomial::decode(decoder)?,
+            p1: Polynomial::decode(decoder)?,
+        })
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32> bincode::Encode for SecretKey<P, N> {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.p().encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32, Context> bincode::Decode<Context> for SecretKey<P, N> {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        // Route the decoded polynomial straight into the zeroizing `Secret`
+        // guard so no transient, unlocked copy of the coefficients lingers.
+        let p = Polynomial::decode(decoder)?;
+        Ok(Self {
+            p: Secret::new(p).map_err(|e| {
+                bincode::error::DecodeError::OtherString(alloc::format!(
+                    "failed to mlock deserialized secret key: {e}"
+                ))
+            })?,
+        })
+    }
+}
+
+/// Error returned by the fallible key-generation entry points.
+#[derive(Debug)]
+pub enum KeyGenError {
+    /// A random sampler (uniform or truncated Gaussian) ran out of entropy.
+    Sampling(getrandom::Error),
+    /// The generated secret material could not be memory-locked.
+    Lock(fhe_core::secret::SecretError),
+}
+
+impl From<getrandom::Error> for KeyGenError {
+    #[inline]
+    fn from(e: getrandom::Error) -> Self {
+        Self::Sampling(e)
+    }
+}
+
+impl core::fmt::Display for KeyGenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Sampling(e) => write!(f, "key generation sampler failed: {e}"),
+            Self::Lock(e) => write!(f, "{e}"),
+        }
+    }
 }
 
 #[must_use]
@@ -50,14 +160,30 @@ impl<const P: i64, const N: u32> SecretKey<P, N> {
 ///
 /// # Panics
 ///
-/// Panics if randomness fails to be generated, or if any noise value is non-positive
+/// Panics if randomness fails to be generated, if any noise value is non-positive,
+/// or if the secret key pages cannot be memory-locked (see `MLOCK_SECRETS`)
 pub fn generate_keys<const P: i64, const N: u32>(
     config: Config<P, N>,
 ) -> (PublicKey<P, N>, SecretKey<P, N>) {
+    try_generate_keys(config).expect("key generation failed")
+}
+
+/// Generate a fresh pair of keys, surfacing sampler and locking failures.
+///
+/// Fallible counterpart to [`generate_keys`] for services that cannot afford to
+/// unwind when the entropy source is exhausted or `mlock` is refused.
+///
+/// # Errors
+///
+/// Returns [`KeyGenError::Sampling`] if a distribution fails to produce a value,
+/// or [`KeyGenError::Lock`] if the secret key pages cannot be memory-locked.
+pub fn try_generate_keys<const P: i64, const N: u32>(
+    config: Config<P, N>,
+) -> Result<(PublicKey<P, N>, SecretKey<P, N>), KeyGenError> {
     let skey = {
         let u = Uniform::<i64>::new(-1..=1);
         SecretKey {
-            p: Polynomial::random(&u),
+            p: Secret::new(Polynomial::try_random(&u)?).map_err(KeyGenError::Lock)?,
         }
     };
 
@@ -65,7 +191,7 @@ pub fn generate_keys<const P: i64, const N: u32>(
         #[allow(clippy::range_minus_one)]
         let u = Uniform::<i64>::new(0..=P - 1);
 
-        let p1 = { Polynomial::random(&u) };
+        let p1 = Polynomial::try_random(&u)?;
 
         let p0 = {
             let g = Gaussian::new(config.gdp().mu(), config.gdp().sigma());
@@ -79,17 +205,274 @@ pub fn generate_keys<const P: i64, const N: u32>(
                 .zip(p1.coeffs())
                 .map(|(&sk, &r)| {
                     // Gaussian distribution bounded by beta
-                    let e = t.sample().unwrap();
+                    let e = t.sample()?;
                     let r_128 = i128::from(r.as_i64());
                     let sk_128 = i128::from(sk.as_i64());
-                    i64::try_from((-r_128 * sk_128).rem_euclid(P.into())).unwrap() + round(e)
+                    Ok(i64::try_from((-r_128 * sk_128).rem_euclid(P.into())).unwrap() + round(e))
                 })
-                .collect();
+                .collect::<Result<Vec<i64>, getrandom::Error>>()?;
             Polynomial::new(coeffs)
         };
 
         PublicKey { p0, p1 }
     };
 
-    (pkey, skey)
+    Ok((pkey, skey))
+}
+
+/// One party's Shamir share of a [`SecretKey`].
+///
+/// The share is the secret polynomial evaluated coefficient-wise at the party's
+/// index `j`; like the full key it is kept in a [`Secret`] guard.
+#[derive(Debug, Clone)]
+pub struct SecretKeyShare<const P: i64, const N: u32> {
+    index: i64,
+    share: Secret<Polynomial<P, N>>,
+}
+
+impl<const P: i64, const N: u32> SecretKeyShare<P, N> {
+    #[must_use]
+    #[inline]
+    /// The party index (the x-coordinate at which the secret was evaluated).
+    pub const fn index(&self) -> i64 {
+        self.index
+    }
+
+    #[must_use]
+    #[inline]
+    /// The share polynomial `f(index)`.
+    pub fn share(&self) -> &Polynomial<P, N> {
+        &self.share
+    }
+
+    #[must_use]
+    /// Compares two shares in constant time with respect to their contents.
+    ///
+    /// Like [`SecretKey::constant_time_eq`], the comparison never short-circuits;
+    /// `SecretKeyShare` likewise implements no `PartialEq`, `Ord`/`PartialOrd`
+    /// or `Hash`, since ordering or hashing share material is unsafe.
+    pub fn constant_time_eq(&self, other: &Self) -> bool {
+        self.index == other.index && ct_eq_coeffs(self.share().coeffs(), other.share().coeffs())
+    }
+}
+
+/// Splits `skey` into `n` Shamir shares with reconstruction threshold `t`.
+///
+/// For every secret coefficient `s_i` a degree-`t` polynomial
+/// `f_i(x) = s_i + a_1 x + … + a_t x^t` is sampled with uniform coefficients in
+/// `[0, P)`, and party `j` (for `j = 1..=n`) receives the evaluations `f_i(j)`
+/// gathered back into a [`Polynomial`]. Any `t + 1` shares reconstruct the
+/// secret via [`combine_shares`]; fewer reveal nothing.
+///
+/// # Panics
+///
+/// Panics if `t >= n`, if randomness fails, or if a share's pages cannot be
+/// memory-locked (see `MLOCK_SECRETS`).
+#[must_use]
+pub fn share_secret_key<const P: i64, const N: u32>(
+    skey: &SecretKey<P, N>,
+    n: usize,
+    t: usize,
+) -> Vec<SecretKeyShare<P, N>> {
+    try_share_secret_key(skey, n, t).expect("failed to share secret key")
+}
+
+/// Fallible counterpart to [`share_secret_key`], propagating sampler and
+/// locking failures instead of panicking.
+///
+/// # Panics
+///
+/// Panics if `t >= n`.
+///
+/// # Errors
+///
+/// Returns [`KeyGenError::Sampling`] if the masking coefficients cannot be
+/// sampled, or [`KeyGenError::Lock`] if a share's pages cannot be locked.
+pub fn try_share_secret_key<const P: i64, const N: u32>(
+    skey: &SecretKey<P, N>,
+    n: usize,
+    t: usize,
+) -> Result<Vec<SecretKeyShare<P, N>>, KeyGenError> {
+    assert!(t < n, "threshold t must be smaller than the number of parties n");
+
+    let secret = skey.p().coeffs();
+
+    // Sample the masking coefficients a_{i,1..t} once; they define the sharing
+    // polynomials f_i and are shared by every party.
+    #[allow(clippy::range_minus_one)]
+    let u = Uniform::<i64>::new(0..=P - 1);
+    let mut masks: Vec<Vec<Coeff<P>>> = Vec::with_capacity(secret.len());
+    for _ in secret {
+        let mut row = Vec::with_capacity(t);
+        for _ in 0..t {
+            row.push(Coeff::new(u.sample()?));
+        }
+        masks.push(row);
+    }
+
+    (1..=n)
+        .map(|j| {
+            let x = Coeff::<P>::new(i64::try_from(j).unwrap());
+            let coeffs = secret
+                .iter()
+                .zip(&masks)
+                .map(|(&s, a)| {
+                    let mut acc = s;
+                    let mut xpow = x;
+                    for &ak in a {
+                        acc = acc + ak * xpow;
+                        xpow = xpow * x;
+                    }
+                    acc.as_i64()
+                })
+                .collect();
+            Ok(SecretKeyShare {
+                index: i64::try_from(j).unwrap(),
+                share: Secret::new(Polynomial::new(coeffs)).map_err(KeyGenError::Lock)?,
+            })
+        })
+        .collect()
+}
+
+/// Reconstructs a [`SecretKey`] from at least `t + 1` shares via Lagrange
+/// interpolation at `x = 0`, with coefficients `λ_j = Π_{m≠j} x_m / (x_m − x_j)`
+/// taken modulo `P`.
+///
+/// # Panics
+///
+/// Panics if `shares` is empty, if two shares carry the same index, or if the
+/// reconstructed pages cannot be memory-locked (see `MLOCK_SECRETS`).
+#[must_use]
+pub fn combine_shares<const P: i64, const N: u32>(
+    shares: &[SecretKeyShare<P, N>],
+) -> SecretKey<P, N> {
+    assert!(!shares.is_empty(), "need at least one share to reconstruct");
+
+    let lambdas: Vec<Coeff<P>> = shares
+        .iter()
+        .map(|sj| {
+            let xj = Coeff::<P>::new(sj.index);
+            let mut num = Coeff::<P>::new(1);
+            let mut den = Coeff::<P>::new(1);
+            for sm in shares {
+                if sm.index == sj.index {
+                    continue;
+                }
+                let xm = Coeff::<P>::new(sm.index);
+                num = num * xm;
+                den = den * (xm - xj);
+            }
+            num * Coeff::new(inv_mod(den.as_i64(), P))
+        })
+        .collect();
+
+    let len = shares[0].share().coeffs().len();
+    let coeffs = (0..len)
+        .map(|i| {
+            let mut acc = Coeff::<P>::new(0);
+            for (sj, &lambda) in shares.iter().zip(&lambdas) {
+                acc = acc + sj.share().coeffs()[i] * lambda;
+            }
+            acc.as_i64()
+        })
+        .collect();
+
+    SecretKey {
+        p: Secret::new(Polynomial::new(coeffs))
+            .expect("failed to mlock reconstructed secret key pages"),
+    }
+}
+
+/// Generates a key pair and splits the secret into `n` threshold shares.
+///
+/// The returned [`PublicKey`] matches the shared secret, so callers can encrypt
+/// normally and perform threshold decryption by combining `t + 1` shares with
+/// [`combine_shares`].
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`generate_keys`] and
+/// [`share_secret_key`].
+#[must_use]
+pub fn distributed_keygen<const P: i64, const N: u32>(
+    config: Config<P, N>,
+    n: usize,
+    t: usize,
+) -> (PublicKey<P, N>, Vec<SecretKeyShare<P, N>>) {
+    let (pkey, skey) = generate_keys(config);
+    let shares = share_secret_key(&skey, n, t);
+    (pkey, shares)
+}
+
+/// Fallible counterpart to [`distributed_keygen`].
+///
+/// # Panics
+///
+/// Panics if `t >= n`.
+///
+/// # Errors
+///
+/// Propagates any [`KeyGenError`] from key generation or sharing.
+pub fn try_distributed_keygen<const P: i64, const N: u32>(
+    config: Config<P, N>,
+    n: usize,
+    t: usize,
+) -> Result<(PublicKey<P, N>, Vec<SecretKeyShare<P, N>>), KeyGenError> {
+    let (pkey, skey) = try_generate_keys(config)?;
+    let shares = try_share_secret_key(&skey, n, t)?;
+    Ok((pkey, shares))
+}
+
+/// Constant-time equality of two coefficient buffers: every element is folded
+/// into a single accumulator so the loop never short-circuits on a mismatch.
+/// A length difference is not secret and returns early.
+fn ct_eq_coeffs<const P: i64>(a: &[Coeff<P>], b: &[Coeff<P>]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0i64;
+    for (&x, &y) in a.iter().zip(b) {
+        diff |= x.as_i64() ^ y.as_i64();
+    }
+    diff == 0
+}
+
+/// Splits `skey` into `n` Shamir shares reconstructible from any `t` of them.
+///
+/// For every secret coefficient a degree-`t-1` polynomial is sampled with that
+/// coefficient as its constant term and uniform masking coefficients in
+/// `[0, P)`, and party `j` (for `j = 1..=n`) receives the evaluations at `j`
+/// gathered into a [`Polynomial`]. This is the threshold-`t` counterpart to
+/// [`share_secret_key`] (whose sharing polynomials have degree `t`, i.e.
+/// threshold `t + 1`) and feeds the collaborative decryption in
+/// [`crate::cipher`].
+///
+/// # Panics
+///
+/// Panics if `t == 0`, if `t > n`, if randomness fails, or if a share's pages
+/// cannot be memory-locked (see `MLOCK_SECRETS`).
+#[must_use]
+pub fn split_secret_key<const P: i64, const N: u32>(
+    skey: &SecretKey<P, N>,
+    n: usize,
+    t: usize,
+) -> Vec<SecretKeyShare<P, N>> {
+    assert!(t >= 1, "threshold t must be at least 1");
+    // A degree-`t-1` polynomial has `t-1` masking coefficients on top of the
+    // secret constant term, matching `share_secret_key`'s `t` masks for its
+    // degree-`t` polynomials.
+    share_secret_key(skey, n, t - 1)
+}
+
+/// Modular inverse of `a` modulo the prime `p` via the extended Euclidean
+/// algorithm, returned in `[0, p)`.
+pub(crate) fn inv_mod(a: i64, p: i64) -> i64 {
+    let (mut old_r, mut r) = (a.rem_euclid(p), p);
+    let (mut old_s, mut s) = (1i64, 0i64);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    old_s.rem_euclid(p)
 }
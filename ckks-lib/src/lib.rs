@@ -1,4 +1,5 @@
 //! CKKS Backend
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(clippy::nursery, clippy::pedantic)]
 #![forbid(unsafe_code)]
 
@@ -7,7 +8,9 @@ extern crate alloc;
 pub mod cipher;
 pub mod config;
 pub mod key;
+pub mod modulus_chain;
 pub mod ops;
+pub mod sampler;
 
 /// Type for plaintext values
 pub type Plaintext = f64;
@@ -0,0 +1,275 @@
+//! RNS coefficient-modulus chain for CKKS rescaling.
+//!
+//! A single scalar modulus cannot express the depth CKKS needs: every rescale
+//! peels one prime off an ordered chain of NTT-friendly moduli, dropping the
+//! scale back to `Δ`. [`ModulusChain`] holds that chain — an ordered list of
+//! primes each `≡ 1 (mod 2N)` so the negacyclic NTT exists at every level —
+//! together with the target scale `Δ`. Build one explicitly with
+//! [`ModulusChainBuilder`], or let [`ModulusChain::recommended`] pick a chain
+//! whose total bit count stays within the security bound for the ring degree.
+
+use alloc::vec::Vec;
+
+/// HE-standard security level, selecting the maximum total coefficient-modulus
+/// bit count permitted for a given ring degree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityLevel {
+    /// 128-bit security (the `TC128` parameter set).
+    Tc128,
+    /// 192-bit security.
+    Tc192,
+    /// 256-bit security.
+    Tc256,
+}
+
+/// An ordered chain of RNS primes plus the CKKS target scale.
+#[derive(Debug, Clone)]
+pub struct ModulusChain {
+    /// Ring degree `N` (a power of two); every prime satisfies `p ≡ 1 (mod 2N)`.
+    degree: usize,
+    /// The prime moduli, highest level first.
+    primes: Vec<u64>,
+    /// Target scale `Δ` the chain rescales back to after each multiply.
+    scale: f64,
+    /// Number of primes already consumed by rescaling.
+    consumed: usize,
+}
+
+impl ModulusChain {
+    /// The current level: the number of primes still available for rescaling.
+    #[must_use]
+    pub fn level(&self) -> usize {
+        self.primes.len().saturating_sub(self.consumed)
+    }
+
+    /// The primes not yet consumed, highest level first.
+    #[must_use]
+    pub fn remaining_primes(&self) -> &[u64] {
+        &self.primes[self.consumed.min(self.primes.len())..]
+    }
+
+    /// Drops the top prime, as [`mod_switch_to_next`] does during a rescale.
+    ///
+    /// Returns the consumed prime, or `None` when the chain is exhausted.
+    ///
+    /// [`mod_switch_to_next`]: https://github.com/microsoft/SEAL
+    pub fn mod_switch_to_next(&mut self) -> Option<u64> {
+        if self.consumed >= self.primes.len() {
+            return None;
+        }
+        let prime = self.primes[self.consumed];
+        self.consumed += 1;
+        Some(prime)
+    }
+
+    /// Target scale `Δ`.
+    #[must_use]
+    pub const fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Total bit count of the remaining coefficient modulus, for checking
+    /// against the security bound of the ring degree.
+    #[must_use]
+    pub fn total_coeff_modulus_bit_count(&self) -> u32 {
+        self.remaining_primes()
+            .iter()
+            .map(|&p| 64 - p.leading_zeros())
+            .sum()
+    }
+
+    /// Builds the chain the HE standard recommends for `degree` at
+    /// `security_level`: a high-precision special prime, a run of `≈ log2 Δ`-bit
+    /// primes for rescaling depth, and a final special prime, kept under
+    /// [`max_total_bits`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is not a power of two, or if [`max_total_bits`] for
+    /// `degree` and `security_level` is too small to hold the two special primes.
+    #[must_use]
+    pub fn recommended(degree: usize, security_level: SecurityLevel) -> Self {
+        assert!(degree.is_power_of_two(), "degree must be a power of 2");
+
+        const SPECIAL_BITS: u32 = 60;
+        const SCALE_BITS: u32 = 40;
+
+        let budget = max_total_bits(degree, security_level);
+        assert!(
+            2 * SPECIAL_BITS <= budget,
+            "max_total_bits for this degree and security level cannot hold the special primes"
+        );
+        let mut builder = ModulusChainBuilder::new(degree).scale((1u64 << SCALE_BITS) as f64);
+
+        // One special prime at each end, scale-sized primes in between.
+        builder = builder.push_prime(SPECIAL_BITS);
+        let mut used = SPECIAL_BITS;
+        while used + SCALE_BITS + SPECIAL_BITS <= budget {
+            builder = builder.push_prime(SCALE_BITS);
+            used += SCALE_BITS;
+        }
+        builder = builder.push_prime(SPECIAL_BITS);
+
+        builder.build()
+    }
+}
+
+/// Fluent builder for a [`ModulusChain`].
+pub struct ModulusChainBuilder {
+    degree: usize,
+    primes: Vec<u64>,
+    scale: f64,
+}
+
+impl ModulusChainBuilder {
+    /// Starts a builder for ring degree `degree`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is not a power of two.
+    #[must_use]
+    pub fn new(degree: usize) -> Self {
+        assert!(degree.is_power_of_two(), "degree must be a power of 2");
+        Self {
+            degree,
+            primes: Vec::new(),
+            scale: 1.0,
+        }
+    }
+
+    /// Sets the target scale `Δ`.
+    #[must_use]
+    pub const fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Appends the next NTT-friendly prime of `bits` bits not already in the
+    /// chain (`p ≡ 1 (mod 2·degree)`, distinct from the existing primes).
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such prime fits in `bits` bits.
+    #[must_use]
+    pub fn push_prime(mut self, bits: u32) -> Self {
+        let prime = self.find_prime(bits);
+        self.primes.push(prime);
+        self
+    }
+
+    /// Finalizes the chain.
+    #[must_use]
+    pub fn build(self) -> ModulusChain {
+        ModulusChain {
+            degree: self.degree,
+            primes: self.primes,
+            scale: self.scale,
+            consumed: 0,
+        }
+    }
+
+    /// Searches downward from `2^bits` for a prime `≡ 1 (mod 2·degree)` not
+    /// already used.
+    fn find_prime(&self, bits: u32) -> u64 {
+        let m = 2 * self.degree as u64;
+        let upper = 1u64 << bits;
+        let lower = 1u64 << (bits - 1);
+
+        // Largest value < 2^bits that is ≡ 1 (mod m).
+        let mut candidate = upper - ((upper - 1) % m);
+        while candidate >= lower {
+            if !self.primes.contains(&candidate) && is_prime(candidate) {
+                return candidate;
+            }
+            candidate -= m;
+        }
+        panic!("no NTT-friendly {bits}-bit prime for degree {}", self.degree);
+    }
+}
+
+/// Maximum total coefficient-modulus bit count permitted at `security_level`
+/// for ring degree `degree`, per the HomomorphicEncryption.org standard.
+#[must_use]
+pub fn max_total_bits(degree: usize, security_level: SecurityLevel) -> u32 {
+    match security_level {
+        SecurityLevel::Tc128 => match degree {
+            1024 => 27,
+            2048 => 54,
+            4096 => 109,
+            8192 => 218,
+            16384 => 438,
+            32768 => 881,
+            _ => 0,
+        },
+        SecurityLevel::Tc192 => match degree {
+            1024 => 19,
+            2048 => 37,
+            4096 => 75,
+            8192 => 152,
+            16384 => 305,
+            32768 => 611,
+            _ => 0,
+        },
+        SecurityLevel::Tc256 => match degree {
+            1024 => 14,
+            2048 => 29,
+            4096 => 58,
+            8192 => 118,
+            16384 => 237,
+            32768 => 476,
+            _ => 0,
+        },
+    }
+}
+
+/// Deterministic Miller–Rabin primality test over `u64`.
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in &[2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+    let mut d = n - 1;
+    let mut r = 0;
+    while d % 2 == 0 {
+        d /= 2;
+        r += 1;
+    }
+    // These bases are a deterministic witness set for all n < 3.3·10^24.
+    'witness: for &a in &[2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 0..r - 1 {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// `base^exp mod m` by square-and-multiply.
+fn mod_pow(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1u64;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// `a·b mod m` through a 128-bit intermediate.
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
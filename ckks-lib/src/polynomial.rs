@@ -1,12 +1,119 @@
 //! Polynomial backend for fast operations in CKKS contexts
 #![allow(clippy::cast_precision_loss)] // For casting i64 to f64
 use crate::Plaintext;
+use crate::config::Config;
 use alloc::vec::Vec;
 use fhe_core::f64::{round, round_to};
+use fhe_core::rand::distributions::{Distribution, Uniform};
+use fhe_core::secret::MemRange;
 use zeroize::Zeroize;
 
 pub type Coeff = i64;
 
+/// Precomputed Barrett reciprocal for a fixed modulus, avoiding a hardware
+/// division on every reduction.
+///
+/// Mirrors the `fastdiv`-style fixed-divisor optimization: `m = ⌊2^k / q⌋` is
+/// computed once and reused by every [`reduce`](Self::reduce) call against
+/// that modulus.
+#[derive(Debug, Clone, Copy)]
+pub struct FastModulus {
+    q: i64,
+    m: i128,
+    k: u32,
+}
+
+/// Low 64 bits of a `u128`, for the limb decomposition in [`full_mul`].
+const LIMB_MASK: u128 = u64::MAX as u128;
+
+/// Full-width product `a·b` of two 128-bit values as a `(high, low)` pair of
+/// `u128` limbs, composing four 64×64→128 partial products so no
+/// intermediate overflows. Mirrors `FqInt128::full_mul` in `fhe-core`.
+fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    let (a0, a1) = (a & LIMB_MASK, a >> 64);
+    let (b0, b1) = (b & LIMB_MASK, b >> 64);
+
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+
+    let mid = (p00 >> 64) + (p01 & LIMB_MASK) + (p10 & LIMB_MASK);
+    let lo = (p00 & LIMB_MASK) | ((mid & LIMB_MASK) << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Computes `⌊x·m / 2^k⌋` (sign of `x`, `m ≥ 0`) without letting `x * m`
+/// overflow `i128`.
+///
+/// For a realistic ~61-bit NTT-friendly modulus `x` (up to `q²`) and `m`
+/// (Barrett's `⌊2^k / q⌋`) are each wide enough that their product reaches
+/// ~190 bits, well past `i128`'s 127-bit range. The multiply is instead done
+/// on the unsigned magnitude as a 128×128→256-bit widening product via
+/// [`full_mul`], then shifted and re-signed; this truncates the negative
+/// case towards zero rather than `x * m`'s true floor, so the estimate can
+/// be off by one more than the floor-based estimate would be — immaterial,
+/// since [`FastModulus::reduce`]'s correction loops already handle an
+/// estimate off by more than one.
+fn wide_shifted_product(x: i128, m: i128, k: u32) -> i128 {
+    let neg = x < 0;
+    let xu = x.unsigned_abs();
+    let mu = m as u128;
+    let (hi, lo) = full_mul(xu, mu);
+    let shifted = ((hi << (128 - k)) | (lo >> k)) as i128;
+    if neg { -shifted } else { shifted }
+}
+
+impl FastModulus {
+    #[must_use]
+    /// Precomputes the Barrett reciprocal for `q`.
+    pub fn new(q: i64) -> Self {
+        let k = 2 * (64 - (q - 1).leading_zeros());
+        Self {
+            q,
+            m: (1i128 << k) / q as i128,
+            k,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Reduces `x` to its representative in `[0, q)`.
+    ///
+    /// For `x` within the Barrett bound (`|x| < q²`, the case for every caller
+    /// in this module) the Barrett quotient estimate is off by at most a
+    /// couple of units, so the correction below runs only a couple of times;
+    /// it is written as a loop only as a safety net for inputs outside that
+    /// bound.
+    pub fn reduce(&self, x: i128) -> i64 {
+        let q = self.q as i128;
+        let t = wide_shifted_product(x, self.m, self.k);
+        let mut r = x - t * q;
+        while r >= q {
+            r -= q;
+        }
+        while r < 0 {
+            r += q;
+        }
+        r as i64
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the modulus this reciprocal was built for.
+    pub const fn modulus(&self) -> i64 {
+        self.q
+    }
+
+    #[must_use]
+    #[inline]
+    /// Computes `a * b mod q`.
+    pub fn mul(&self, a: i64, b: i64) -> i64 {
+        self.reduce(a as i128 * b as i128)
+    }
+}
+
 #[derive(Debug, Clone, Zeroize)]
 /// Polynomial backend struct for CKKS operations
 pub struct Polynomial {
@@ -106,6 +213,34 @@ impl Polynomial {
         Self::new(result, self.scale() * other.scale())
     }
 
+    #[must_use = "This method does not modify the polynomial, it returns a new one instead"]
+    /// Negacyclic multiplication in `Z_q[x]/(x^N + 1)` accelerated by the NTT.
+    ///
+    /// Thin wrapper over [`multiply_cyclo`](Self::multiply_cyclo) for callers
+    /// that already have a [`Config`] in hand.
+    pub fn multiply_ntt(&self, other: &Self, config: &Config) -> Self {
+        self.multiply_cyclo(other, config.degree_as_power_of_two(), config.modulus())
+    }
+
+    #[must_use = "This method does not modify the polynomial, it returns a new one instead"]
+    /// Negacyclic multiplication in `Z_q[x]/(x^(2^n) + 1)` accelerated by the NTT.
+    ///
+    /// When the modulus `q` is prime with `q ≡ 1 (mod 2·2^n)` a primitive
+    /// `2·2^n`-th root of unity exists and the product is computed in
+    /// `O(m log m)` (`m = 2^n`) via forward/inverse Cooley–Tukey transforms,
+    /// replacing the `O(m^2)` [`multiply`](Self::multiply) followed by
+    /// [`rem_cyclo`](Self::rem_cyclo). The root search, the one expensive part
+    /// of setting up the transform, is memoized per `(n, modulus)` so repeated
+    /// ciphertext multiplies under the same parameters reuse it. Falls back to
+    /// the schoolbook path when no NTT-friendly modulus is available.
+    pub fn multiply_cyclo(&self, other: &Self, n: u32, modulus: i64) -> Self {
+        let m = 1_usize << n;
+        ntt::negacyclic_multiply_cached(self.coeffs(), other.coeffs(), n, m, modulus).map_or_else(
+            || self.multiply(other).rem_cyclo(n, modulus),
+            |coeffs| Self::new(coeffs, self.scale() * other.scale()),
+        )
+    }
+
     #[must_use = "This method does not modify the polynomial, it returns a new one instead"]
     /// Coefficient by coefficient multiplication
     pub fn multiply_coeff(&self, other: &Self) -> Self {
@@ -178,13 +313,22 @@ impl Polynomial {
     /// For P(x) = ∑ a_i x^i, we have:
     ///   R(x) = ∑_{j=0}^{2^n-1}  (∑_{k ≥ 0} (-1)^k a_{j+k·2^n}) x^j.
     pub fn rem_cyclo(&self, n: u32, modulus: i64) -> Polynomial {
+        let fast = FastModulus::new(modulus);
+        self.rem_cyclo_fast(n, &fast)
+    }
+
+    #[must_use = "This method does not modify the polynomial, it returns a new one instead"]
+    /// As [`rem_cyclo`](Self::rem_cyclo), but takes a precomputed
+    /// [`FastModulus`] so the Barrett reciprocal is shared across calls
+    /// instead of a hardware division running on every coefficient.
+    pub fn rem_cyclo_fast(&self, n: u32, fast: &FastModulus) -> Polynomial {
         let m = 1_usize.checked_shl(n).unwrap();
         let mut r = vec![0_i64; m];
         // For each coefficient a_i, we "fold" according to i mod m with a sign (-1)^(i/m)
         let mut j = 0; // i % m
         let mut k = 1; // if (i / m) % 2 == 0 { 1 } else { -1 }
         for &coeff in &self.coeffs {
-            r[j] = (r[j] + coeff * k).rem_euclid(modulus);
+            r[j] = fast.reduce(r[j] as i128 + coeff as i128 * k as i128);
             j += 1;
             if j >= m {
                 j = 0;
@@ -207,6 +351,22 @@ impl Polynomial {
         Self::new(result_coeffs, self.scale())
     }
 
+    #[must_use = "This method does not modify the polynomial, it returns a new one instead"]
+    /// As [`mod_reduce`](Self::mod_reduce), but reduces through a precomputed
+    /// [`FastModulus`] instead of a hardware `%` per coefficient. Note this
+    /// canonicalizes negative coefficients into `[0, modulus)`, unlike
+    /// [`mod_reduce`](Self::mod_reduce)'s `%`, which keeps their sign.
+    pub fn mod_reduce_fast(&self, fast: &FastModulus) -> Self {
+        let result_coeffs = self
+            .coeffs()
+            .iter()
+            .map(|&coeff| fast.reduce(coeff as i128))
+            .filter(|&coeff| coeff != 0)
+            .collect();
+
+        Self::new(result_coeffs, self.scale())
+    }
+
     #[must_use]
     /// Encodes a series of plaintext values into a polynomial
     pub fn encode(plaintext: &[Plaintext], scale: f64) -> Self {
@@ -235,11 +395,328 @@ impl Polynomial {
             })
             .collect()
     }
+
+    #[must_use]
+    /// Evaluates `self` at `x` modulo `modulus`, via Horner's rule.
+    ///
+    /// Coefficients are read low-degree first, matching [`coeffs`](Self::coeffs).
+    /// This treats `self.coeffs()` as the coefficients of an ordinary
+    /// univariate polynomial over `Z_modulus`, independent of the ring/scale
+    /// semantics the rest of this type uses for CKKS plaintexts — the caller
+    /// is [`split_secret`], whose shares [`reconstruct`] recombines by Lagrange
+    /// interpolation.
+    pub fn evaluate(&self, x: i64, modulus: i64) -> i64 {
+        let mut acc = 0_i64;
+        for &coeff in self.coeffs.iter().rev() {
+            acc = (acc.rem_euclid(modulus) * x.rem_euclid(modulus)).rem_euclid(modulus);
+            acc = (acc + coeff).rem_euclid(modulus);
+        }
+        acc
+    }
+}
+
+/// Modular inverse of `a` modulo the prime `modulus`, via the extended
+/// Euclidean algorithm, returned in `[0, modulus)`.
+fn inv_mod(a: i64, modulus: i64) -> i64 {
+    let (mut old_r, mut r) = (a.rem_euclid(modulus), modulus);
+    let (mut old_s, mut s) = (1_i64, 0_i64);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    old_s.rem_euclid(modulus)
+}
+
+/// Splits `secret` into `n_shares` Shamir shares reconstructible from any
+/// `threshold + 1` of them.
+///
+/// Samples a degree-`threshold` polynomial over `Z_modulus` with `secret` as
+/// its constant term and uniform random masking coefficients, then returns
+/// the evaluations `(i, f(i))` for `i = 1..=n_shares` as shares. Pair with
+/// [`reconstruct`] to recover the secret; this is the scalar analogue of
+/// [`crate::key::share_secret_key`]'s per-coefficient key sharing.
+///
+/// # Panics
+///
+/// Panics if `threshold >= n_shares`, or if randomness fails.
+#[must_use]
+pub fn split_secret(
+    secret: i64,
+    threshold: usize,
+    n_shares: usize,
+    modulus: i64,
+) -> Vec<(i64, i64)> {
+    assert!(
+        threshold < n_shares,
+        "threshold must be smaller than the number of shares"
+    );
+
+    #[allow(clippy::range_minus_one)]
+    let u = Uniform::<i64>::new(0..=modulus - 1);
+    let mut coeffs = Vec::with_capacity(threshold + 1);
+    coeffs.push(secret.rem_euclid(modulus));
+    for _ in 0..threshold {
+        coeffs.push(u.sample().expect("failed to sample masking coefficient"));
+    }
+    let f = Polynomial::new(coeffs, 1.0);
+
+    (1..=n_shares as i64)
+        .map(|i| (i, f.evaluate(i, modulus)))
+        .collect()
+}
+
+/// Reconstructs the secret from `threshold + 1` or more `(index, value)`
+/// shares produced by [`split_secret`], via Lagrange interpolation at `x = 0`.
+#[must_use]
+pub fn reconstruct(shares: &[(i64, i64)], modulus: i64) -> i64 {
+    let mut secret = 0_i64;
+    for &(xi, yi) in shares {
+        let mut num = 1_i64;
+        let mut den = 1_i64;
+        for &(xj, _) in shares {
+            if xi == xj {
+                continue;
+            }
+            num = (num * (0 - xj).rem_euclid(modulus)).rem_euclid(modulus);
+            den = (den * (xi - xj).rem_euclid(modulus)).rem_euclid(modulus);
+        }
+        let lambda = (num * inv_mod(den, modulus)).rem_euclid(modulus);
+        secret = (secret + yi.rem_euclid(modulus) * lambda).rem_euclid(modulus);
+    }
+    secret
+}
+
+impl MemRange for Polynomial {
+    #[inline]
+    fn mem_range(&self) -> (*const u8, usize) {
+        // The sensitive material is the coefficient buffer itself; locking its
+        // heap pages keeps secret/noise coefficients off swap.
+        (
+            self.coeffs.as_ptr().cast(),
+            self.coeffs.len() * core::mem::size_of::<Coeff>(),
+        )
+    }
+}
+
+/// A secret-key or noise [`Polynomial`] whose coefficient buffer is
+/// memory-locked for its whole lifetime.
+///
+/// A plain [`Polynomial`] leaves its coefficients in a `Vec` that `Zeroize`
+/// wipes on drop but that the kernel may still have paged to swap, or that a
+/// prior reallocation may have copied into freed heap pages before the wipe —
+/// the gap `threshold_crypto` closes by `mlock`ing secret buffers.
+/// `SecurePolynomial` routes the same buffer through
+/// [`Secret`](fhe_core::secret::Secret), which `mlock`s the pages on
+/// construction and `munlock`s + zeroizes them on drop; syscall failures
+/// surface as [`SecretError`](fhe_core::secret::SecretError) (errno, address,
+/// byte count) rather than panicking. It derefs immutably only, so the
+/// buffer can never grow or reallocate once locked.
+///
+/// Use this for secret-key and noise polynomials; public ciphertext
+/// polynomials should stay on the plain, unlocked [`Polynomial`].
+///
+/// Only compiled with the `mlock` feature; without it there is nothing to
+/// lock, so the plain [`Polynomial`] should be used instead.
+#[cfg(feature = "mlock")]
+pub struct SecurePolynomial(fhe_core::secret::Secret<Polynomial>);
+
+#[cfg(feature = "mlock")]
+impl SecurePolynomial {
+    /// Locks `poly`'s coefficient buffer and wraps it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::MlockFailed`](fhe_core::secret::SecretError::MlockFailed)
+    /// when the OS refuses to lock the buffer (e.g. `RLIMIT_MEMLOCK` exceeded).
+    #[inline]
+    pub fn new(poly: Polynomial) -> Result<Self, fhe_core::secret::SecretError> {
+        fhe_core::secret::Secret::new(poly).map(Self)
+    }
+}
+
+#[cfg(feature = "mlock")]
+impl core::ops::Deref for SecurePolynomial {
+    type Target = Polynomial;
+    #[inline]
+    fn deref(&self) -> &Polynomial {
+        &self.0
+    }
+}
+
+/// Number-theoretic transform over `Z_q` for negacyclic convolution.
+///
+/// All routines take the modulus `q` at run time and use a precomputed Barrett
+/// reciprocal for the per-butterfly reductions, avoiding hardware division.
+mod ntt {
+    use super::{Coeff, FastModulus};
+    use alloc::vec::Vec;
+
+    /// Barrett reducer for a fixed modulus, shared with
+    /// [`rem_cyclo_fast`](super::Polynomial::rem_cyclo_fast) and
+    /// [`mod_reduce_fast`](super::Polynomial::mod_reduce_fast).
+    type Barrett = FastModulus;
+
+    fn powmod(bar: &Barrett, mut base: i64, mut exp: u64) -> i64 {
+        base = base.rem_euclid(bar.modulus());
+        let mut result = 1i64;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = bar.mul(result, base);
+            }
+            base = bar.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Finds a primitive `2m`-th root of unity modulo `q`, or `None` when `q`
+    /// is not `≡ 1 (mod 2m)`.
+    fn primitive_root_2m(bar: &Barrett, m: usize) -> Option<i64> {
+        let two_m = 2 * m as i64;
+        if (bar.modulus() - 1) % two_m != 0 {
+            return None;
+        }
+        let exp = ((bar.modulus() - 1) / two_m) as u64;
+        (2..bar.modulus())
+            .map(|g| powmod(bar, g, exp))
+            .find(|&psi| powmod(bar, psi, m as u64) == bar.modulus() - 1)
+    }
+
+    /// Returns the primitive `2m`-th root of unity for `(n, q)`, memoizing the
+    /// search so repeated multiplies under the same parameters reuse it.
+    ///
+    /// With the `std` feature the result is cached in a process-wide table
+    /// keyed by `(n, q)`; without it, the root is recomputed on each call,
+    /// since a `no_std` target has no global allocator-backed cache.
+    #[cfg(feature = "std")]
+    fn cached_psi(bar: &Barrett, n: u32, m: usize) -> Option<i64> {
+        use std::collections::HashMap;
+        use std::sync::{Mutex, OnceLock};
+
+        static CACHE: OnceLock<Mutex<HashMap<(u32, i64), Option<i64>>>> = OnceLock::new();
+        let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let key = (n, bar.modulus());
+        if let Some(&v) = cache.lock().unwrap().get(&key) {
+            return v;
+        }
+        let v = primitive_root_2m(bar, m);
+        cache.lock().unwrap().insert(key, v);
+        v
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn cached_psi(bar: &Barrett, _n: u32, m: usize) -> Option<i64> {
+        primitive_root_2m(bar, m)
+    }
+
+    /// In-place Cooley–Tukey NTT of `a` (length a power of two) with root
+    /// `omega`, using bit-reversal ordering.
+    fn transform(bar: &Barrett, a: &mut [i64], omega: i64) {
+        let n = a.len();
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j ^= bit;
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+        let mut len = 2;
+        while len <= n {
+            let wlen = powmod(bar, omega, (n / len) as u64);
+            let mut i = 0;
+            while i < n {
+                let mut w = 1i64;
+                for k in 0..len / 2 {
+                    let u = a[i + k];
+                    let v = bar.mul(a[i + k + len / 2], w);
+                    a[i + k] = (u + v).rem_euclid(bar.modulus());
+                    a[i + k + len / 2] = (u - v).rem_euclid(bar.modulus());
+                    w = bar.mul(w, wlen);
+                }
+                i += len;
+            }
+            len <<= 1;
+        }
+    }
+
+    /// Computes `a * b` in `Z_q[x]/(x^m + 1)` via the negacyclic NTT, returning
+    /// the `m` reduced coefficients, or `None` when no suitable root exists.
+    pub fn negacyclic_multiply(a: &[Coeff], b: &[Coeff], m: usize, q: i64) -> Option<Vec<Coeff>> {
+        let bar = Barrett::new(q);
+        let psi = primitive_root_2m(&bar, m)?;
+        negacyclic_multiply_with_psi(a, b, m, &bar, psi)
+    }
+
+    /// As [`negacyclic_multiply`], but sources the primitive root from the
+    /// `(n, q)`-keyed [`cached_psi`] table instead of searching for it afresh.
+    pub fn negacyclic_multiply_cached(
+        a: &[Coeff],
+        b: &[Coeff],
+        n: u32,
+        m: usize,
+        q: i64,
+    ) -> Option<Vec<Coeff>> {
+        let bar = Barrett::new(q);
+        let psi = cached_psi(&bar, n, m)?;
+        negacyclic_multiply_with_psi(a, b, m, &bar, psi)
+    }
+
+    /// Shared core of [`negacyclic_multiply`]/[`negacyclic_multiply_cached`]
+    /// once a primitive `2m`-th root of unity `psi` is in hand.
+    fn negacyclic_multiply_with_psi(
+        a: &[Coeff],
+        b: &[Coeff],
+        m: usize,
+        bar: &Barrett,
+        psi: i64,
+    ) -> Option<Vec<Coeff>> {
+        let psi_inv = powmod(bar, psi, 2 * m as u64 - 1);
+        let m_inv = powmod(bar, m as i64, (bar.modulus() - 2) as u64);
+        let omega = bar.mul(psi, psi);
+        let omega_inv = bar.mul(psi_inv, psi_inv);
+
+        // Twist a_i ← a_i·ψ^i so the cyclic NTT realises the negacyclic product.
+        let twist = |src: &[Coeff]| -> Vec<i64> {
+            let mut v = alloc::vec![0i64; m];
+            let mut p = 1i64;
+            for (i, slot) in v.iter_mut().enumerate() {
+                let c = src.get(i).copied().unwrap_or(0).rem_euclid(bar.modulus());
+                *slot = bar.mul(c, p);
+                p = bar.mul(p, psi);
+            }
+            v
+        };
+
+        let mut fa = twist(a);
+        let mut fb = twist(b);
+        transform(bar, &mut fa, omega);
+        transform(bar, &mut fb, omega);
+        for i in 0..m {
+            fa[i] = bar.mul(fa[i], fb[i]);
+        }
+        transform(bar, &mut fa, omega_inv);
+
+        let mut coeffs = Vec::with_capacity(m);
+        let mut p = 1i64;
+        for value in &fa {
+            coeffs.push(bar.mul(bar.mul(*value, m_inv), p));
+            p = bar.mul(p, psi_inv);
+        }
+        Some(coeffs)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::GaussianDistribParams;
 
     #[test]
     fn test_add() {
@@ -284,6 +761,52 @@ mod tests {
         assert_eq!(result.coeffs(), &[1, 2, 1, 2]);
     }
 
+    #[test]
+    fn test_fast_modulus_matches_rem_euclid() {
+        let fast = FastModulus::new(1_000_000_007);
+        for x in [-5_000_000_000_i128, -1, 0, 1, 999_999_999, 5_000_000_000] {
+            assert_eq!(
+                fast.reduce(x),
+                i64::try_from(x.rem_euclid(1_000_000_007)).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_fast_modulus_matches_rem_euclid_for_large_ntt_prime() {
+        // 2^61 - 1, a Mersenne prime in the range `negacyclic_multiply` is
+        // meant to serve: `k` lands around 122 bits, so `x * m` reaches ~190
+        // bits — wide enough that a plain `i128` multiply would panic (debug)
+        // or silently wrap (release) before `reduce` ever ran.
+        const Q: i64 = 2_305_843_009_213_693_951;
+        let fast = FastModulus::new(Q);
+        let qq = i128::from(Q) * i128::from(Q);
+        for x in [-qq + 1, -5, -1, 0, 1, 5, qq - 1] {
+            assert_eq!(fast.reduce(x), i64::try_from(x.rem_euclid(i128::from(Q))).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_mod_reduce_fast_matches_rem_cyclo_fast_semantics() {
+        let fast = FastModulus::new(3);
+        let poly = Polynomial::new(vec![1, 2, 3, 4, 5], 1.0);
+
+        let result = poly.mod_reduce_fast(&fast);
+        // Unlike `mod_reduce`'s `%`, negative coefficients would canonicalize
+        // into [0, 3); none arise here, so the non-zero results line up.
+        assert_eq!(result.coeffs(), &[1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn test_rem_cyclo_fast_matches_rem_cyclo() {
+        let fast = FastModulus::new(100_000_007);
+        let poly = Polynomial::new(vec![4, 2, 0, 5, 3], 1.0);
+
+        let result = poly.rem_cyclo_fast(2, &fast);
+        let expected = poly.rem_cyclo(2, 100_000_007);
+        assert_eq!(result.coeffs(), expected.coeffs());
+    }
+
     #[test]
     fn test_encode_decode() {
         let plaintext = vec![1.21, 2.0, 3.0];
@@ -305,6 +828,48 @@ mod tests {
         assert_eq!(result.coeffs(), &[1, 0, 0]);
     }
 
+    #[test]
+    fn test_multiply_ntt_matches_schoolbook() {
+        // 17 is prime with 2·8 = 16 | 16, so a negacyclic NTT is available.
+        let config = Config::new(8, 17, GaussianDistribParams::TC128);
+        let a = Polynomial::new(vec![1, 2, 3, 4, 5, 6, 7, 8], 1.0);
+        let b = Polynomial::new(vec![8, 7, 6, 5, 4, 3, 2, 1], 1.0);
+        let ntt = a.multiply_ntt(&b, &config);
+        let school = a.multiply(&b).rem_cyclo(3, 17);
+        assert_eq!(ntt.coeffs(), school.coeffs());
+    }
+
+    #[test]
+    fn test_multiply_ntt_fallback() {
+        // 13 is not ≡ 1 (mod 16), so this exercises the schoolbook fallback.
+        let config = Config::new(8, 13, GaussianDistribParams::TC128);
+        let a = Polynomial::new(vec![1, 0, 2, 0, 0, 0, 0, 0], 1.0);
+        let b = Polynomial::new(vec![0, 3, 0, 0, 0, 0, 0, 0], 1.0);
+        let ntt = a.multiply_ntt(&b, &config);
+        let school = a.multiply(&b).rem_cyclo(3, 13);
+        assert_eq!(ntt.coeffs(), school.coeffs());
+    }
+
+    #[test]
+    fn test_multiply_cyclo_matches_schoolbook() {
+        let a = Polynomial::new(vec![1, 2, 3, 4, 5, 6, 7, 8], 1.0);
+        let b = Polynomial::new(vec![8, 7, 6, 5, 4, 3, 2, 1], 1.0);
+        let cyclo = a.multiply_cyclo(&b, 3, 17);
+        let school = a.multiply(&b).rem_cyclo(3, 17);
+        assert_eq!(cyclo.coeffs(), school.coeffs());
+    }
+
+    #[test]
+    fn test_multiply_cyclo_reuses_cached_root() {
+        // A second call with the same (n, modulus) should hit the memoized
+        // root search and produce the same result as the first call.
+        let a = Polynomial::new(vec![1, 2, 3, 4, 5, 6, 7, 8], 1.0);
+        let b = Polynomial::new(vec![8, 7, 6, 5, 4, 3, 2, 1], 1.0);
+        let first = a.multiply_cyclo(&b, 3, 17);
+        let second = a.multiply_cyclo(&b, 3, 17);
+        assert_eq!(first.coeffs(), second.coeffs());
+    }
+
     #[test]
     fn test_rem_cyclo() {
         let n = 2;
@@ -314,4 +879,44 @@ mod tests {
         let expected = vec![1, 2, 0, 5];
         assert_eq!(result.coeffs(), expected);
     }
+
+    #[test]
+    fn test_evaluate_matches_direct_computation() {
+        let modulus = 101;
+        let poly = Polynomial::new(vec![3, 5, 7], 1.0);
+        // f(2) = 3 + 5·2 + 7·4 = 41
+        assert_eq!(poly.evaluate(2, modulus), 41);
+    }
+
+    #[test]
+    fn test_split_secret_reconstructs_with_threshold_shares() {
+        let modulus = 100_000_007;
+        let secret = 1234;
+        let shares = split_secret(secret, 2, 5, modulus);
+        assert_eq!(shares.len(), 5);
+        assert_eq!(reconstruct(&shares[..3], modulus), secret);
+        assert_eq!(reconstruct(&shares[1..4], modulus), secret);
+    }
+
+    #[test]
+    fn test_split_secret_full_set_reconstructs() {
+        let modulus = 100_000_007;
+        let secret = 42;
+        let shares = split_secret(secret, 1, 4, modulus);
+        assert_eq!(reconstruct(&shares, modulus), secret);
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold must be smaller")]
+    fn test_split_secret_rejects_threshold_at_least_n() {
+        split_secret(1, 3, 3, 100_000_007);
+    }
+
+    #[cfg(feature = "mlock")]
+    #[test]
+    fn test_secure_polynomial_derefs_to_same_coeffs() {
+        let poly = Polynomial::new(vec![1, 2, 3], 1.0);
+        let secure = SecurePolynomial::new(poly).unwrap();
+        assert_eq!(secure.coeffs(), &[1, 2, 3]);
+    }
 }
@@ -0,0 +1,124 @@
+//! Discrete Gaussian sampling for CKKS error polynomials.
+//!
+//! [`GaussianSampler`] turns a [`GaussianDistribParams`] into a concrete noise
+//! source. It is a cumulative-distribution-table (CDT) sampler: the truncated
+//! support `[round(mu-beta), round(mu+beta)]` is finite (guaranteed by `beta`),
+//! so the scaled cumulative probabilities can be precomputed once as `u64`
+//! thresholds and each draw is a single uniform `u64` resolved against the
+//! table.
+//!
+//! Randomness is supplied by the caller as any [`Distribution`] that yields a
+//! uniform `u64` — the same abstraction the rest of the crate samples through,
+//! so a seedable CSPRNG (e.g. a ChaCha stream) can be dropped in for
+//! reproducible tests while the default `getrandom`-backed source is used in
+//! production.
+
+use alloc::vec::Vec;
+
+use fhe_core::f64::round;
+use fhe_core::rand::RandResult;
+use fhe_core::rand::distributions::Distribution;
+
+use crate::config::GaussianDistribParams;
+
+/// A CDT sampler for a truncated discrete Gaussian.
+pub struct GaussianSampler {
+    /// Lowest integer of the truncated support.
+    support_min: i64,
+    /// `thresholds[k]` is the scaled cumulative probability `P(X ≤ support_min + k)`,
+    /// monotonically non-decreasing with the last entry pinned to `u64::MAX`.
+    thresholds: Vec<u64>,
+}
+
+impl GaussianSampler {
+    #[must_use]
+    /// Builds the cumulative table for `gdp`.
+    ///
+    /// Weights the support by `exp(-(x-mu)²/(2σ²))`, accumulates, and normalizes
+    /// the running sum into `u64` thresholds over the truncated support.
+    pub fn new(gdp: GaussianDistribParams) -> Self {
+        let (mu, sigma, beta) = (gdp.mu(), gdp.sigma(), gdp.beta());
+        let support_min = round(mu - beta);
+        let support_max = round(mu + beta);
+        let len = (support_max - support_min + 1) as usize;
+
+        let denom = 2.0 * sigma * sigma;
+        let mut weights = Vec::with_capacity(len);
+        let mut total = 0.0;
+        for k in 0..len {
+            let x = (support_min + k as i64) as f64 - mu;
+            let w = libm::exp(-(x * x) / denom);
+            total += w;
+            weights.push(total);
+        }
+
+        // Normalize the running totals into the full u64 range; the final
+        // threshold is pinned to u64::MAX so every uniform draw is covered.
+        let mut thresholds = Vec::with_capacity(len);
+        let scale = u64::MAX as f64 / total;
+        for (k, &cum) in weights.iter().enumerate() {
+            if k + 1 == len {
+                thresholds.push(u64::MAX);
+            } else {
+                thresholds.push((cum * scale) as u64);
+            }
+        }
+
+        Self {
+            support_min,
+            thresholds,
+        }
+    }
+
+    /// Draws a single sample by binary-searching the cumulative table.
+    ///
+    /// # Errors
+    ///
+    /// Returns the sampler error if `rng` fails to produce a uniform value.
+    pub fn sample<D: Distribution<Output = u64>>(&self, rng: &D) -> RandResult<i64> {
+        let r = rng.sample()?;
+        // First threshold the draw does not exceed.
+        let mut lo = 0usize;
+        let mut hi = self.thresholds.len() - 1;
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if r <= self.thresholds[mid] {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        Ok(self.support_min + lo as i64)
+    }
+
+    /// Draws a single sample in constant time, scanning the whole table with
+    /// branchless conditional selects rather than an early-exit search.
+    ///
+    /// The running index advances once per threshold the draw still exceeds, so
+    /// the memory-access and branch pattern is independent of the sampled value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the sampler error if `rng` fails to produce a uniform value.
+    pub fn sample_constant_time<D: Distribution<Output = u64>>(&self, rng: &D) -> RandResult<i64> {
+        let r = rng.sample()?;
+        let mut index: i64 = 0;
+        for &threshold in &self.thresholds {
+            // `(r > threshold)` is 0 or 1; add it without branching.
+            index += i64::from(r > threshold);
+        }
+        Ok(self.support_min + index)
+    }
+
+    /// Fills `out` with independent samples, one per polynomial coefficient.
+    ///
+    /// # Errors
+    ///
+    /// Returns the sampler error if `rng` fails to produce a uniform value.
+    pub fn fill<D: Distribution<Output = u64>>(&self, rng: &D, out: &mut [i64]) -> RandResult<()> {
+        for slot in out.iter_mut() {
+            *slot = self.sample(rng)?;
+        }
+        Ok(())
+    }
+}
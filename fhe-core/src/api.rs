@@ -1,5 +1,29 @@
 //! This module defines the core API of FHE cryptosystems.
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+pub mod circuit;
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Selects the wire format used by [`CryptoSystem::serialize_ciphertext`] and
+/// its key-serialization counterparts.
+pub enum SerFormat {
+    /// The scheme's compact native binary encoding.
+    ///
+    /// Smallest on the wire; use this between two instances of this codebase
+    /// (e.g. a client writing a result for a server of the same version).
+    #[default]
+    Binary,
+    /// A portable JSON envelope around the same bytes.
+    ///
+    /// Larger, but legible and easy to hand to a client outside this
+    /// codebase.
+    Json,
+}
+
 /// A trait that defines the operations that can be performed on the ciphertexts.
 pub trait Operation {}
 impl Operation for () {}
@@ -8,7 +32,19 @@ impl Operation for () {}
 pub trait Arity1Operation: Operation {}
 impl Arity1Operation for () {}
 /// A trait that defines the operations that can be performed on two ciphertexts.
-pub trait Arity2Operation: Operation {}
+pub trait Arity2Operation: Operation {
+    /// Whether this operation is a ciphertext×ciphertext multiplication.
+    ///
+    /// Such multiplications roughly double a ciphertext's polynomial degree,
+    /// so [`circuit::Circuit::evaluate`] calls
+    /// [`CryptoSystem::relinearize`] right after any operation that reports
+    /// `true` here, to bring it back to canonical form before it feeds a
+    /// later node. Defaults to `false`; schemes should override it for their
+    /// `Mul` variant.
+    fn is_multiplication(&self) -> bool {
+        false
+    }
+}
 impl Arity2Operation for () {}
 
 /// A trait that defines the core API of a FHE cryptosystem.
@@ -62,6 +98,68 @@ pub trait CryptoSystem {
 
     /// Relinearizes a ciphertext.
     fn relinearize(&self, ciphertext: &mut Self::Ciphertext);
+
+    /// `ciphertext`'s current position in the modulus chain.
+    ///
+    /// A fresh encryption starts at a scheme-chosen maximum and the level
+    /// decreases towards `0` as [`rescale`](Self::rescale) and
+    /// [`mod_switch_to`](Self::mod_switch_to) consume primes off the chain.
+    /// Schemes without a modulus chain (or test doubles) may always return
+    /// the same constant.
+    fn level(&self, ciphertext: &Self::Ciphertext) -> u32;
+
+    /// Divides `ciphertext` by the top modulus of the chain and decrements its
+    /// level, bringing a post-multiplication scale of roughly `s²` back down
+    /// to `≈s`.
+    ///
+    /// # Panics
+    ///
+    /// Implementations should panic if `ciphertext` is already at level `0`,
+    /// which cannot be rescaled further.
+    fn rescale(&self, ciphertext: &mut Self::Ciphertext);
+
+    /// Drops `ciphertext` down to `level` by discarding the extra RNS limbs,
+    /// without touching the scale.
+    ///
+    /// Used to align two operands onto the same level before `operate2`,
+    /// since mismatched-level operands must error (or be aligned) rather
+    /// than silently produce a corrupt result.
+    ///
+    /// # Panics
+    ///
+    /// Implementations should panic if `level` is above `ciphertext`'s
+    /// current level.
+    fn mod_switch_to(&self, ciphertext: &mut Self::Ciphertext, level: u32);
+
+    #[cfg(feature = "alloc")]
+    /// The error produced when ciphertext or key bytes cannot be parsed back
+    /// by [`deserialize_ciphertext`](Self::deserialize_ciphertext).
+    type SerError: core::fmt::Debug;
+
+    #[cfg(feature = "alloc")]
+    /// Serializes `ciphertext` in `format`, so it can be written to disk or
+    /// sent to a peer that later reloads it with
+    /// [`deserialize_ciphertext`](Self::deserialize_ciphertext).
+    fn serialize_ciphertext(&self, ciphertext: &Self::Ciphertext, format: SerFormat) -> Vec<u8>;
+
+    #[cfg(feature = "alloc")]
+    /// Reconstructs a ciphertext previously produced by
+    /// [`serialize_ciphertext`](Self::serialize_ciphertext).
+    fn deserialize_ciphertext(
+        &self,
+        bytes: &[u8],
+        format: SerFormat,
+    ) -> Result<Self::Ciphertext, Self::SerError>;
+
+    #[cfg(feature = "alloc")]
+    /// Serializes this system's public key in `format`, so a client can hand
+    /// a server the key it encrypted under.
+    fn serialize_public_key(&self, format: SerFormat) -> Vec<u8>;
+
+    #[cfg(feature = "alloc")]
+    /// Serializes this system's relinearization key in `format`, or `None` if
+    /// the scheme does not use one.
+    fn serialize_relin_key(&self, format: SerFormat) -> Option<Vec<u8>>;
 }
 
 #[allow(dead_code)]
@@ -130,9 +228,65 @@ mod private {
         }
 
         fn relinearize(&self, _ciphertext: &mut Self::Ciphertext) {}
+
+        fn level(&self, _ciphertext: &Self::Ciphertext) -> u32 {
+            0
+        }
+
+        fn rescale(&self, _ciphertext: &mut Self::Ciphertext) {}
+
+        fn mod_switch_to(&self, _ciphertext: &mut Self::Ciphertext, _level: u32) {}
+
+        #[cfg(feature = "alloc")]
+        type SerError = ();
+
+        #[cfg(feature = "alloc")]
+        fn serialize_ciphertext(
+            &self,
+            _ciphertext: &Self::Ciphertext,
+            _format: super::SerFormat,
+        ) -> alloc::vec::Vec<u8> {
+            alloc::vec::Vec::new()
+        }
+
+        #[cfg(feature = "alloc")]
+        fn deserialize_ciphertext(
+            &self,
+            _bytes: &[u8],
+            _format: super::SerFormat,
+        ) -> Result<Self::Ciphertext, Self::SerError> {
+            Ok(TestCiphertext {
+                data: TestPlaintext {},
+            })
+        }
+
+        #[cfg(feature = "alloc")]
+        fn serialize_public_key(&self, _format: super::SerFormat) -> alloc::vec::Vec<u8> {
+            alloc::vec::Vec::new()
+        }
+
+        #[cfg(feature = "alloc")]
+        fn serialize_relin_key(&self, _format: super::SerFormat) -> Option<alloc::vec::Vec<u8>> {
+            None
+        }
     }
 
     // Assert that CryptoSystem is `dyn` compatible.
+    #[cfg(feature = "alloc")]
+    fn any_operation<C, P>(
+        _system: &dyn CryptoSystem<
+            Ciphertext = C,
+            Plaintext = P,
+            Operation1 = (),
+            Operation2 = Op,
+            SerError = (),
+        >,
+        other_param: u8,
+    ) -> u8 {
+        other_param
+    }
+
+    #[cfg(not(feature = "alloc"))]
     fn any_operation<C, P>(
         _system: &dyn CryptoSystem<Ciphertext = C, Plaintext = P, Operation1 = (), Operation2 = Op>,
         other_param: u8,
@@ -0,0 +1,297 @@
+//! DAG-based circuit evaluation with automatic relinearization.
+//!
+//! [`CryptoSystem`] only offers `operate1`/`operate2` one call at a time,
+//! forcing callers to manually interleave [`relinearize`](CryptoSystem::relinearize)
+//! after every ciphertext×ciphertext multiplication. [`Circuit`] instead
+//! builds a DAG once — [`Circuit::input`], [`Circuit::op1`] and
+//! [`Circuit::op2`] each return the [`NodeId`] of the node they created,
+//! which can be reused as an operand any number of times — and
+//! [`Circuit::evaluate`] walks it in construction order (always a valid
+//! topological order, since every operand is an earlier node), evaluating
+//! each node exactly once and relinearizing automatically after any
+//! multiplication.
+
+use alloc::vec::Vec;
+
+use super::{Arity2Operation, CryptoSystem};
+
+/// The identifier of a node within a [`Circuit`], returned by
+/// [`Circuit::input`], [`Circuit::op1`] and [`Circuit::op2`] to be reused as
+/// an operand of later nodes.
+pub type NodeId = usize;
+
+/// One node of a [`Circuit`] DAG.
+#[derive(Debug, Clone, Copy)]
+enum CircuitNode<Op1, Op2> {
+    /// The `idx`-th element of the input slice passed to [`Circuit::evaluate`].
+    Input(usize),
+    /// A unary operation applied to an earlier node.
+    Op1(Op1, NodeId),
+    /// A binary operation applied to two earlier nodes.
+    Op2(Op2, NodeId, NodeId),
+}
+
+/// A reusable computation graph over a [`CryptoSystem`]'s ciphertexts.
+///
+/// Built once with [`input`](Self::input)/[`op1`](Self::op1)/[`op2`](Self::op2),
+/// a `Circuit` can be [`evaluate`](Self::evaluate)d against any number of
+/// distinct input slices without rebuilding the graph.
+#[derive(Debug, Clone)]
+pub struct Circuit<Op1, Op2> {
+    nodes: Vec<CircuitNode<Op1, Op2>>,
+}
+
+impl<Op1, Op2> Default for Circuit<Op1, Op2> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Op1, Op2> Circuit<Op1, Op2> {
+    #[must_use]
+    #[inline]
+    /// Creates an empty circuit.
+    pub const fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    #[inline]
+    /// Appends a node that reads the `idx`-th element of the input slice
+    /// passed to [`evaluate`](Self::evaluate).
+    pub fn input(&mut self, idx: usize) -> NodeId {
+        self.push(CircuitNode::Input(idx))
+    }
+
+    #[inline]
+    /// Appends a unary operation applied to an earlier node.
+    pub fn op1(&mut self, op: Op1, node: NodeId) -> NodeId {
+        self.push(CircuitNode::Op1(op, node))
+    }
+
+    #[inline]
+    /// Appends a binary operation applied to two earlier nodes.
+    pub fn op2(&mut self, op: Op2, lhs: NodeId, rhs: NodeId) -> NodeId {
+        self.push(CircuitNode::Op2(op, lhs, rhs))
+    }
+
+    #[inline]
+    fn push(&mut self, node: CircuitNode<Op1, Op2>) -> NodeId {
+        let id = self.nodes.len();
+        self.nodes.push(node);
+        id
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the number of nodes in the circuit.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns `true` if the circuit has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    #[must_use]
+    /// The longest chain of multiplications (per [`Arity2Operation::is_multiplication`])
+    /// along any path from an input to the circuit's output, i.e. the minimum
+    /// modulus-chain depth a [`CryptoSystem`] must support to evaluate it.
+    pub fn multiplicative_depth(&self) -> usize
+    where
+        Op2: Arity2Operation,
+    {
+        let mut depth: Vec<usize> = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let d = match node {
+                CircuitNode::Input(_) => 0,
+                CircuitNode::Op1(_, input) => depth[*input],
+                CircuitNode::Op2(op, lhs, rhs) => {
+                    let base = depth[*lhs].max(depth[*rhs]);
+                    if op.is_multiplication() {
+                        base + 1
+                    } else {
+                        base
+                    }
+                }
+            };
+            depth.push(d);
+        }
+        depth.last().copied().unwrap_or(0)
+    }
+
+    #[must_use]
+    /// Evaluates the circuit against `inputs`, returning the ciphertext of
+    /// its last node.
+    ///
+    /// Nodes are evaluated in construction order into a results buffer, so
+    /// each is computed exactly once even if referenced by several later
+    /// nodes; every ciphertext×ciphertext multiplication is automatically
+    /// followed by [`relinearize`](CryptoSystem::relinearize) so the result
+    /// stays in canonical form for the next operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the circuit is empty, or if an [`input`](Self::input) node
+    /// indexes past the end of `inputs`.
+    pub fn evaluate<C>(&self, cs: &C, inputs: &[C::Ciphertext]) -> C::Ciphertext
+    where
+        C: CryptoSystem<Operation1 = Op1, Operation2 = Op2>,
+        C::Ciphertext: Clone,
+        Op1: Copy,
+        Op2: Copy + Arity2Operation,
+    {
+        let mut results: Vec<C::Ciphertext> = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let value = match *node {
+                CircuitNode::Input(idx) => inputs[idx].clone(),
+                CircuitNode::Op1(op, input) => cs.operate1(op, &results[input]),
+                CircuitNode::Op2(op, lhs, rhs) => {
+                    let mut value = cs.operate2(op, &results[lhs], &results[rhs]);
+                    if op.is_multiplication() {
+                        cs.relinearize(&mut value);
+                    }
+                    value
+                }
+            };
+            results.push(value);
+        }
+        results.pop().expect("circuit must have at least one node")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{Operation, SerFormat};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct TestCiphertext(i64);
+
+    #[derive(Debug, Clone, Copy)]
+    enum Op2 {
+        Add,
+        Mul,
+    }
+    impl Operation for Op2 {}
+    impl Arity2Operation for Op2 {
+        fn is_multiplication(&self) -> bool {
+            matches!(self, Self::Mul)
+        }
+    }
+
+    struct TestCryptoSystem {
+        relins: core::cell::Cell<u32>,
+    }
+
+    impl CryptoSystem for TestCryptoSystem {
+        type Plaintext = i64;
+        type Ciphertext = TestCiphertext;
+        type Operation1 = ();
+        type Operation2 = Op2;
+
+        fn cipher(&self, plaintext: &Self::Plaintext) -> Self::Ciphertext {
+            TestCiphertext(*plaintext)
+        }
+        fn decipher(&self, ciphertext: &Self::Ciphertext) -> Self::Plaintext {
+            ciphertext.0
+        }
+
+        fn operate1(&self, (): Self::Operation1, lhs: &Self::Ciphertext) -> Self::Ciphertext {
+            *lhs
+        }
+
+        fn operate2(
+            &self,
+            operation: Self::Operation2,
+            lhs: &Self::Ciphertext,
+            rhs: &Self::Ciphertext,
+        ) -> Self::Ciphertext {
+            match operation {
+                Op2::Add => TestCiphertext(lhs.0 + rhs.0),
+                Op2::Mul => TestCiphertext(lhs.0 * rhs.0),
+            }
+        }
+
+        fn relinearize(&self, _ciphertext: &mut Self::Ciphertext) {
+            self.relins.set(self.relins.get() + 1);
+        }
+
+        fn level(&self, _ciphertext: &Self::Ciphertext) -> u32 {
+            0
+        }
+        fn rescale(&self, _ciphertext: &mut Self::Ciphertext) {}
+        fn mod_switch_to(&self, _ciphertext: &mut Self::Ciphertext, _level: u32) {}
+
+        type SerError = ();
+        fn serialize_ciphertext(&self, _ciphertext: &Self::Ciphertext, _format: SerFormat) -> Vec<u8> {
+            Vec::new()
+        }
+        fn deserialize_ciphertext(
+            &self,
+            _bytes: &[u8],
+            _format: SerFormat,
+        ) -> Result<Self::Ciphertext, Self::SerError> {
+            Ok(TestCiphertext(0))
+        }
+        fn serialize_public_key(&self, _format: SerFormat) -> Vec<u8> {
+            Vec::new()
+        }
+        fn serialize_relin_key(&self, _format: SerFormat) -> Option<Vec<u8>> {
+            None
+        }
+    }
+
+    #[test]
+    fn evaluates_shared_subexpression_once() {
+        // out = (a + b) * (a + b), with `sum` referenced twice.
+        let cs = TestCryptoSystem {
+            relins: core::cell::Cell::new(0),
+        };
+        let mut circuit = Circuit::<(), Op2>::new();
+        let a = circuit.input(0);
+        let b = circuit.input(1);
+        let sum = circuit.op2(Op2::Add, a, b);
+        let out = circuit.op2(Op2::Mul, sum, sum);
+        assert_eq!(out, circuit.len() - 1);
+
+        let inputs = [TestCiphertext(2), TestCiphertext(3)];
+        let result = circuit.evaluate(&cs, &inputs);
+
+        assert_eq!(result.0, 25);
+        assert_eq!(cs.relins.get(), 1, "Mul should relinearize exactly once");
+    }
+
+    #[test]
+    fn multiplicative_depth_counts_the_longest_chain() {
+        // out = ((a * b) * c) + a — two chained multiplications, one addition.
+        let mut circuit = Circuit::<(), Op2>::new();
+        let a = circuit.input(0);
+        let b = circuit.input(1);
+        let c = circuit.input(2);
+        let ab = circuit.op2(Op2::Mul, a, b);
+        let abc = circuit.op2(Op2::Mul, ab, c);
+        let _out = circuit.op2(Op2::Add, abc, a);
+
+        assert_eq!(circuit.multiplicative_depth(), 2);
+    }
+
+    #[test]
+    fn replays_against_multiple_input_sets() {
+        let cs = TestCryptoSystem {
+            relins: core::cell::Cell::new(0),
+        };
+        let mut circuit = Circuit::<(), Op2>::new();
+        let a = circuit.input(0);
+        let b = circuit.input(1);
+        let _sum = circuit.op2(Op2::Add, a, b);
+
+        let first = circuit.evaluate(&cs, &[TestCiphertext(1), TestCiphertext(2)]);
+        let second = circuit.evaluate(&cs, &[TestCiphertext(10), TestCiphertext(20)]);
+
+        assert_eq!(first.0, 3);
+        assert_eq!(second.0, 30);
+    }
+}
@@ -12,11 +12,48 @@ pub struct FqInt64<const Q: usize>(i64);
 impl<const Q: usize> FqInt64<Q> {
     const Q_I64: i64 = Q as i64;
 
+    /// Barrett shift `k = 2·⌈log₂ Q⌉`, wide enough that `⌊2^k / Q⌋` fixes the
+    /// quotient estimate for any `x < Q²`.
+    const K: u32 = 2 * (usize::BITS - (Q - 1).leading_zeros());
+
+    /// Precomputed reciprocal `μ = ⌊2^K / Q⌋` driving [`reduce`](Self::reduce).
+    const MU: u128 = (1u128 << Self::K) / Q as u128;
+
     #[must_use]
     #[inline]
     /// Constructor to create a new FqInt64
     pub const fn new(value: i64) -> Self {
-        Self(value % Self::Q_I64)
+        Self(value.rem_euclid(Self::Q_I64))
+    }
+
+    #[must_use]
+    #[inline]
+    /// Reduce a value in `[0, Q²)` modulo `Q` with Barrett's method: one wide
+    /// multiply, a shift, and at most two conditional subtractions — no integer
+    /// division.
+    ///
+    /// `x` and [`MU`](Self::MU) are each sized for moduli up to 64 bits, so for
+    /// any `Q` above ~42 bits their product no longer fits a `u128` — a bare
+    /// `wrapping_mul` would silently truncate it and hand back a garbage
+    /// quotient estimate. The multiply is instead done as the same
+    /// limb-decomposed 128×128→256-bit widening product [`FqInt128::full_mul`]
+    /// uses, and only the top [`K`](Self::K) bits needed for the estimate are
+    /// kept.
+    pub const fn reduce(x: u128) -> Self {
+        let (hi, lo) = full_mul(x, Self::MU);
+        let q_est = if Self::K >= 128 {
+            hi >> (Self::K - 128)
+        } else {
+            (hi << (128 - Self::K)) | (lo >> Self::K)
+        };
+        let mut r = (x - q_est * Q as u128) as i64;
+        if r >= Self::Q_I64 {
+            r -= Self::Q_I64;
+        }
+        if r >= Self::Q_I64 {
+            r -= Self::Q_I64;
+        }
+        Self(r)
     }
 
     #[must_use]
@@ -33,7 +70,7 @@ impl<const Q: usize> Add for FqInt64<Q> {
     #[inline]
     /// Add two FqInt64
     fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.0 + rhs.0)
+        Self::reduce(self.0 as u128 + rhs.0 as u128)
     }
 }
 
@@ -43,7 +80,8 @@ impl<const Q: usize> Sub for FqInt64<Q> {
     #[inline]
     /// Subtract two FqInt64
     fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.0 - rhs.0)
+        // Bias by `Q` so the argument stays non-negative before reduction.
+        Self::reduce((self.0 + Self::Q_I64 - rhs.0) as u128)
     }
 }
 
@@ -53,6 +91,264 @@ impl<const Q: usize> Mul for FqInt64<Q> {
     #[inline]
     /// Multiply two FqInt64
     fn mul(self, rhs: Self) -> Self::Output {
-        Self::new(self.0 * rhs.0)
+        Self::reduce(self.0 as u128 * rhs.0 as u128)
+    }
+}
+
+/// Selects the modular-arithmetic path for a given modulus: moduli that fit 64
+/// bits keep the fast [`FqInt64`] Barrett reduction, while larger ones (up to
+/// 127 bits) fall back to the double-width [`FqInt128`] path.
+///
+/// [`FqInt64::reduce`] widens its quotient estimate through the same
+/// limb-decomposed product [`FqInt128`] uses, so it stays exact across the
+/// full `Bits64` range rather than only for moduli narrow enough that a bare
+/// `u128` multiply wouldn't truncate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModulusWidth {
+    /// Modulus fits in 64 bits — use the `u128`-intermediate fast path.
+    Bits64,
+    /// Modulus needs up to 127 bits — use the full 128×128→256 multiply.
+    Bits128,
+}
+
+impl ModulusWidth {
+    #[must_use]
+    #[inline]
+    /// The narrowest path that can hold `modulus`.
+    pub const fn of(modulus: u128) -> Self {
+        if modulus >> 64 == 0 {
+            Self::Bits64
+        } else {
+            Self::Bits128
+        }
+    }
+}
+
+/// Low 64 bits of a `u128`, for limb decomposition in [`full_mul`].
+const LIMB_MASK: u128 = u64::MAX as u128;
+
+/// Full-width product `a·b` of two 128-bit values as a `(high, low)` pair of
+/// `u128` limbs, composing four 64×64→128 partial products so no intermediate
+/// overflows. Shared by [`FqInt64::reduce`] and [`FqInt128::full_mul`], the two
+/// places in this file that need a product wider than `u128`.
+const fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    let (a0, a1) = (a & LIMB_MASK, a >> 64);
+    let (b0, b1) = (b & LIMB_MASK, b >> 64);
+
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+
+    let mid = (p00 >> 64) + (p01 & LIMB_MASK) + (p10 & LIMB_MASK);
+    let lo = (p00 & LIMB_MASK) | ((mid & LIMB_MASK) << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Int 128 in F_q, for moduli up to 127 bits.
+///
+/// The 64-bit [`FqInt64`] path reduces the `u128` product of two sub-`2⁶⁴`
+/// operands with a single Barrett step. For a wider modulus the product no
+/// longer fits a `u128`, so multiplication forms the full 256-bit result with
+/// [`full_mul`](Self::full_mul) and reduces it exactly with
+/// [`reduce_wide`](Self::reduce_wide); the Barrett reciprocal of the 64-bit path
+/// does not extend here because a μ-based estimate would need a 256×128 high
+/// multiply that a `u128` cannot express.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FqInt128<const Q: u128>(u128);
+
+impl<const Q: u128> FqInt128<Q> {
+    #[must_use]
+    #[inline]
+    /// Constructor to create a new FqInt128.
+    pub const fn new(value: u128) -> Self {
+        Self(value % Q)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Get the value of the FqInt128.
+    pub const fn value(&self) -> u128 {
+        self.0
+    }
+
+    #[must_use]
+    #[inline]
+    /// Full-width product `a·b` of two 128-bit values as a `(high, low)` pair of
+    /// `u128` limbs, composing four 64×64→128 partial products so no
+    /// intermediate overflows.
+    pub const fn full_mul(a: u128, b: u128) -> (u128, u128) {
+        full_mul(a, b)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Reduce a 256-bit value `hi·2¹²⁸ + lo` modulo `Q`, MSB-first shift-and-
+    /// subtract so no division is used. Requires `Q < 2¹²⁷` so the running
+    /// remainder never overflows when doubled.
+    pub const fn reduce_wide(hi: u128, lo: u128) -> Self {
+        let mut r: u128 = 0;
+        let mut i: u32 = 256;
+        while i > 0 {
+            i -= 1;
+            let bit = if i >= 128 {
+                (hi >> (i - 128)) & 1
+            } else {
+                (lo >> i) & 1
+            };
+            r = (r << 1) | bit;
+            if r >= Q {
+                r -= Q;
+            }
+        }
+        Self(r)
+    }
+}
+
+impl<const Q: u128> Add for FqInt128<Q> {
+    type Output = Self;
+
+    #[inline]
+    /// Add two FqInt128.
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut r = self.0 + rhs.0;
+        if r >= Q {
+            r -= Q;
+        }
+        Self(r)
+    }
+}
+
+impl<const Q: u128> Sub for FqInt128<Q> {
+    type Output = Self;
+
+    #[inline]
+    /// Subtract two FqInt128.
+    fn sub(self, rhs: Self) -> Self::Output {
+        // Bias by `Q` so the argument stays non-negative before reduction.
+        let mut r = self.0 + Q - rhs.0;
+        if r >= Q {
+            r -= Q;
+        }
+        Self(r)
+    }
+}
+
+impl<const Q: u128> Mul for FqInt128<Q> {
+    type Output = Self;
+
+    #[inline]
+    /// Multiply two FqInt128 through the full 256-bit product.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (hi, lo) = Self::full_mul(self.0, rhs.0);
+        Self::reduce_wide(hi, lo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduce_agrees_with_modulo() {
+        const Q: usize = 17;
+        for x in 0..(Q * Q) as u128 {
+            assert_eq!(FqInt64::<Q>::reduce(x).value(), (x % Q as u128) as i64);
+        }
+    }
+
+    #[test]
+    fn reduce_near_q_squared_boundary() {
+        const Q: usize = 1_000_003;
+        let qq = (Q as u128) * (Q as u128);
+        for x in (qq - 5)..qq {
+            assert_eq!(FqInt64::<Q>::reduce(x).value(), (x % Q as u128) as i64);
+        }
+    }
+
+    #[test]
+    fn mul_does_not_overflow_for_large_modulus() {
+        // Q close to i64::MAX: a naive `self.0 * rhs.0` before reduction would
+        // overflow i64 long before any `%` ran; routing through the widened
+        // u128 intermediate in `reduce` must not.
+        //
+        // `expected` is computed with a plain u128 multiply — (Q-1)^2 is
+        // still well under 2^128, so this arithmetic is exact and does not
+        // go anywhere near `reduce`'s own widening. Pinning it against the
+        // known correct literal (rather than only comparing two
+        // independently-derived values) is what actually exercises the
+        // full-width product in `reduce`: a `reduce` that silently
+        // truncated `x * MU` would return 3592 here, not 1.
+        const Q: usize = (1 << 62) - 57;
+        let a = FqInt64::<Q>::new(Q as i64 - 1);
+        let b = FqInt64::<Q>::new(Q as i64 - 1);
+        let expected = ((Q as u128 - 1) * (Q as u128 - 1)) % Q as u128;
+        assert_eq!(expected, 1);
+        assert_eq!((a * b).value(), expected as i64);
+    }
+
+    #[test]
+    fn sub_never_yields_a_negative_representative() {
+        const Q: usize = 17;
+        for x in 0..Q as i64 {
+            for y in 0..Q as i64 {
+                let diff = FqInt64::<Q>::new(x) - FqInt64::<Q>::new(y);
+                assert!((0..Q as i64).contains(&diff.value()));
+                assert_eq!(diff.value(), (x - y).rem_euclid(Q as i64));
+            }
+        }
+    }
+
+    #[test]
+    fn modulus_width_picks_path() {
+        assert_eq!(ModulusWidth::of(17), ModulusWidth::Bits64);
+        assert_eq!(ModulusWidth::of(u64::MAX as u128), ModulusWidth::Bits64);
+        assert_eq!(ModulusWidth::of(1u128 << 64), ModulusWidth::Bits128);
+    }
+
+    #[test]
+    fn full_mul_matches_u128_when_product_fits() {
+        for (a, b) in [(0u128, 0u128), (1, 1), (u64::MAX as u128, 3), (12_345, 67_890)] {
+            let (hi, lo) = FqInt128::<17>::full_mul(a, b);
+            assert_eq!(hi, 0);
+            assert_eq!(lo, a * b);
+        }
+    }
+
+    #[test]
+    fn full_mul_composes_high_limb() {
+        // (2^64)·(2^64) = 2^128 → hi = 1, lo = 0.
+        let (hi, lo) = FqInt128::<17>::full_mul(1u128 << 64, 1u128 << 64);
+        assert_eq!((hi, lo), (1, 0));
+    }
+
+    #[test]
+    fn reduce_wide_agrees_with_modulo() {
+        const Q: u128 = 17;
+        for x in 0..(Q * Q) {
+            assert_eq!(FqInt128::<Q>::reduce_wide(0, x).value(), x % Q);
+        }
+    }
+
+    #[test]
+    fn reduce_wide_near_q_squared_boundary() {
+        const Q: u128 = 1_000_003;
+        let qq = Q * Q;
+        for x in (qq - 5)..qq {
+            assert_eq!(FqInt128::<Q>::reduce_wide(0, x).value(), x % Q);
+        }
+    }
+
+    #[test]
+    fn mul_agrees_with_modulo_for_wide_modulus() {
+        // A modulus beyond 64 bits so the product genuinely spills into `hi`.
+        const Q: u128 = (1u128 << 100) + 277;
+        let a = FqInt128::<Q>::new((1u128 << 99) + 5);
+        let b = FqInt128::<Q>::new((1u128 << 98) + 7);
+        let (hi, lo) = FqInt128::<Q>::full_mul(a.value(), b.value());
+        assert_eq!((a * b).value(), FqInt128::<Q>::reduce_wide(hi, lo).value());
+        assert_eq!((a + b).value(), (a.value() + b.value()) % Q);
     }
 }
@@ -52,4 +52,247 @@ impl<const Q: usize> FqPolynomial<Q> {
 
         Self::new(result)
     }
+
+    #[must_use = "This method does not modify the FqPolynomial, it returns a new one instead"]
+    /// Full polynomial product `self * other` (degree `len₁ + len₂ − 2`).
+    ///
+    /// Runs in `O(n log n)` through a cyclic NTT when `Q` admits a root of unity
+    /// of the padded length, and otherwise falls back to the schoolbook
+    /// convolution.
+    pub fn mul(&self, other: &Self) -> Self {
+        if self.coeffs.is_empty() || other.coeffs.is_empty() {
+            return Self::new(Vec::new());
+        }
+        let out_len = self.coeffs.len() + other.coeffs.len() - 1;
+        let size = out_len.next_power_of_two();
+        match Self::primitive_nth_root(size) {
+            Some(omega) => {
+                let mut a = Self::padded(&self.coeffs, size);
+                let mut b = Self::padded(&other.coeffs, size);
+                Self::ntt(&mut a, omega);
+                Self::ntt(&mut b, omega);
+                for i in 0..size {
+                    a[i] = Self::mulmod(a[i], b[i]);
+                }
+                let omega_inv = Self::powmod(omega, (size - 1) as u64);
+                Self::ntt(&mut a, omega_inv);
+                let n_inv = Self::powmod(size as i64, (Self::Q_I64 - 2) as u64);
+                let coeffs = a[..out_len]
+                    .iter()
+                    .map(|&c| FqInt64::new(Self::mulmod(c, n_inv)))
+                    .collect();
+                Self::new(coeffs)
+            }
+            None => self.mul_schoolbook(other),
+        }
+    }
+
+    #[must_use = "This method does not modify the FqPolynomial, it returns a new one instead"]
+    /// Negacyclic product in `F_q[X]/(X^n + 1)`, where `n` is the (shared) length
+    /// of both operands and must be a power of two.
+    ///
+    /// Requires `Q` prime with `2n | Q − 1`; when no primitive `2n`-th root of
+    /// unity exists it falls back to the schoolbook product folded modulo
+    /// `X^n + 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two operands do not share the same length.
+    pub fn mul_negacyclic(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.coeffs.len(),
+            other.coeffs.len(),
+            "negacyclic multiply requires operands of equal length"
+        );
+        let n = self.coeffs.len();
+        let Some(psi) = Self::primitive_root_2n(n) else {
+            return self.mul_schoolbook(other).fold_negacyclic(n);
+        };
+        let psi_inv = Self::powmod(psi, (2 * n - 1) as u64);
+        let n_inv = Self::powmod(n as i64, (Self::Q_I64 - 2) as u64);
+        let omega = Self::mulmod(psi, psi);
+        let omega_inv = Self::mulmod(psi_inv, psi_inv);
+
+        let twist = |src: &[FqInt64<Q>]| -> Vec<i64> {
+            let mut v = alloc::vec![0i64; n];
+            let mut p = 1i64;
+            for (i, c) in src.iter().enumerate() {
+                v[i] = Self::mulmod(c.value().rem_euclid(Self::Q_I64), p);
+                p = Self::mulmod(p, psi);
+            }
+            v
+        };
+        let mut a = twist(&self.coeffs);
+        let mut b = twist(&other.coeffs);
+        Self::ntt(&mut a, omega);
+        Self::ntt(&mut b, omega);
+        for i in 0..n {
+            a[i] = Self::mulmod(a[i], b[i]);
+        }
+        Self::ntt(&mut a, omega_inv);
+
+        let mut coeffs = Vec::with_capacity(n);
+        let mut p = 1i64;
+        for &c in a.iter().take(n) {
+            coeffs.push(FqInt64::new(Self::mulmod(Self::mulmod(c, n_inv), p)));
+            p = Self::mulmod(p, psi_inv);
+        }
+        Self::new(coeffs)
+    }
+
+    /// Schoolbook convolution, used as the fallback when no NTT root exists.
+    fn mul_schoolbook(&self, other: &Self) -> Self {
+        if self.coeffs.is_empty() || other.coeffs.is_empty() {
+            return Self::new(Vec::new());
+        }
+        let mut result = alloc::vec![FqInt64::<Q>::default(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (j, &b) in other.coeffs.iter().enumerate() {
+                result[i + j] = result[i + j] + a * b;
+            }
+        }
+        Self::new(result)
+    }
+
+    /// Folds any coefficient at index `i ≥ n` into index `i mod n` with sign
+    /// `(−1)^(i / n)`, realizing reduction modulo `X^n + 1`.
+    fn fold_negacyclic(&self, n: usize) -> Self {
+        let mut r = alloc::vec![FqInt64::<Q>::default(); n];
+        for (i, &c) in self.coeffs.iter().enumerate() {
+            let slot = i % n;
+            if (i / n) % 2 == 0 {
+                r[slot] = r[slot] + c;
+            } else {
+                r[slot] = r[slot] - c;
+            }
+        }
+        Self::new(r)
+    }
+
+    const Q_I64: i64 = Q as i64;
+
+    /// Zero-pads `src` (reduced into `[0, Q)`) to length `size`.
+    fn padded(src: &[FqInt64<Q>], size: usize) -> Vec<i64> {
+        let mut v = alloc::vec![0i64; size];
+        for (slot, c) in v.iter_mut().zip(src) {
+            *slot = c.value().rem_euclid(Self::Q_I64);
+        }
+        v
+    }
+
+    /// Modular multiplication routed through Barrett reduction.
+    fn mulmod(a: i64, b: i64) -> i64 {
+        FqInt64::<Q>::reduce(a as u128 * b as u128).value()
+    }
+
+    /// Modular exponentiation `base^exp mod Q` by square-and-multiply.
+    fn powmod(mut base: i64, mut exp: u64) -> i64 {
+        base = base.rem_euclid(Self::Q_I64);
+        let mut result = 1i64;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = Self::mulmod(result, base);
+            }
+            base = Self::mulmod(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Find a primitive `n`-th root of unity when `n | Q − 1`.
+    fn primitive_nth_root(n: usize) -> Option<i64> {
+        let n = n as i64;
+        if n <= 1 || (Self::Q_I64 - 1) % n != 0 {
+            return None;
+        }
+        let exp = ((Self::Q_I64 - 1) / n) as u64;
+        (2..Self::Q_I64)
+            .map(|g| Self::powmod(g, exp))
+            .find(|&w| Self::powmod(w, (n / 2) as u64) == Self::Q_I64 - 1)
+    }
+
+    /// Find a primitive `2n`-th root of unity `ψ` (with `ψ^n ≡ −1`) when
+    /// `2n | Q − 1`.
+    fn primitive_root_2n(n: usize) -> Option<i64> {
+        let two_n = 2 * n as i64;
+        if (Self::Q_I64 - 1) % two_n != 0 {
+            return None;
+        }
+        let exp = ((Self::Q_I64 - 1) / two_n) as u64;
+        (2..Self::Q_I64)
+            .map(|g| Self::powmod(g, exp))
+            .find(|&psi| Self::powmod(psi, n as u64) == Self::Q_I64 - 1)
+    }
+
+    /// In-place length-`n` Cooley–Tukey NTT with `omega` a primitive `n`-th root
+    /// of unity (bit-reversed input ordering).
+    fn ntt(a: &mut [i64], omega: i64) {
+        let n = a.len();
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j ^= bit;
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let wlen = Self::powmod(omega, (n / len) as u64);
+            let mut i = 0;
+            while i < n {
+                let mut w = 1i64;
+                for k in 0..len / 2 {
+                    let u = a[i + k];
+                    let v = Self::mulmod(a[i + k + len / 2], w);
+                    a[i + k] = FqInt64::<Q>::reduce((u + v) as u128).value();
+                    a[i + k + len / 2] =
+                        FqInt64::<Q>::reduce((u + Self::Q_I64 - v) as u128).value();
+                    w = Self::mulmod(w, wlen);
+                }
+                i += len;
+            }
+            len <<= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    const Q: usize = 17;
+
+    fn poly(v: &[i64]) -> FqPolynomial<Q> {
+        FqPolynomial::new(v.iter().map(|&x| FqInt64::new(x)).collect())
+    }
+
+    fn norm(p: &FqPolynomial<Q>) -> Vec<i64> {
+        p.coeffs()
+            .iter()
+            .map(|c| c.value().rem_euclid(Q as i64))
+            .collect()
+    }
+
+    #[test]
+    fn mul_matches_schoolbook() {
+        let a = poly(&[1, 2, 3, 4]);
+        let b = poly(&[4, 3, 2, 1]);
+        assert_eq!(norm(&a.mul(&b)), norm(&a.mul_schoolbook(&b)));
+    }
+
+    #[test]
+    fn mul_negacyclic_matches_folded_schoolbook() {
+        let a = poly(&[1, 2, 3, 4]);
+        let b = poly(&[5, 6, 7, 8]);
+        let fast = a.mul_negacyclic(&b);
+        let slow = a.mul_schoolbook(&b).fold_negacyclic(4);
+        assert_eq!(norm(&fast), norm(&slow));
+    }
 }
@@ -7,11 +7,74 @@ use crate::rand::distributions::Distribution;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "zeroize", derive(::zeroize::Zeroize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 #[repr(transparent)]
 /// Coefficient of the polynomial, namely elements of Z/pZ
 pub struct Coeff<const P: i64>(i64);
 
 impl<const P: i64> Coeff<P> {
+    /// Barrett shift `k = 2·⌈log2 P⌉`, wide enough to reduce any product `< P²`.
+    const BARRETT_K: u32 = 2 * (64 - (P - 1).leading_zeros());
+    /// Precomputed Barrett multiplier `m = ⌊2^k / P⌋`.
+    const BARRETT_M: i128 = (1i128 << Self::BARRETT_K) / P as i128;
+    /// Low 64 bits of a `u128`, for the limb decomposition in
+    /// [`barrett_reduce`](Self::barrett_reduce).
+    const LIMB_MASK: u128 = u64::MAX as u128;
+
+    /// Primality check used by [`new`](Self::new): trial division over the
+    /// compile-time modulus, run on every call and taking `O(√P)` steps.
+    const fn is_prime(p: i64) -> bool {
+        if p < 2 {
+            return false;
+        }
+        let mut d = 2;
+        while d * d <= p {
+            if p % d == 0 {
+                return false;
+            }
+            d += 1;
+        }
+        true
+    }
+
+    /// Barrett reduction of `x ∈ [0, P²)` to its representative in `[0, P)`.
+    ///
+    /// `x` and [`BARRETT_M`](Self::BARRETT_M) are each sized for moduli up to
+    /// ~63 bits, so for a realistic NTT-friendly prime their product can reach
+    /// ~190 bits — too wide for a plain `i128` multiply, which would either
+    /// panic (debug) or silently wrap (release). Both operands are
+    /// non-negative here, so the multiply is instead carried out as an
+    /// unsigned 128×128→256-bit widening product, limb by limb, the same way
+    /// [`FqInt128::full_mul`](crate::fq::FqInt128::full_mul) does in `fq.rs`.
+    #[inline]
+    const fn barrett_reduce(x: i128) -> i64 {
+        let xu = x as u128;
+        let mu = Self::BARRETT_M as u128;
+
+        let (x0, x1) = (xu & Self::LIMB_MASK, xu >> 64);
+        let (m0, m1) = (mu & Self::LIMB_MASK, mu >> 64);
+
+        let p00 = x0 * m0;
+        let p01 = x0 * m1;
+        let p10 = x1 * m0;
+        let p11 = x1 * m1;
+
+        let mid = (p00 >> 64) + (p01 & Self::LIMB_MASK) + (p10 & Self::LIMB_MASK);
+        let lo = (p00 & Self::LIMB_MASK) | ((mid & Self::LIMB_MASK) << 64);
+        let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+
+        // `BARRETT_K` is always in `(0, 128)` for an `i64` modulus, so the
+        // 256-bit product's top `BARRETT_K` bits can be recombined from
+        // `hi`/`lo` with a sub-128-bit shift on each.
+        let q = ((hi << (128 - Self::BARRETT_K)) | (lo >> Self::BARRETT_K)) as i128;
+        let r = (x - q * P as i128) as i64;
+        if r >= P {
+            r - P
+        } else {
+            r
+        }
+    }
+
     #[must_use]
     #[inline]
     /// Constructor to create a new Coeff
@@ -20,6 +83,7 @@ impl<const P: i64> Coeff<P> {
     ///
     /// Panics if the modulus is not prime.
     pub const fn new(coeff: i64) -> Self {
+        assert!(Self::is_prime(P), "modulus P must be prime");
         Self(coeff.rem_euclid(P))
     }
 
@@ -46,7 +110,10 @@ impl<const P: i64> Add for Coeff<P> {
     type Output = Self;
     #[inline]
     fn add(self, rhs: Self) -> Self {
-        Self((self.0 + rhs.0).rem_euclid(P))
+        // Both operands are already reduced, so a single conditional subtract
+        // of P brings the sum back into [0, P).
+        let s = self.0 + rhs.0;
+        Self(if s >= P { s - P } else { s })
     }
 }
 
@@ -54,7 +121,9 @@ impl<const P: i64> Sub for Coeff<P> {
     type Output = Self;
     #[inline]
     fn sub(self, rhs: Self) -> Self {
-        Self((self.0 - rhs.0).rem_euclid(P))
+        // Single conditional add of P keeps the difference in [0, P).
+        let d = self.0 - rhs.0;
+        Self(if d < 0 { d + P } else { d })
     }
 }
 
@@ -62,7 +131,7 @@ impl<const P: i64> Mul for Coeff<P> {
     type Output = Self;
     #[inline]
     fn mul(self, rhs: Self) -> Self {
-        Self((self.0 * rhs.0).rem_euclid(P))
+        Self(Self::barrett_reduce(self.0 as i128 * rhs.0 as i128))
     }
 }
 
@@ -86,6 +155,7 @@ impl<const P: i64> From<Coeff<P>> for i64 {
 
 #[derive(Debug, Clone, Eq)]
 #[cfg_attr(feature = "zeroize", derive(::zeroize::Zeroize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 /// Polynomial Ring Z/pZ[X]/(X^N + 1) where N is a power of 2 and p is prime.
 pub struct Polynomial<const P: i64, const N: u32> {
     coeffs: Vec<Coeff<P>>,
@@ -119,6 +189,25 @@ impl<const P: i64, const N: u32> Polynomial<P, N> {
         Self::new(coeffs)
     }
 
+    /// Generate a random polynomial, propagating sampler failures.
+    ///
+    /// Fallible counterpart to [`random`](Self::random) for callers that must
+    /// not panic when the entropy source is exhausted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the sampler error if the distribution fails to produce a value.
+    pub fn try_random<D: Distribution>(d: &D) -> crate::rand::RandResult<Self>
+    where
+        D::Output: Into<i64>,
+    {
+        let mut coeffs = Vec::with_capacity(Self::M);
+        for _ in 0..Self::M {
+            coeffs.push(d.sample()?.into());
+        }
+        Ok(Self::new(coeffs))
+    }
+
     #[must_use]
     #[inline]
     /// Get the len of the coefficients
@@ -185,6 +274,20 @@ impl<const P: i64, const N: u32> Polynomial<P, N> {
     #[inline]
     /// Multiply two polynomials
     pub fn multiply(lhs: &Self, rhs: &Self) -> Self {
+        if Self::cached_psi().is_some() {
+            Self::multiply_ntt(lhs, rhs)
+        } else {
+            Self::multiply_schoolbook(lhs, rhs)
+        }
+    }
+
+    #[must_use]
+    /// Schoolbook `O(M²)` convolution followed by reduction mod `X^M + 1`.
+    ///
+    /// The public [`multiply`](Self::multiply) uses this only when `P` admits no
+    /// negacyclic NTT; it is also the fallback of
+    /// [`multiply_ntt`](Self::multiply_ntt).
+    pub fn multiply_schoolbook(lhs: &Self, rhs: &Self) -> Self {
         let mut coeffs = alloc::vec![Coeff(0); lhs.len() + rhs.len() - 1];
         for (i, &l) in lhs.coeffs().iter().enumerate() {
             for (j, &r) in rhs.coeffs().iter().enumerate() {
@@ -195,6 +298,153 @@ impl<const P: i64, const N: u32> Polynomial<P, N> {
         raw.rem_cyclo()
     }
 
+    /// Returns the primitive `2M`-th root of unity for `(P, N)`, memoizing the
+    /// (relatively expensive) search so repeated products reuse it.
+    ///
+    /// With the `std` feature the result is cached in a process-wide table keyed
+    /// by the const generics `(P, N)`; without it the root is recomputed on each
+    /// call, since a `no_std` target has no global allocator-backed cache.
+    #[cfg(feature = "std")]
+    fn cached_psi() -> Option<i64> {
+        use std::collections::HashMap;
+        use std::sync::{Mutex, OnceLock};
+
+        static CACHE: OnceLock<Mutex<HashMap<(i64, u32), Option<i64>>>> = OnceLock::new();
+        let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let key = (P, N);
+        if let Some(&v) = cache.lock().unwrap().get(&key) {
+            return v;
+        }
+        let v = Self::primitive_root_2m();
+        cache.lock().unwrap().insert(key, v);
+        v
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn cached_psi() -> Option<i64> {
+        Self::primitive_root_2m()
+    }
+
+    #[inline]
+    /// Modular multiplication through a wider intermediate to avoid overflow.
+    const fn mulmod(a: i64, b: i64) -> i64 {
+        ((a as i128 * b as i128).rem_euclid(P as i128)) as i64
+    }
+
+    #[inline]
+    /// Modular exponentiation `base^exp mod P` by square-and-multiply.
+    const fn powmod(mut base: i64, mut exp: u64) -> i64 {
+        base = base.rem_euclid(P);
+        let mut result = 1i64;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = Self::mulmod(result, base);
+            }
+            base = Self::mulmod(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Find a primitive `2M`-th root of unity `ψ` (with `ψ^M ≡ −1`) when one
+    /// exists, i.e. when `2M | (P − 1)`.
+    fn primitive_root_2m() -> Option<i64> {
+        let two_m = 2 * Self::M as i64;
+        if (P - 1) % two_m != 0 {
+            return None;
+        }
+        let exp = ((P - 1) / two_m) as u64;
+        // Any candidate raised to `(P−1)/2M` is a `2M`-th root; keep the first
+        // one whose order is exactly `2M`, witnessed by `ψ^M ≡ −1`.
+        (2..P)
+            .map(|g| Self::powmod(g, exp))
+            .find(|&psi| Self::powmod(psi, Self::M as u64) == P - 1)
+    }
+
+    /// In-place length-`n` Cooley–Tukey NTT with `omega` a primitive `n`-th
+    /// root of unity (bit-reversed input ordering).
+    fn ntt(a: &mut [i64], omega: i64) {
+        let n = a.len();
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j ^= bit;
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+        let mut len = 2;
+        while len <= n {
+            let wlen = Self::powmod(omega, (n / len) as u64);
+            let mut i = 0;
+            while i < n {
+                let mut w = 1i64;
+                for k in 0..len / 2 {
+                    let u = a[i + k];
+                    let v = Self::mulmod(a[i + k + len / 2], w);
+                    a[i + k] = (u + v).rem_euclid(P);
+                    a[i + k + len / 2] = (u - v).rem_euclid(P);
+                    w = Self::mulmod(w, wlen);
+                }
+                i += len;
+            }
+            len <<= 1;
+        }
+    }
+
+    #[must_use]
+    /// Multiply two polynomials using a negacyclic NTT.
+    ///
+    /// When `P` admits a primitive `2M`-th root of unity (`2M | P − 1`) this
+    /// computes the product directly in `Z/pZ[X]/(X^M + 1)` in `O(M log M)`
+    /// without an explicit reduction step. Otherwise it falls back to
+    /// [`multiply_schoolbook`](Self::multiply_schoolbook).
+    pub fn multiply_ntt(lhs: &Self, rhs: &Self) -> Self {
+        let m = Self::M;
+        let Some(psi) = Self::cached_psi() else {
+            return Self::multiply_schoolbook(lhs, rhs);
+        };
+        let psi_inv = Self::powmod(psi, 2 * m as u64 - 1);
+        let n_inv = Self::powmod(m as i64, (P - 2) as u64);
+        let omega = Self::mulmod(psi, psi);
+        let omega_inv = Self::mulmod(psi_inv, psi_inv);
+
+        // Twist a_i ← a_i·ψ^i, transform, pointwise multiply, inverse, untwist.
+        let twist = |src: &[Coeff<P>]| -> Vec<i64> {
+            let mut v = alloc::vec![0i64; m];
+            let mut p = 1i64;
+            for i in 0..m {
+                let c = src.get(i).map_or(0, Coeff::as_i64);
+                v[i] = Self::mulmod(c, p);
+                p = Self::mulmod(p, psi);
+            }
+            v
+        };
+        let mut a = twist(lhs.coeffs());
+        let mut b = twist(rhs.coeffs());
+        Self::ntt(&mut a, omega);
+        Self::ntt(&mut b, omega);
+        for i in 0..m {
+            a[i] = Self::mulmod(a[i], b[i]);
+        }
+        Self::ntt(&mut a, omega_inv);
+
+        let mut coeffs = Vec::with_capacity(m);
+        let mut p = 1i64;
+        for i in 0..m {
+            let untwisted = Self::mulmod(Self::mulmod(a[i], n_inv), p);
+            // Safe: mulmod keeps values in [0, P).
+            coeffs.push(unsafe { Coeff::new_unchecked(untwisted) });
+            p = Self::mulmod(p, psi_inv);
+        }
+        Self { coeffs }
+    }
+
     #[must_use]
     /// Computes the remainder of the division by the cyclotomic polynomial X^(2^n) + 1.
     ///
@@ -240,6 +490,482 @@ impl<const P: i64, const N: u32> PartialEq for Polynomial<P, N> {
     }
 }
 
+/// Precomputed negacyclic evaluation domain for `Z/pZ[X]/(X^M + 1)`.
+///
+/// Coefficient-form arithmetic pays a full forward/inverse transform on every
+/// multiply; callers that chain several products are better served by
+/// converting once into point-value form, multiplying elementwise, and
+/// converting back a single time. `EvaluationDomain` caches the roots that
+/// transform needs — the primitive `2M`-th root `ψ` used for the negacyclic
+/// twist, the base `M`-th root `ω = ψ²`, their inverses, and `M⁻¹` — so those
+/// conversions reuse one table.
+///
+/// Construction returns `None` when `P` admits no primitive `2M`-th root
+/// (`2M ∤ P − 1`), the same condition under which
+/// [`Polynomial::multiply_ntt`] falls back to the schoolbook path.
+pub struct EvaluationDomain<const P: i64, const N: u32> {
+    psi: i64,
+    psi_inv: i64,
+    omega: i64,
+    omega_inv: i64,
+    n_inv: i64,
+}
+
+impl<const P: i64, const N: u32> EvaluationDomain<P, N> {
+    const M: usize = 1 << N;
+
+    #[must_use]
+    /// Builds the domain, or returns `None` if `P` has no primitive `2M`-th
+    /// root of unity.
+    pub fn new() -> Option<Self> {
+        let m = Self::M;
+        let psi = Polynomial::<P, N>::primitive_root_2m()?;
+        let psi_inv = Polynomial::<P, N>::powmod(psi, 2 * m as u64 - 1);
+        let omega = Polynomial::<P, N>::mulmod(psi, psi);
+        let omega_inv = Polynomial::<P, N>::mulmod(psi_inv, psi_inv);
+        let n_inv = Polynomial::<P, N>::powmod(m as i64, (P - 2) as u64);
+        Some(Self {
+            psi,
+            psi_inv,
+            omega,
+            omega_inv,
+            n_inv,
+        })
+    }
+
+    #[must_use]
+    /// Transforms `poly` into point-value form over this domain.
+    pub fn to_point_values(&self, poly: &Polynomial<P, N>) -> Vec<Coeff<P>> {
+        let m = Self::M;
+        let mut a = alloc::vec![0i64; m];
+        let mut p = 1i64;
+        for i in 0..m {
+            let c = poly.coeffs().get(i).map_or(0, Coeff::as_i64);
+            a[i] = Polynomial::<P, N>::mulmod(c, p);
+            p = Polynomial::<P, N>::mulmod(p, self.psi);
+        }
+        Polynomial::<P, N>::ntt(&mut a, self.omega);
+        // Safe: `mulmod`/`ntt` keep every value in [0, P).
+        a.into_iter()
+            .map(|v| unsafe { Coeff::new_unchecked(v) })
+            .collect()
+    }
+
+    #[must_use]
+    /// Transforms point-value `values` back into a coefficient-form polynomial.
+    pub fn from_point_values(&self, values: &[Coeff<P>]) -> Polynomial<P, N> {
+        let m = Self::M;
+        let mut a = alloc::vec![0i64; m];
+        for (slot, v) in a.iter_mut().zip(values.iter()) {
+            *slot = v.as_i64();
+        }
+        Polynomial::<P, N>::ntt(&mut a, self.omega_inv);
+
+        let mut coeffs = Vec::with_capacity(m);
+        let mut p = 1i64;
+        for &value in a.iter().take(m) {
+            let untwisted =
+                Polynomial::<P, N>::mulmod(Polynomial::<P, N>::mulmod(value, self.n_inv), p);
+            // Safe: `mulmod` keeps values in [0, P).
+            coeffs.push(unsafe { Coeff::new_unchecked(untwisted) });
+            p = Polynomial::<P, N>::mulmod(p, self.psi_inv);
+        }
+        Polynomial { coeffs }
+    }
+
+    /// Elementwise multiplies `lhs` by `rhs` in point-value form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two point-value buffers have different lengths.
+    pub fn mul_assign(&self, lhs: &mut [Coeff<P>], rhs: &[Coeff<P>]) {
+        assert_eq!(lhs.len(), rhs.len(), "point-value length mismatch");
+        for (l, r) in lhs.iter_mut().zip(rhs.iter()) {
+            *l = *l * *r;
+        }
+    }
+
+    /// Elementwise adds `rhs` into `lhs` in point-value form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two point-value buffers have different lengths.
+    pub fn add_assign(&self, lhs: &mut [Coeff<P>], rhs: &[Coeff<P>]) {
+        assert_eq!(lhs.len(), rhs.len(), "point-value length mismatch");
+        for (l, r) in lhs.iter_mut().zip(rhs.iter()) {
+            *l = *l + *r;
+        }
+    }
+}
+
+/// A symmetric bivariate polynomial `f(x, y) = Σ c_{i,j} x^i y^j` of degree `N`
+/// over the field `Z/pZ`, the dealer's object in a verifiable secret-sharing
+/// scheme for threshold key generation.
+///
+/// The shared secret is `f(0, 0)`; participant `m` receives the univariate
+/// [`row`](Self::row) `f(m, ·)` and can exchange `f(m, s)` [`value`](Self::value)
+/// samples with participant `s`. Symmetry (`c_{i,j} = c_{j,i}`) makes
+/// `f(m, s) = f(s, m)`, so those exchanged samples agree without extra rounds.
+/// A dealer publishes a [`commitment`](Self::commitment) derived from the
+/// coefficient matrix; a recipient checks a received row or value against it
+/// with [`BivarCommitment::verify_row`]/[`verify_value`](BivarCommitment::verify_value)
+/// without learning `f`. Once `N + 1` verified values are collected, the secret
+/// is recovered by [`reconstruct`](Self::reconstruct) (Lagrange interpolation
+/// over `Coeff<P>`).
+///
+/// The commitment is a Feldman commitment in the multiplicative group of the
+/// field — `g^{c_{i,j}} mod P` for a fixed primitive root `g` — which is the
+/// group this arithmetic-only module actually has; it is binding for a
+/// cryptographically sized `P`.
+#[derive(Debug, Clone)]
+pub struct BivarPolynomial<const P: i64, const N: u32> {
+    // Row-major `(N+1)×(N+1)` symmetric coefficient matrix.
+    coeffs: Vec<Coeff<P>>,
+}
+
+impl<const P: i64, const N: u32> BivarPolynomial<P, N> {
+    /// Side length `N + 1` of the coefficient matrix (`N` is the degree `t`).
+    const T: usize = N as usize + 1;
+
+    #[must_use]
+    /// Samples a symmetric bivariate polynomial whose constant term is `secret`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the distribution fails to generate randomness.
+    pub fn new_symmetric<D: Distribution>(secret: Coeff<P>, d: &D) -> Self
+    where
+        D::Output: Into<i64>,
+    {
+        let t = Self::T;
+        let mut coeffs = alloc::vec![Coeff(0); t * t];
+        for i in 0..t {
+            for j in i..t {
+                let c = if i == 0 && j == 0 {
+                    secret
+                } else {
+                    Coeff::new(d.sample().unwrap().into())
+                };
+                coeffs[i * t + j] = c;
+                coeffs[j * t + i] = c;
+            }
+        }
+        Self { coeffs }
+    }
+
+    #[must_use]
+    #[inline]
+    /// The degree `t` of the polynomial; `t + 1` shares reconstruct the secret.
+    pub const fn degree(&self) -> usize {
+        N as usize
+    }
+
+    #[must_use]
+    /// Evaluates `f(x, y)`.
+    pub fn value(&self, x: Coeff<P>, y: Coeff<P>) -> Coeff<P> {
+        let t = Self::T;
+        let mut acc = Coeff::new(0);
+        // Horner over x of the inner Horner over y.
+        for i in (0..t).rev() {
+            let mut inner = Coeff::new(0);
+            for j in (0..t).rev() {
+                inner = inner * y + self.coeffs[i * t + j];
+            }
+            acc = acc * x + inner;
+        }
+        acc
+    }
+
+    #[must_use]
+    /// Returns the coefficients of the univariate row `f(m, y)`, low degree
+    /// first.
+    pub fn row(&self, m: Coeff<P>) -> Vec<Coeff<P>> {
+        let t = Self::T;
+        let mut row = alloc::vec![Coeff::new(0); t];
+        for (k, slot) in row.iter_mut().enumerate() {
+            // Horner over i of column k: Σ_i c_{i,k} m^i.
+            let mut acc = Coeff::new(0);
+            for i in (0..t).rev() {
+                acc = acc * m + self.coeffs[i * t + k];
+            }
+            *slot = acc;
+        }
+        row
+    }
+
+    #[must_use]
+    /// Publishes the Feldman commitment to the coefficient matrix.
+    pub fn commitment(&self) -> BivarCommitment<P, N> {
+        let g = feldman_generator::<P, N>();
+        let rows = self
+            .coeffs
+            .iter()
+            .map(|c| Polynomial::<P, N>::powmod(g, c.as_i64() as u64))
+            .collect();
+        BivarCommitment { g, rows }
+    }
+
+    #[must_use]
+    /// Reconstructs `f(0, 0)` from `t + 1` or more `(index, value)` shares by
+    /// Lagrange interpolation at `x = 0`.
+    ///
+    /// Returns `None` if fewer than `t + 1` shares are supplied or two shares
+    /// reuse an index (which makes the interpolation singular).
+    pub fn reconstruct(shares: &[(Coeff<P>, Coeff<P>)]) -> Option<Coeff<P>> {
+        if shares.len() <= N as usize {
+            return None;
+        }
+        let points = &shares[..=N as usize];
+        let mut secret = Coeff::new(0);
+        for (i, &(xi, yi)) in points.iter().enumerate() {
+            let mut num = Coeff::new(1);
+            let mut den = Coeff::new(1);
+            for (j, &(xj, _)) in points.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                if xi == xj {
+                    return None;
+                }
+                // Lagrange basis at x = 0: Π_{j≠i} (0 − xj) / (xi − xj).
+                num = num * (Coeff::new(0) - xj);
+                den = den * (xi - xj);
+            }
+            secret = secret + yi * num * inv::<P, N>(den)?;
+        }
+        Some(secret)
+    }
+}
+
+/// A Feldman commitment to a [`BivarPolynomial`]'s coefficient matrix.
+///
+/// Holds the public generator `g` and `g^{c_{i,j}} mod P` for every
+/// coefficient, enough to verify a row or value without revealing `f`.
+#[derive(Debug, Clone)]
+pub struct BivarCommitment<const P: i64, const N: u32> {
+    g: i64,
+    rows: Vec<i64>,
+}
+
+impl<const P: i64, const N: u32> BivarCommitment<P, N> {
+    const T: usize = N as usize + 1;
+
+    #[must_use]
+    /// Verifies that `row` is genuinely `f(m, ·)` for the committed `f`.
+    ///
+    /// Checks `g^{row_k} ≟ Π_i C_{i,k}^{m^i}` for every column `k`.
+    pub fn verify_row(&self, m: Coeff<P>, row: &[Coeff<P>]) -> bool {
+        let t = Self::T;
+        if row.len() != t {
+            return false;
+        }
+        for (k, coeff) in row.iter().enumerate() {
+            let lhs = Polynomial::<P, N>::powmod(self.g, coeff.as_i64() as u64);
+            let mut rhs = 1i64;
+            let mut m_pow = 1i64;
+            for i in 0..t {
+                let term = Polynomial::<P, N>::powmod(self.rows[i * t + k], m_pow as u64);
+                rhs = Polynomial::<P, N>::mulmod(rhs, term);
+                m_pow = Polynomial::<P, N>::mulmod(m_pow, m.as_i64());
+            }
+            if lhs != rhs {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[must_use]
+    /// Verifies a single committed value `f(m, s)`.
+    ///
+    /// Checks `g^{value} ≟ Π_{i,j} C_{i,j}^{m^i s^j}`.
+    pub fn verify_value(&self, m: Coeff<P>, s: Coeff<P>, value: Coeff<P>) -> bool {
+        let t = Self::T;
+        let lhs = Polynomial::<P, N>::powmod(self.g, value.as_i64() as u64);
+        let mut rhs = 1i64;
+        let mut m_pow = 1i64;
+        for i in 0..t {
+            let mut s_pow = 1i64;
+            for j in 0..t {
+                let exp = Polynomial::<P, N>::mulmod(m_pow, s_pow);
+                let term = Polynomial::<P, N>::powmod(self.rows[i * t + j], exp as u64);
+                rhs = Polynomial::<P, N>::mulmod(rhs, term);
+                s_pow = Polynomial::<P, N>::mulmod(s_pow, s.as_i64());
+            }
+            m_pow = Polynomial::<P, N>::mulmod(m_pow, m.as_i64());
+        }
+        lhs == rhs
+    }
+}
+
+/// Modular inverse of `c` in `Z/pZ` via Fermat's little theorem, or `None` for
+/// zero (which has no inverse).
+fn inv<const P: i64, const N: u32>(c: Coeff<P>) -> Option<Coeff<P>> {
+    if c.as_i64() == 0 {
+        return None;
+    }
+    let raw = Polynomial::<P, N>::powmod(c.as_i64(), (P - 2) as u64);
+    // Safe: `powmod` keeps the result in [0, P).
+    Some(unsafe { Coeff::new_unchecked(raw) })
+}
+
+/// Picks a fixed primitive root of `P` to anchor the Feldman commitment.
+///
+/// Any generator works for the verification equation; a primitive root is
+/// chosen so the commitment spans the whole multiplicative group.
+fn feldman_generator<const P: i64, const N: u32>() -> i64 {
+    // Trial-divide P − 1 and keep the smallest g whose order is exactly P − 1.
+    let phi = P - 1;
+    let mut factors = Vec::new();
+    let mut n = phi;
+    let mut d = 2i64;
+    while d * d <= n {
+        if n % d == 0 {
+            factors.push(d);
+            while n % d == 0 {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    for g in 2..P {
+        if factors
+            .iter()
+            .all(|&q| Polynomial::<P, N>::powmod(g, (phi / q) as u64) != 1)
+        {
+            return g;
+        }
+    }
+    // P = 2 has the trivial group; fall back to 1.
+    1
+}
+
+impl<const P: i64, const N: u32> crate::secret::MemRange for Polynomial<P, N> {
+    #[inline]
+    fn mem_range(&self) -> (*const u8, usize) {
+        // The sensitive material is the coefficient buffer itself; locking its
+        // heap pages keeps secret coefficients off swap.
+        (
+            self.coeffs.as_ptr().cast(),
+            self.coeffs.len() * core::mem::size_of::<Coeff<P>>(),
+        )
+    }
+}
+
+/// A secret-key polynomial whose coefficient buffer is memory-locked for its
+/// whole lifetime.
+///
+/// Ordinary [`Polynomial::random`] leaves its coefficients in a plain `Vec`
+/// that can be paged to disk or linger in freed heap pages; a
+/// `SecretPolynomial` routes the same buffer through [`Secret`], which
+/// `mlock`s the pages on construction and `munlock`s + zeroizes them on drop.
+/// It derefs to the guarded [`Polynomial`], so the usual ring arithmetic is
+/// available while the buffer stays pinned.
+///
+/// Only compiled with the `mlock` feature; without it the buffer cannot be
+/// locked and the plain [`Polynomial`] should be used instead.
+#[cfg(feature = "mlock")]
+pub struct SecretPolynomial<const P: i64, const N: u32>(
+    crate::secret::Secret<Polynomial<P, N>>,
+);
+
+#[cfg(feature = "mlock")]
+impl<const P: i64, const N: u32> SecretPolynomial<P, N> {
+    /// Samples a secret-distribution polynomial and locks its buffer.
+    ///
+    /// The coefficients are drawn from `d` and the backing pages locked in
+    /// place, so the sampled material is pinned against paging from the first
+    /// moment it is owned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::MlockFailed`](crate::secret::SecretError::MlockFailed)
+    /// when the OS refuses to lock the buffer (e.g. `RLIMIT_MEMLOCK` exceeded).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the distribution fails to generate randomness; use
+    /// [`try_sample`](Self::try_sample) to handle sampler exhaustion instead.
+    pub fn sample<D: Distribution>(d: &D) -> Result<Self, crate::secret::SecretError>
+    where
+        D::Output: Into<i64>,
+    {
+        crate::secret::Secret::new(Polynomial::random(d)).map(Self)
+    }
+
+    /// Fallible counterpart to [`sample`](Self::sample) that propagates both
+    /// sampler and lock failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns the sampler error in the outer `Err` if the distribution fails
+    /// to produce a value; no locking is attempted then. Otherwise returns
+    /// `Ok` holding the result of locking, which is
+    /// [`SecretError::MlockFailed`](crate::secret::SecretError::MlockFailed)
+    /// when the buffer cannot be locked.
+    pub fn try_sample<D: Distribution>(
+        d: &D,
+    ) -> crate::rand::RandResult<Result<Self, crate::secret::SecretError>>
+    where
+        D::Output: Into<i64>,
+    {
+        Ok(crate::secret::Secret::new(Polynomial::try_random(d)?).map(Self))
+    }
+}
+
+#[cfg(feature = "mlock")]
+impl<const P: i64, const N: u32> core::ops::Deref for SecretPolynomial<P, N> {
+    type Target = Polynomial<P, N>;
+    #[inline]
+    fn deref(&self) -> &Polynomial<P, N> {
+        &self.0
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64> bincode::Encode for Coeff<P> {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.as_i64().encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, Context> bincode::Decode<Context> for Coeff<P> {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        // Route through `new` so the coefficient is re-reduced into `[0, P)`.
+        Ok(Self::new(i64::decode(decoder)?))
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32> bincode::Encode for Polynomial<P, N> {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.coeffs.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const P: i64, const N: u32, Context> bincode::Decode<Context> for Polynomial<P, N> {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        Ok(Self {
+            coeffs: Vec::<Coeff<P>>::decode(decoder)?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,6 +987,71 @@ mod tests {
         assert_eq!((-c1).as_i64(), 4);
     }
 
+    #[test]
+    fn test_barrett_matches_i128() {
+        // Barrett reduction must agree with the straight i128 `rem_euclid`
+        // reference over the whole [0, P²) domain, including the boundary
+        // values just below P² where the quotient estimate is tightest.
+        const P: i64 = 17;
+        for x in 0..(P as i128 * P as i128) {
+            assert_eq!(
+                Coeff::<P>::barrett_reduce(x),
+                (x % P as i128) as i64,
+                "mismatch at x = {x}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_barrett_mul_matches_reference() {
+        // The `Mul` impl (Barrett-backed) must match the reduced product for a
+        // batch of random operands.
+        use crate::rand::distributions::Uniform;
+
+        const P: i64 = 17;
+        let d = Uniform::<i32>::new(0..=(P as i32 - 1));
+        for _ in 0..64 {
+            let a = d.sample().unwrap() as i64;
+            let b = d.sample().unwrap() as i64;
+            let got = (Coeff::<P>::new(a) * Coeff::<P>::new(b)).as_i64();
+            assert_eq!(got, (a * b).rem_euclid(P));
+        }
+    }
+
+    #[test]
+    fn test_barrett_reduce_large_ntt_prime_does_not_overflow() {
+        // 2^61 - 1, a Mersenne prime in the range chunk0-1's NTT feature
+        // actually needs: `BARRETT_K` lands around 122 bits, so `x * BARRETT_M`
+        // reaches ~190 bits — wide enough that a plain `i128` multiply would
+        // panic (debug) or silently wrap (release) before `barrett_reduce`
+        // ever reduced anything.
+        const P: i64 = 2_305_843_009_213_693_951;
+        let pp = P as i128 * P as i128;
+        for x in (pp - 10)..pp {
+            assert_eq!(
+                Coeff::<P>::barrett_reduce(x),
+                (x % P as i128) as i64,
+                "mismatch at x = {x}"
+            );
+        }
+        for x in 0..10 {
+            assert_eq!(
+                Coeff::<P>::barrett_reduce(x),
+                (x % P as i128) as i64,
+                "mismatch at x = {x}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_large_ntt_prime_does_not_overflow() {
+        const P: i64 = 2_305_843_009_213_693_951;
+        let a = Coeff::<P>::new(P - 1);
+        let b = Coeff::<P>::new(P - 1);
+        let expected = ((P as i128 - 1) * (P as i128 - 1)).rem_euclid(P as i128) as i64;
+        assert_eq!((a * b).as_i64(), expected);
+    }
+
     #[test]
     fn test_polynomial() {
         let p1 = Polynomial::<7, 3>::new(vec![1, 2, 3, 4, 5, 6, 7, 8]);
@@ -268,4 +1059,105 @@ mod tests {
 
         // TODO: Other tests
     }
+
+    #[test]
+    fn test_multiply_ntt_matches_schoolbook() {
+        // 17 is prime with 2·8 = 16 | 16, so a negacyclic NTT is available.
+        let p1 = Polynomial::<17, 3>::new(vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        let p2 = Polynomial::<17, 3>::new(vec![8, 7, 6, 5, 4, 3, 2, 1]);
+
+        let school = Polynomial::multiply_schoolbook(&p1, &p2);
+        let ntt = Polynomial::multiply_ntt(&p1, &p2);
+        assert_eq!(school, ntt);
+        // `multiply` dispatches to the NTT path for this NTT-friendly prime.
+        assert_eq!(Polynomial::multiply(&p1, &p2), ntt);
+    }
+
+    #[test]
+    fn test_multiply_ntt_matches_schoolbook_random() {
+        // Cross-check the NTT path against the schoolbook reference over a batch
+        // of random operands. P = 17 admits a 2·8-th root, so the NTT branch is
+        // exercised rather than the fallback.
+        use crate::rand::distributions::Uniform;
+
+        let d = Uniform::<i32>::new(0..=16);
+        for _ in 0..32 {
+            let p1 = Polynomial::<17, 3>::random(&d);
+            let p2 = Polynomial::<17, 3>::random(&d);
+            assert_eq!(
+                Polynomial::multiply_schoolbook(&p1, &p2),
+                Polynomial::multiply_ntt(&p1, &p2)
+            );
+        }
+    }
+
+    #[test]
+    fn test_evaluation_domain_roundtrip_and_multiply() {
+        // 17 admits a primitive 2·8-th root, so the domain exists. A forward
+        // then inverse transform must be the identity, and an elementwise
+        // product in the domain must match the coefficient-form product.
+        let domain = EvaluationDomain::<17, 3>::new().unwrap();
+        let p1 = Polynomial::<17, 3>::new(vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        let p2 = Polynomial::<17, 3>::new(vec![8, 7, 6, 5, 4, 3, 2, 1]);
+
+        assert_eq!(domain.from_point_values(&domain.to_point_values(&p1)), p1);
+
+        let mut a = domain.to_point_values(&p1);
+        let b = domain.to_point_values(&p2);
+        domain.mul_assign(&mut a, &b);
+        assert_eq!(
+            domain.from_point_values(&a),
+            Polynomial::multiply(&p1, &p2)
+        );
+    }
+
+    #[test]
+    fn test_bivar_sharing_and_commitment() {
+        use crate::rand::distributions::Uniform;
+
+        let d = Uniform::<i32>::new(0..=16);
+        let secret = Coeff::<17>::new(9);
+        let f = BivarPolynomial::<17, 2>::new_symmetric(secret, &d);
+
+        // Symmetry: f(m, s) == f(s, m).
+        let (m, s) = (Coeff::new(3), Coeff::new(5));
+        assert_eq!(f.value(m, s), f.value(s, m));
+
+        // A row evaluated at a point equals the direct value.
+        let row = f.row(m);
+        let mut acc = Coeff::new(0);
+        for c in row.iter().rev() {
+            acc = acc * s + *c;
+        }
+        assert_eq!(acc, f.value(m, s));
+
+        // The commitment verifies genuine rows and values.
+        let commitment = f.commitment();
+        assert!(commitment.verify_row(m, &row));
+        assert!(commitment.verify_value(m, s, f.value(m, s)));
+
+        // t + 1 = 3 shares of f(x, 0) reconstruct the secret f(0, 0).
+        let shares: Vec<_> = [1i64, 2, 3]
+            .into_iter()
+            .map(|x| {
+                let xi = Coeff::new(x);
+                (xi, f.value(xi, Coeff::new(0)))
+            })
+            .collect();
+        assert_eq!(
+            BivarPolynomial::<17, 2>::reconstruct(&shares),
+            Some(secret)
+        );
+    }
+
+    #[test]
+    fn test_multiply_ntt_fallback() {
+        // 7 does not satisfy 16 | 6, so this exercises the schoolbook fallback.
+        let p1 = Polynomial::<7, 3>::new(vec![1, 0, 2, 0, 0, 0, 0, 0]);
+        let p2 = Polynomial::<7, 3>::new(vec![0, 3, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            Polynomial::multiply(&p1, &p2),
+            Polynomial::multiply_ntt(&p1, &p2)
+        );
+    }
 }
@@ -142,6 +142,325 @@ pub fn rand_gaussian_truncated(mu: f64, sigma: f64, beta: f64) -> RandResult<f64
 /// Result type for `rand` function.
 pub type RandResult<T> = Result<T, getrandom::Error>;
 
+/// Tail cut of the discrete Gaussian support, in multiples of `sigma`.
+#[cfg(feature = "alloc")]
+const DG_TAU: f64 = 6.0;
+
+/// Fixed-point scale of the cumulative table: probabilities are stored as
+/// `u64` multiples of `2^63`, matching the 63-bit uniform draw.
+#[cfg(feature = "alloc")]
+const DG_SCALE: u64 = 1u64 << 63;
+
+/// Constant-time discrete Gaussian sampler over a symmetric, zero-centred
+/// support.
+///
+/// Continuous `gaussian` draws are unsuitable for RLWE/CKKS/BFV error
+/// polynomials, which need *integer* samples, and the rejection loop in
+/// [`rand_gaussian_truncated`] is data-dependent (timing-leaky). This sampler
+/// precomputes a cumulative-distribution table (CDT) over magnitudes
+/// `0..=ceil(DG_TAU * sigma)` once, then resolves each draw with a full-table
+/// branchless scan whose runtime is independent of the sampled value. The sign
+/// is chosen from one extra random bit, so the off-centre masses are symmetric.
+#[cfg(feature = "alloc")]
+pub struct DiscreteGaussian {
+    /// `table[k]` is the scaled cumulative probability `P(|X| <= k)`,
+    /// monotonically non-decreasing with the final entry pinned to [`DG_SCALE`]
+    /// so every 63-bit draw lands inside the support.
+    table: alloc::vec::Vec<u64>,
+}
+
+#[cfg(feature = "alloc")]
+impl DiscreteGaussian {
+    #[must_use]
+    /// Builds the cumulative table for standard deviation `sigma`.
+    ///
+    /// The zero magnitude keeps weight `exp(0) = 1`; every non-zero magnitude is
+    /// doubled to fold in both signs, and the running sum is normalized over the
+    /// whole symmetric support before being scaled into `u64` thresholds.
+    pub fn new(sigma: f64) -> Self {
+        let tail = libm::ceil(DG_TAU * sigma) as usize;
+        let denom = 2.0 * sigma * sigma;
+
+        // Normalization over the full symmetric support: the centre once, every
+        // off-centre magnitude twice (for its two signs).
+        let mut total = 1.0;
+        for k in 1..=tail {
+            let k = k as f64;
+            total += 2.0 * libm::exp(-(k * k) / denom);
+        }
+
+        let mut table = alloc::vec::Vec::with_capacity(tail + 1);
+        let scale = DG_SCALE as f64 / total;
+        let mut cum = 1.0;
+        for k in 0..=tail {
+            if k == tail {
+                table.push(DG_SCALE);
+            } else {
+                table.push((cum * scale) as u64);
+            }
+            let next = (k + 1) as f64;
+            cum += 2.0 * libm::exp(-(next * next) / denom);
+        }
+
+        Self { table }
+    }
+
+    /// Draws one integer sample from `rng`.
+    ///
+    /// Resolves a single 63-bit uniform against the whole table without an early
+    /// exit, then folds in a sign bit, so both the memory-access and branch
+    /// pattern are independent of the returned value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if randomness fails to be generated.
+    pub fn sample<R: RandSource>(&self, rng: &mut R) -> RandResult<i64> {
+        let u = (unsafe { rng.next::<u64>() }? & (DG_SCALE - 1)) as u64;
+        let mut magnitude: i64 = 0;
+        for &threshold in &self.table {
+            // `(u > threshold)` is 0 or 1; accumulate without branching.
+            magnitude += i64::from(u > threshold);
+        }
+        // One extra bit picks the sign; magnitude 0 is unaffected either way.
+        let sign_bit = unsafe { rng.next::<u8>() }? & 1;
+        let signed = if sign_bit == 1 { -magnitude } else { magnitude };
+        Ok(signed)
+    }
+}
+
+/// Draws a single discrete Gaussian sample of standard deviation `sigma` from
+/// the OS entropy source.
+///
+/// Rebuilds the cumulative table on every call; use [`DiscreteGaussian`]
+/// directly to amortize the table across a whole polynomial's coefficients.
+///
+/// # Errors
+///
+/// Returns an error if randomness fails to be generated.
+#[cfg(feature = "alloc")]
+pub fn rand_discrete_gaussian(sigma: f64) -> RandResult<i64> {
+    DiscreteGaussian::new(sigma).sample(&mut OsRng)
+}
+
+/// A source of random bytes the sampling helpers can draw from.
+///
+/// The free functions above always pull from the OS pool; implementing this
+/// trait lets the same `range`/`gaussian` logic run against either the OS
+/// entropy source ([`OsRng`]) or a deterministic, seedable stream
+/// ([`ChaCha20Rng`]) so key generation and noise sampling can be replayed in
+/// tests.
+pub trait RandSource {
+    /// Fill `dest` with random bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if randomness fails to be generated.
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> RandResult<()>;
+
+    /// Draw a random instance of type `T`.
+    ///
+    /// # Safety
+    ///
+    /// Any arbitrary sequence of bytes (of len `size_of::<T>()`) must be a
+    /// valid instance of type `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if randomness fails to be generated.
+    unsafe fn next<T: Sized>(&mut self) -> RandResult<T> {
+        let mut value = MaybeUninit::<T>::uninit();
+        // Safety:
+        // `MaybeUninit<T>` has the same layout as `T`, so the bytes can be
+        // filled in place; the caller guarantees every bit pattern is valid.
+        let rd_slice = unsafe {
+            core::slice::from_raw_parts_mut(value.as_mut_ptr().cast::<u8>(), size_of::<T>())
+        };
+        self.fill_bytes(rd_slice)?;
+        Ok(unsafe { value.assume_init() })
+    }
+
+    /// Generate a random instance of type `T` in the given range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if randomness fails to be generated.
+    fn range<T: RandRange>(
+        &mut self,
+        r: core::ops::Range<T>,
+    ) -> RandResult<<T as Add>::Output> {
+        let rd = unsafe { self.next::<T>() }?;
+        let modulus = r.end - r.start;
+        Ok(RandRange::rem_euclid(rd, modulus) + r.start)
+    }
+
+    /// Generate a random number using a Gaussian distribution.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if randomness fails to be generated.
+    fn gaussian(&mut self, mu: f64, sigma: f64) -> RandResult<f64> {
+        use core::f64::consts::PI;
+        use libm::{cos, log, sqrt};
+
+        let u1: f64 = self.range(0.0..1.0)?;
+        let u2: f64 = self.range(0.0..1.0)?;
+
+        // Morph into normal distribution using Box-Muller's method
+        let z0 = sqrt(-2.0 * log(u1)) * cos(2.0 * PI * u2);
+
+        // Reshape the distribution
+        Ok(mu + sigma * z0)
+    }
+}
+
+/// The OS entropy source, backed by `getrandom`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsRng;
+
+impl RandSource for OsRng {
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> RandResult<()> {
+        getrandom::fill(dest)
+    }
+}
+
+/// A seedable random source whose stream is fully determined by a 32-byte seed.
+pub trait SeedableRng: RandSource {
+    /// Build a generator from a fixed 32-byte seed.
+    fn from_seed(seed: [u8; 32]) -> Self;
+    /// Restart the stream from a fresh 32-byte seed.
+    fn reseed(&mut self, seed: [u8; 32]);
+}
+
+/// Deterministic keystream generator backed by the ChaCha20 block function.
+///
+/// The 16-word state holds the four `"expand 32-byte k"` constants, eight key
+/// words taken from the seed, a block counter, and a three-word nonce (left at
+/// zero by [`from_seed`](SeedableRng::from_seed)). Each 64-byte block is
+/// produced by 20 rounds of the quarter-round permutation, buffered, and
+/// refilled on exhaustion with the counter incremented per block.
+#[derive(Debug, Clone)]
+pub struct ChaCha20Rng {
+    state: [u32; 16],
+    block: [u8; 64],
+    /// Offset of the next unused byte in `block`; `64` means exhausted.
+    pos: usize,
+}
+
+impl ChaCha20Rng {
+    /// `"expand 32-byte k"` as four little-endian words.
+    const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+    /// One ChaCha quarter-round on the working state indices `a, b, c, d`.
+    #[inline]
+    fn quarter_round(s: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        s[a] = s[a].wrapping_add(s[b]);
+        s[d] = (s[d] ^ s[a]).rotate_left(16);
+        s[c] = s[c].wrapping_add(s[d]);
+        s[b] = (s[b] ^ s[c]).rotate_left(12);
+        s[a] = s[a].wrapping_add(s[b]);
+        s[d] = (s[d] ^ s[a]).rotate_left(8);
+        s[c] = s[c].wrapping_add(s[d]);
+        s[b] = (s[b] ^ s[c]).rotate_left(7);
+    }
+
+    /// Run the block function on the current state into `self.block` and bump
+    /// the block counter (state word 12).
+    fn refill(&mut self) {
+        let mut working = self.state;
+        // 20 rounds = 10 column-round/diagonal-round pairs.
+        for _ in 0..10 {
+            Self::quarter_round(&mut working, 0, 4, 8, 12);
+            Self::quarter_round(&mut working, 1, 5, 9, 13);
+            Self::quarter_round(&mut working, 2, 6, 10, 14);
+            Self::quarter_round(&mut working, 3, 7, 11, 15);
+            Self::quarter_round(&mut working, 0, 5, 10, 15);
+            Self::quarter_round(&mut working, 1, 6, 11, 12);
+            Self::quarter_round(&mut working, 2, 7, 8, 13);
+            Self::quarter_round(&mut working, 3, 4, 9, 14);
+        }
+        for (i, word) in working.iter_mut().enumerate() {
+            let out = word.wrapping_add(self.state[i]);
+            self.block[i * 4..i * 4 + 4].copy_from_slice(&out.to_le_bytes());
+        }
+        self.state[12] = self.state[12].wrapping_add(1);
+        self.pos = 0;
+    }
+}
+
+impl SeedableRng for ChaCha20Rng {
+    fn from_seed(seed: [u8; 32]) -> Self {
+        let mut state = [0u32; 16];
+        state[0..4].copy_from_slice(&Self::CONSTANTS);
+        for (i, chunk) in seed.chunks_exact(4).enumerate() {
+            state[4 + i] = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        // Words 12..=15 (counter and nonce) stay zero.
+        Self {
+            state,
+            block: [0u8; 64],
+            pos: 64,
+        }
+    }
+
+    fn reseed(&mut self, seed: [u8; 32]) {
+        *self = Self::from_seed(seed);
+    }
+}
+
+impl RandSource for ChaCha20Rng {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> RandResult<()> {
+        for byte in dest {
+            if self.pos == 64 {
+                self.refill();
+            }
+            *byte = self.block[self.pos];
+            self.pos += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Reseeding wrapper that refreshes its inner generator from the OS after a
+/// configurable number of emitted bytes.
+///
+/// Long-running services gain forward secrecy — a leaked state only exposes the
+/// keystream since the last reseed — while tests can still drive the inner
+/// generator from a fixed seed by setting a threshold larger than the run.
+#[derive(Debug, Clone)]
+pub struct ReseedingRng<R: SeedableRng> {
+    inner: R,
+    threshold: u64,
+    emitted: u64,
+}
+
+impl<R: SeedableRng> ReseedingRng<R> {
+    /// Wrap `inner`, pulling a fresh seed once `threshold` bytes have been
+    /// emitted.
+    #[must_use]
+    pub const fn new(inner: R, threshold: u64) -> Self {
+        Self {
+            inner,
+            threshold,
+            emitted: 0,
+        }
+    }
+}
+
+impl<R: SeedableRng> RandSource for ReseedingRng<R> {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> RandResult<()> {
+        if self.emitted >= self.threshold {
+            let mut seed = [0u8; 32];
+            getrandom::fill(&mut seed)?;
+            self.inner.reseed(seed);
+            self.emitted = 0;
+        }
+        self.inner.fill_bytes(dest)?;
+        self.emitted = self.emitted.saturating_add(dest.len() as u64);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,6 +501,80 @@ mod tests {
         unsafe { rand::<Weird>() }.unwrap();
     }
 
+    #[test]
+    fn chacha20_is_reproducible() {
+        let seed = [7u8; 32];
+        let mut a = ChaCha20Rng::from_seed(seed);
+        let mut b = ChaCha20Rng::from_seed(seed);
+
+        let mut buf_a = [0u8; 128];
+        let mut buf_b = [0u8; 128];
+        a.fill_bytes(&mut buf_a).unwrap();
+        b.fill_bytes(&mut buf_b).unwrap();
+        assert_eq!(buf_a, buf_b);
+    }
+
+    #[test]
+    fn chacha20_differs_across_seeds_and_blocks() {
+        let mut a = ChaCha20Rng::from_seed([1u8; 32]);
+        let mut b = ChaCha20Rng::from_seed([2u8; 32]);
+        let mut buf_a = [0u8; 64];
+        let mut buf_b = [0u8; 64];
+        a.fill_bytes(&mut buf_a).unwrap();
+        b.fill_bytes(&mut buf_b).unwrap();
+        assert_ne!(buf_a, buf_b);
+
+        // A second block must not repeat the first (counter advanced).
+        let first = buf_a;
+        a.fill_bytes(&mut buf_a).unwrap();
+        assert_ne!(first, buf_a);
+    }
+
+    #[test]
+    fn chacha20_range_is_deterministic() {
+        let mut a = ChaCha20Rng::from_seed([42u8; 32]);
+        let mut b = ChaCha20Rng::from_seed([42u8; 32]);
+        for _ in 0..32 {
+            let x: i64 = a.range(-5..37).unwrap();
+            let y: i64 = b.range(-5..37).unwrap();
+            assert_eq!(x, y);
+            assert!((-5..37).contains(&x));
+        }
+    }
+
+    #[test]
+    fn reseeding_keeps_producing_bytes() {
+        // A tiny threshold forces a reseed mid-run; it must stay functional.
+        let mut rng = ReseedingRng::new(ChaCha20Rng::from_seed([0u8; 32]), 16);
+        let mut buf = [0u8; 256];
+        rng.fill_bytes(&mut buf[..32]).unwrap();
+        rng.fill_bytes(&mut buf[32..]).unwrap();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn discrete_gaussian_is_reproducible() {
+        let dg = DiscreteGaussian::new(3.2);
+        let mut a = ChaCha20Rng::from_seed([9u8; 32]);
+        let mut b = ChaCha20Rng::from_seed([9u8; 32]);
+        for _ in 0..64 {
+            assert_eq!(dg.sample(&mut a).unwrap(), dg.sample(&mut b).unwrap());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn discrete_gaussian_stays_in_support() {
+        let sigma = 2.5;
+        let tail = libm::ceil(DG_TAU * sigma) as i64;
+        let dg = DiscreteGaussian::new(sigma);
+        let mut rng = ChaCha20Rng::from_seed([3u8; 32]);
+        for _ in 0..1024 {
+            let x = dg.sample(&mut rng).unwrap();
+            assert!(x.abs() <= tail);
+        }
+    }
+
     #[test]
     fn test_gaussian_truncated() {
         let mu = 0.0;
@@ -142,6 +142,54 @@ where
     }
 }
 
+/// Tail cut of the discrete Gaussian support, shared with the sibling
+/// sampler in [`crate::rand`] so the two never disagree on it.
+#[cfg(feature = "alloc")]
+use super::DG_TAU;
+
+/// Integer-valued discrete Gaussian `D_{σ,c}` over `ℤ`, for RLWE/FHE noise.
+///
+/// Rounding a continuous [`Gaussian`] sample does not reproduce the correct
+/// tail behaviour, which error sampling in keygen/noise paths needs. A
+/// data-dependent binary search over a CDT would reintroduce exactly the
+/// timing/cache side-channel [`crate::rand::DiscreteGaussian`] was built to
+/// avoid, so this type adds only a `center` on top of it: the zero-centred
+/// magnitude comes straight out of that constant-time, branchless-scan
+/// sampler, drawn from the OS entropy source.
+#[cfg(feature = "alloc")]
+pub struct DiscreteGaussian {
+    /// Center `c` of the distribution; rounded into the integer result.
+    center: f64,
+    /// The constant-time, zero-centred sampler this type delegates to.
+    inner: crate::rand::DiscreteGaussian,
+}
+
+#[cfg(feature = "alloc")]
+impl DiscreteGaussian {
+    #[must_use]
+    #[inline]
+    /// Builds the cumulative table for standard deviation `sigma` and center `c`.
+    ///
+    /// The table only depends on `sigma`, so repeated instances for the same
+    /// `sigma` (regardless of `c`) recompute identical weights; callers that
+    /// sample many times under one `sigma` should keep this instance around
+    /// rather than rebuilding it per draw.
+    pub fn new(sigma: f64, center: f64) -> Self {
+        Self {
+            center,
+            inner: crate::rand::DiscreteGaussian::new(sigma),
+        }
+    }
+}
+
+impl Distribution for DiscreteGaussian {
+    type Output = i64;
+    fn sample(&self) -> RandResult<i64> {
+        let signed = self.inner.sample(&mut crate::rand::OsRng)?;
+        Ok(crate::f64::round(self.center) + signed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,6 +210,28 @@ mod tests {
         let _sample = g.sample().unwrap();
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_discrete_gaussian_stays_in_support() {
+        let sigma = 2.5;
+        let tail = libm::ceil(DG_TAU * sigma) as i64;
+        let dg = DiscreteGaussian::new(sigma, 0.0);
+        for _ in 0..256 {
+            let x = dg.sample().unwrap();
+            assert!(x.abs() <= tail);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_discrete_gaussian_centers() {
+        let dg = DiscreteGaussian::new(1.5, 100.0);
+        for _ in 0..64 {
+            let x = dg.sample().unwrap();
+            assert!((80..=120).contains(&x));
+        }
+    }
+
     #[test]
     fn test_truncated() {
         let g = Gaussian {
@@ -0,0 +1,260 @@
+//! Memory-locked, auto-zeroizing guard for secret key material.
+//!
+//! [`Secret<T>`] wraps a value whose backing pages hold sensitive bytes. On
+//! construction the pages are `mlock`ed so the kernel never swaps them to disk;
+//! on drop they are `munlock`ed and the value is zeroized. When the `mlock`
+//! feature is disabled, or the target has no `mlock` syscall, the guard
+//! degrades to zeroize-only behaviour.
+//!
+//! The locking discipline mirrors the `MemRange`/`ContainsSecret` split of
+//! `threshold-crypto`: [`MemRange`] pins the exact buffer to protect, and
+//! [`Secret`] owns the lock/unlock lifecycle around it.
+
+use core::ops::{Deref, DerefMut};
+
+use zeroize::Zeroize;
+
+/// A type able to expose the `(pointer, byte length)` of the buffer that holds
+/// its secret material, so that [`Secret`] knows which pages to `mlock`.
+pub trait MemRange {
+    /// Returns the pointer and length, in bytes, of the sensitive buffer.
+    ///
+    /// The range must stay valid and stable for as long as the value is owned
+    /// by a [`Secret`]; reallocating the buffer while locked is undefined.
+    fn mem_range(&self) -> (*const u8, usize);
+}
+
+/// Error surfaced when a secret buffer cannot be memory-locked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretError {
+    /// The `mlock` syscall failed, typically because `RLIMIT_MEMLOCK`
+    /// (`ulimit -l`) is too low. Raise the limit or opt out with
+    /// `MLOCK_SECRETS=false`.
+    MlockFailed {
+        /// `errno` reported by the failed syscall.
+        errno: i32,
+        /// Start address of the range that could not be locked.
+        addr: usize,
+        /// Length, in bytes, of that range.
+        n_bytes: usize,
+    },
+    /// The `munlock` syscall failed while releasing a locked range on drop.
+    MunlockFailed {
+        /// `errno` reported by the failed syscall.
+        errno: i32,
+        /// Start address of the range that could not be unlocked.
+        addr: usize,
+        /// Length, in bytes, of that range.
+        n_bytes: usize,
+    },
+}
+
+impl core::fmt::Display for SecretError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MlockFailed {
+                errno,
+                addr,
+                n_bytes,
+            } => write!(
+                f,
+                "failed to mlock {n_bytes} secret bytes at {addr:#x} (errno {errno}); \
+                 raise ulimit -l or set MLOCK_SECRETS=false",
+            ),
+            Self::MunlockFailed {
+                errno,
+                addr,
+                n_bytes,
+            } => write!(
+                f,
+                "failed to munlock {n_bytes} secret bytes at {addr:#x} (errno {errno})",
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SecretError {}
+
+/// An owning guard that memory-locks and zeroizes its contents.
+///
+/// Deref access yields the inner value, so a `Secret<Polynomial<P, N>>` behaves
+/// like the polynomial it guards while keeping the buffer pinned and wiped.
+pub struct Secret<T: Zeroize + MemRange> {
+    value: T,
+    locked: bool,
+}
+
+impl<T: Zeroize + MemRange> Secret<T> {
+    /// Locks the backing pages of `value` and wraps it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::MlockFailed`] when locking fails and the
+    /// `MLOCK_SECRETS` opt-out is not set to `false`.
+    #[inline]
+    pub fn new(value: T) -> Result<Self, SecretError> {
+        let (ptr, len) = value.mem_range();
+        let locked = lock(ptr, len)?;
+        Ok(Self { value, locked })
+    }
+}
+
+impl<T: Zeroize + MemRange + Clone> Clone for Secret<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let value = self.value.clone();
+        let locked = {
+            let (ptr, len) = value.mem_range();
+            lock(ptr, len).unwrap_or(false)
+        };
+        Self { value, locked }
+    }
+}
+
+impl<T: Zeroize + MemRange> core::fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Secret(..)")
+    }
+}
+
+impl<T: Zeroize + MemRange> Deref for Secret<T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Zeroize + MemRange> DerefMut for Secret<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Zeroize + MemRange> Drop for Secret<T> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.locked {
+            let (ptr, len) = self.value.mem_range();
+            // Best effort: a drop cannot propagate, so a failed munlock is
+            // surfaced only where a logger exists and otherwise discarded.
+            if let Err(_e) = unlock(ptr, len) {
+                #[cfg(feature = "std")]
+                eprintln!("{_e}");
+            }
+        }
+        self.value.zeroize();
+    }
+}
+
+/// Locks the `len` bytes at `ptr`, returning whether the lock actually took.
+///
+/// Degrades to a no-op (returning `Ok(false)`) when `mlock` is unavailable or
+/// explicitly disabled through `MLOCK_SECRETS=false`.
+#[cfg(all(feature = "mlock", unix))]
+fn lock(ptr: *const u8, len: usize) -> Result<bool, SecretError> {
+    if len == 0 || !mlock_enabled() {
+        return Ok(false);
+    }
+    // SAFETY: `ptr`/`len` describe a live, owned buffer provided by `MemRange`.
+    let rc = unsafe { libc::mlock(ptr.cast(), len) };
+    if rc == 0 {
+        Ok(true)
+    } else {
+        // SAFETY: reading the thread-local errno right after the failed call.
+        Err(SecretError::MlockFailed {
+            errno: unsafe { *libc::__errno_location() },
+            addr: ptr as usize,
+            n_bytes: len,
+        })
+    }
+}
+
+#[cfg(all(feature = "mlock", unix))]
+fn unlock(ptr: *const u8, len: usize) -> Result<(), SecretError> {
+    if len == 0 {
+        return Ok(());
+    }
+    // SAFETY: matches the range locked in `lock`.
+    let rc = unsafe { libc::munlock(ptr.cast(), len) };
+    if rc == 0 {
+        Ok(())
+    } else {
+        // SAFETY: reading the thread-local errno right after the failed call.
+        Err(SecretError::MunlockFailed {
+            errno: unsafe { *libc::__errno_location() },
+            addr: ptr as usize,
+            n_bytes: len,
+        })
+    }
+}
+
+/// `VirtualLock`/`VirtualUnlock` from `kernel32`, declared directly so the guard
+/// does not pull in a Windows bindings crate.
+#[cfg(all(feature = "mlock", windows))]
+#[link(name = "kernel32")]
+extern "system" {
+    fn VirtualLock(addr: *mut core::ffi::c_void, size: usize) -> i32;
+    fn VirtualUnlock(addr: *mut core::ffi::c_void, size: usize) -> i32;
+    fn GetLastError() -> u32;
+}
+
+#[cfg(all(feature = "mlock", windows))]
+fn lock(ptr: *const u8, len: usize) -> Result<bool, SecretError> {
+    if len == 0 || !mlock_enabled() {
+        return Ok(false);
+    }
+    // SAFETY: `ptr`/`len` describe a live, owned buffer provided by `MemRange`.
+    let rc = unsafe { VirtualLock(ptr as *mut core::ffi::c_void, len) };
+    if rc != 0 {
+        Ok(true)
+    } else {
+        Err(SecretError::MlockFailed {
+            // SAFETY: read the last error straight after the failed call.
+            errno: unsafe { GetLastError() } as i32,
+            addr: ptr as usize,
+            n_bytes: len,
+        })
+    }
+}
+
+#[cfg(all(feature = "mlock", windows))]
+fn unlock(ptr: *const u8, len: usize) -> Result<(), SecretError> {
+    if len == 0 {
+        return Ok(());
+    }
+    // SAFETY: matches the range locked in `lock`.
+    let rc = unsafe { VirtualUnlock(ptr as *mut core::ffi::c_void, len) };
+    if rc != 0 {
+        Ok(())
+    } else {
+        Err(SecretError::MunlockFailed {
+            // SAFETY: read the last error straight after the failed call.
+            errno: unsafe { GetLastError() } as i32,
+            addr: ptr as usize,
+            n_bytes: len,
+        })
+    }
+}
+
+/// Reads the `MLOCK_SECRETS` opt-out; `false`, `0` or `no` (in any case)
+/// disables locking, and any other value, or an unset variable, keeps it enabled.
+#[cfg(all(feature = "mlock", any(unix, windows)))]
+fn mlock_enabled() -> bool {
+    match std::env::var("MLOCK_SECRETS") {
+        Ok(v) => !matches!(v.trim().to_ascii_lowercase().as_str(), "false" | "0" | "no"),
+        Err(_) => true,
+    }
+}
+
+#[cfg(not(all(feature = "mlock", any(unix, windows))))]
+fn lock(_ptr: *const u8, _len: usize) -> Result<bool, SecretError> {
+    Ok(false)
+}
+
+#[cfg(not(all(feature = "mlock", any(unix, windows))))]
+fn unlock(_ptr: *const u8, _len: usize) -> Result<(), SecretError> {
+    Ok(())
+}
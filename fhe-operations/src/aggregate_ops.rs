@@ -0,0 +1,208 @@
+//! Additive secret-shared data for Prio-style private aggregation.
+//!
+//! Where [`seq_ops`](crate::seq_ops) ships one ciphertext per client operation
+//! for the server to evaluate and echo back, this module never ships a
+//! ciphertext at all: each client's numeric contribution is split into
+//! additive shares across `n` non-colluding servers, and only the *sum* of
+//! every server's partial total reveals the aggregate. Losing even one
+//! server's share leaves the sum uniformly random, so no coalition smaller
+//! than all `n` servers learns anything about an individual contribution.
+//! This is `n`-out-of-`n` additive sharing, unlike the `(t, n)` threshold
+//! scheme in `ckks_lib::polynomial`'s `split_secret`/`reconstruct`.
+
+use bincode::{Decode, Encode};
+use fhe_core::rand::distributions::{Distribution, Uniform};
+
+/// One client's additive share of a single contribution, destined for one
+/// non-colluding server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct AggregateShare(i64);
+
+impl AggregateShare {
+    #[must_use]
+    #[inline]
+    /// The raw share value, in `[0, modulus)`.
+    pub const fn value(&self) -> i64 {
+        self.0
+    }
+}
+
+/// A submitted contribution exceeded the aggregation's declared bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds;
+
+/// Splits `value` into `n_servers` additive shares over `Z_modulus`.
+///
+/// Every share but the last is drawn uniformly from `[0, modulus)`; the last
+/// is fixed so all the shares sum to `value mod modulus`. Rejects `value`
+/// before sampling if it exceeds `bound`, so an invalid contribution never
+/// enters any server's running sum.
+///
+/// # Errors
+///
+/// Returns [`OutOfBounds`] if `value > bound`.
+///
+/// # Panics
+///
+/// Panics if `n_servers < 2`, or if randomness fails.
+pub fn split_value(
+    value: u64,
+    bound: u64,
+    modulus: i64,
+    n_servers: usize,
+) -> Result<Vec<AggregateShare>, OutOfBounds> {
+    if value > bound {
+        return Err(OutOfBounds);
+    }
+    assert!(n_servers >= 2, "need at least two non-colluding servers");
+
+    #[allow(clippy::range_minus_one)]
+    let u = Uniform::<i64>::new(0..=modulus - 1);
+    let mut remaining = i64::try_from(value).unwrap().rem_euclid(modulus);
+    let mut shares = Vec::with_capacity(n_servers);
+    for _ in 0..n_servers - 1 {
+        let s = u.sample().expect("failed to sample masking share");
+        remaining = (remaining - s).rem_euclid(modulus);
+        shares.push(AggregateShare(s));
+    }
+    shares.push(AggregateShare(remaining));
+    Ok(shares)
+}
+
+/// Combines every non-colluding server's partial total into the aggregate.
+///
+/// `totals` must hold exactly one entry per server, in the order their shares
+/// were handed out by [`split_value`]; the aggregate is only meaningful when
+/// every server's total is present.
+#[must_use]
+pub fn combine(totals: &[i64], modulus: i64) -> i64 {
+    totals
+        .iter()
+        .fold(0_i64, |acc, &t| (acc + t).rem_euclid(modulus))
+}
+
+/// The shares one non-colluding server holds for a single aggregation round,
+/// one per client that contributed.
+#[derive(Debug, Clone)]
+pub struct AggregateOpsData {
+    modulus: i64,
+    shares: Vec<AggregateShare>,
+}
+
+impl AggregateOpsData {
+    #[must_use]
+    #[inline]
+    /// Creates an empty round for the given modulus.
+    pub const fn new(modulus: i64) -> Self {
+        Self {
+            modulus,
+            shares: Vec::new(),
+        }
+    }
+
+    #[inline]
+    /// Records one more client's share for this server.
+    pub fn push(&mut self, share: AggregateShare) {
+        self.shares.push(share);
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn modulus(&self) -> i64 {
+        self.modulus
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the number of shares held for this round.
+    pub fn len(&self) -> usize {
+        self.shares.len()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns `true` if no client has contributed a share yet.
+    pub fn is_empty(&self) -> bool {
+        self.shares.is_empty()
+    }
+
+    #[inline]
+    /// Iterate over the held shares.
+    pub fn iter_over_data(&self) -> impl Iterator<Item = &AggregateShare> {
+        self.shares.iter()
+    }
+
+    #[must_use]
+    /// This server's partial total: the sum of every client's share it holds.
+    ///
+    /// Combine every server's partial total with [`combine`] to recover the
+    /// aggregate over all contributions.
+    pub fn aggregate(&self) -> i64 {
+        self.shares
+            .iter()
+            .fold(0_i64, |acc, s| (acc + s.value()).rem_euclid(self.modulus))
+    }
+}
+
+impl Encode for AggregateOpsData {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.modulus.encode(encoder)?;
+        self.shares.encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for AggregateOpsData {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        Ok(Self {
+            modulus: Decode::decode(decoder)?,
+            shares: Decode::decode(decoder)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULUS: i64 = 100_000_007;
+
+    #[test]
+    fn test_split_value_rejects_out_of_bounds() {
+        assert_eq!(split_value(11, 10, MODULUS, 3), Err(OutOfBounds));
+    }
+
+    #[test]
+    fn test_split_and_combine_recovers_single_contribution() {
+        let shares = split_value(42, 1000, MODULUS, 3).unwrap();
+        assert_eq!(shares.len(), 3);
+
+        let mut servers = vec![AggregateOpsData::new(MODULUS); 3];
+        for (server, share) in servers.iter_mut().zip(shares) {
+            server.push(share);
+        }
+
+        let totals: Vec<i64> = servers.iter().map(AggregateOpsData::aggregate).collect();
+        assert_eq!(combine(&totals, MODULUS), 42);
+    }
+
+    #[test]
+    fn test_combine_sums_multiple_clients() {
+        let values = [7u64, 13, 25];
+        let mut servers = vec![AggregateOpsData::new(MODULUS); 3];
+        for &value in &values {
+            let shares = split_value(value, 1000, MODULUS, 3).unwrap();
+            for (server, share) in servers.iter_mut().zip(shares) {
+                server.push(share);
+            }
+        }
+
+        let totals: Vec<i64> = servers.iter().map(AggregateOpsData::aggregate).collect();
+        let expected: u64 = values.iter().sum();
+        assert_eq!(combine(&totals, MODULUS), expected as i64);
+    }
+}
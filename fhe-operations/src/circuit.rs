@@ -0,0 +1,311 @@
+//! Dependency-aware circuit of homomorphic operations.
+//!
+//! [`SeqOpItem`](crate::seq_ops::SeqOpItem) models a single binary operation on
+//! two fresh ciphertexts, so a client cannot express a chained computation like
+//! `(a + b) * c` without round-tripping through the client for each step.
+//! [`CircuitData`] instead describes a whole arithmetic circuit as a list of
+//! nodes: each node is either an input ciphertext or an operation referencing
+//! the indices of earlier nodes. The server evaluates the nodes in index order
+//! and returns only the nodes the client marked as outputs, so a single
+//! exchange offloads the entire circuit.
+
+use bincode::error::DecodeError;
+use bincode::{Decode, Encode};
+use fhe_core::api::CryptoSystem;
+
+/// A single node of a [`CircuitData`] DAG.
+///
+/// Operation nodes reference earlier nodes by their position in the circuit.
+/// [`CircuitData`]'s [`Decode`] implementation rejects any reference that is
+/// not strictly smaller than the node's own index; the `push_*` builders do
+/// not check it.
+pub enum CircuitNode<C: CryptoSystem>
+where
+    C::Ciphertext: Encode,
+    C::Operation1: Encode,
+    C::Operation2: Encode,
+{
+    /// A fresh input ciphertext supplied by the client.
+    Input(C::Ciphertext),
+    /// A unary operation applied to an earlier node.
+    Operation1 { op: C::Operation1, input: usize },
+    /// A binary operation applied to two earlier nodes.
+    Operation2 {
+        op: C::Operation2,
+        lhs: usize,
+        rhs: usize,
+    },
+    /// Relinearization of an earlier node.
+    Relinearize { input: usize },
+}
+
+/// Discriminants for the [`CircuitNode`] wire format.
+const TAG_INPUT: u8 = 0;
+const TAG_OP1: u8 = 1;
+const TAG_OP2: u8 = 2;
+const TAG_RELIN: u8 = 3;
+
+/// A circuit of homomorphic operations, ready to exchange with the server.
+#[derive(Default)]
+pub struct CircuitData<C: CryptoSystem>
+where
+    C::Ciphertext: Encode,
+    C::Operation1: Encode,
+    C::Operation2: Encode,
+{
+    nodes: Vec<CircuitNode<C>>,
+    outputs: Vec<usize>,
+}
+
+impl<C: CryptoSystem> CircuitData<C>
+where
+    C::Ciphertext: Encode,
+    C::Operation1: Encode,
+    C::Operation2: Encode,
+{
+    #[must_use]
+    #[inline]
+    /// Creates an empty circuit.
+    pub const fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Appends an input ciphertext, returning its node index.
+    #[inline]
+    pub fn push_input(&mut self, ciphertext: C::Ciphertext) -> usize {
+        self.push(CircuitNode::Input(ciphertext))
+    }
+
+    /// Appends a unary operation on node `input`, returning its node index.
+    #[inline]
+    pub fn push_operation1(&mut self, op: C::Operation1, input: usize) -> usize {
+        self.push(CircuitNode::Operation1 { op, input })
+    }
+
+    /// Appends a binary operation on nodes `lhs` and `rhs`, returning its index.
+    #[inline]
+    pub fn push_operation2(&mut self, op: C::Operation2, lhs: usize, rhs: usize) -> usize {
+        self.push(CircuitNode::Operation2 { op, lhs, rhs })
+    }
+
+    /// Appends a relinearization of node `input`, returning its node index.
+    #[inline]
+    pub fn push_relinearize(&mut self, input: usize) -> usize {
+        self.push(CircuitNode::Relinearize { input })
+    }
+
+    /// Marks node `index` as one of the circuit's outputs.
+    #[inline]
+    pub fn mark_output(&mut self, index: usize) {
+        self.outputs.push(index);
+    }
+
+    #[inline]
+    fn push(&mut self, node: CircuitNode<C>) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(node);
+        index
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns the number of nodes in the circuit.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns `true` if the circuit has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    #[must_use]
+    /// Evaluates the circuit and returns the ciphertexts of the output nodes,
+    /// in the order they were marked.
+    ///
+    /// Nodes are evaluated in index order into a results buffer, so an operand
+    /// on an earlier node is already computed by the time a node consumes it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a node references itself or a later node, which a circuit
+    /// built with the `push_*` methods does not rule out.
+    pub fn execute(&self, cs: &C) -> Vec<C::Ciphertext>
+    where
+        C::Ciphertext: Clone,
+        C::Operation1: Copy,
+        C::Operation2: Copy,
+    {
+        let mut results: Vec<C::Ciphertext> = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let value = match node {
+                CircuitNode::Input(ciphertext) => ciphertext.clone(),
+                CircuitNode::Operation1 { op, input } => cs.operate1(*op, &results[*input]),
+                CircuitNode::Operation2 { op, lhs, rhs } => {
+                    cs.operate2(*op, &results[*lhs], &results[*rhs])
+                }
+                CircuitNode::Relinearize { input } => {
+                    let mut c = results[*input].clone();
+                    cs.relinearize(&mut c);
+                    c
+                }
+            };
+            results.push(value);
+        }
+        self.outputs.iter().map(|&i| results[i].clone()).collect()
+    }
+}
+
+impl<C: CryptoSystem> Encode for CircuitData<C>
+where
+    C::Ciphertext: Encode,
+    C::Operation1: Encode,
+    C::Operation2: Encode,
+{
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        (self.nodes.len() as u64).encode(encoder)?;
+        for node in &self.nodes {
+            match node {
+                CircuitNode::Input(ciphertext) => {
+                    TAG_INPUT.encode(encoder)?;
+                    ciphertext.encode(encoder)?;
+                }
+                CircuitNode::Operation1 { op, input } => {
+                    TAG_OP1.encode(encoder)?;
+                    op.encode(encoder)?;
+                    (*input as u64).encode(encoder)?;
+                }
+                CircuitNode::Operation2 { op, lhs, rhs } => {
+                    TAG_OP2.encode(encoder)?;
+                    op.encode(encoder)?;
+                    (*lhs as u64).encode(encoder)?;
+                    (*rhs as u64).encode(encoder)?;
+                }
+                CircuitNode::Relinearize { input } => {
+                    TAG_RELIN.encode(encoder)?;
+                    (*input as u64).encode(encoder)?;
+                }
+            }
+        }
+        self.outputs.encode(encoder)
+    }
+}
+
+impl<C: CryptoSystem, Context> Decode<Context> for CircuitData<C>
+where
+    C::Ciphertext: Decode<Context> + Encode,
+    C::Operation1: Decode<Context> + Encode,
+    C::Operation2: Decode<Context> + Encode,
+{
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let count = u64::decode(decoder)? as usize;
+        let mut nodes = Vec::with_capacity(count);
+
+        for index in 0..count {
+            // Every referenced node must already exist, i.e. sit strictly
+            // before the node that consumes it: this rejects forward or cyclic
+            // references before any evaluation happens.
+            let check = |referenced: usize| -> Result<usize, DecodeError> {
+                if referenced < index {
+                    Ok(referenced)
+                } else {
+                    Err(DecodeError::OtherString(alloc_msg(index, referenced)))
+                }
+            };
+
+            let tag = u8::decode(decoder)?;
+            let node = match tag {
+                TAG_INPUT => CircuitNode::Input(C::Ciphertext::decode(decoder)?),
+                TAG_OP1 => {
+                    let op = C::Operation1::decode(decoder)?;
+                    let input = check(u64::decode(decoder)? as usize)?;
+                    CircuitNode::Operation1 { op, input }
+                }
+                TAG_OP2 => {
+                    let op = C::Operation2::decode(decoder)?;
+                    let lhs = check(u64::decode(decoder)? as usize)?;
+                    let rhs = check(u64::decode(decoder)? as usize)?;
+                    CircuitNode::Operation2 { op, lhs, rhs }
+                }
+                TAG_RELIN => {
+                    let input = check(u64::decode(decoder)? as usize)?;
+                    CircuitNode::Relinearize { input }
+                }
+                other => {
+                    return Err(DecodeError::OtherString(format!(
+                        "unknown circuit node tag {other}"
+                    )));
+                }
+            };
+            nodes.push(node);
+        }
+
+        let outputs: Vec<usize> = Vec::decode(decoder)?;
+        for &out in &outputs {
+            if out >= nodes.len() {
+                return Err(DecodeError::OtherString(alloc_msg(nodes.len(), out)));
+            }
+        }
+
+        Ok(Self { nodes, outputs })
+    }
+}
+
+/// Builds the "index out of range" decode error message.
+fn alloc_msg(index: usize, referenced: usize) -> String {
+    format!("circuit node {index} references out-of-range node {referenced}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bincode::config::Configuration;
+    use seal_lib::{
+        BfvHOperation2, DegreeType, SealBfvCS, SecurityLevel, context::SealBFVContext,
+    };
+
+    const CONFIG: Configuration = bincode::config::standard();
+
+    #[test]
+    fn evaluates_chained_circuit() {
+        let context = SealBFVContext::new(DegreeType::D4096, SecurityLevel::TC128, 20);
+        let cs = SealBfvCS::new(&context);
+
+        // (a + b) * c with a = 2, b = 3, c = 4 → 20.
+        let mut circuit = CircuitData::<SealBfvCS>::new();
+        let a = circuit.push_input(cs.cipher(&2));
+        let b = circuit.push_input(cs.cipher(&3));
+        let c = circuit.push_input(cs.cipher(&4));
+        let sum = circuit.push_operation2(BfvHOperation2::Add, a, b);
+        let prod = circuit.push_operation2(BfvHOperation2::Mul, sum, c);
+        circuit.mark_output(prod);
+
+        let results = circuit.execute(&cs);
+        assert_eq!(results.len(), 1);
+        assert_eq!(cs.decipher(&results[0]), 20);
+    }
+
+    #[test]
+    fn decode_rejects_non_backward_reference() {
+        let context = SealBFVContext::new(DegreeType::D4096, SecurityLevel::TC128, 20);
+
+        // Node 0 relinearizes node 0 — a self-reference, not strictly earlier.
+        let mut circuit = CircuitData::<SealBfvCS>::new();
+        let _ = circuit.push_relinearize(0);
+
+        let bytes = bincode::encode_to_vec(circuit, CONFIG).unwrap();
+        let decoded: Result<(CircuitData<SealBfvCS>, usize), _> =
+            bincode::decode_from_slice_with_context(&bytes, CONFIG, context);
+        assert!(decoded.is_err());
+    }
+}
@@ -2,6 +2,8 @@
 #![warn(clippy::nursery, clippy::pedantic)]
 #![forbid(unsafe_code)]
 
+pub mod aggregate_ops;
+pub mod circuit;
 pub mod selectable_collection;
 pub mod seq_ops;
 pub mod sign;
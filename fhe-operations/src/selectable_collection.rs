@@ -1,7 +1,7 @@
 //! SQL-like operations on encrypted data.
 
 use bincode::{Decode, Encode};
-use fhe_core::api::CryptoSystem;
+use fhe_core::api::{CryptoSystem, SerFormat};
 
 /// A `CryptoSystem` that can be used to perform selection operations.
 pub trait SelectableCS: CryptoSystem {
@@ -14,6 +14,15 @@ pub trait SelectableCS: CryptoSystem {
     const NEUTRAL_ADD: Self::Plaintext;
     /// The plaintext that is neutral with respect to multiplication.
     const NEUTRAL_MUL: Self::Plaintext;
+
+    /// Scales `ct` by the plaintext rational `1/n`.
+    ///
+    /// This is the plaintext-scalar multiplication that turns an encrypted sum
+    /// into an encrypted mean, mirroring the `homomorphic_div_plain` step of the
+    /// CKKS averaging example. Integer schemes realize it as a plaintext
+    /// division, approximate schemes as a multiplication by `1/n`.
+    #[must_use]
+    fn scale_reciprocal(&self, ct: &Self::Ciphertext, n: usize) -> Self::Ciphertext;
 }
 
 /// A flag that can be used to select items.
@@ -92,6 +101,102 @@ impl<const F: usize, C: SelectableCS> SelectableItem<F, C> {
     pub fn set_flag_plain(&mut self, index: usize, flag: Flag, cs: &C) {
         self.flags[index] = cs.cipher(&flag_to_plaintext::<C>(flag));
     }
+
+    /// Stores a caller-provided encrypted `0`/`1` flag at `index`.
+    ///
+    /// Unlike [`set_flag_plain`](Self::set_flag_plain), the flag is taken from an
+    /// already-encrypted predicate — typically the output of an equality or range
+    /// test — so a WHERE clause evaluated over encrypted data can drive the
+    /// MUL-mask selection used by [`operate_many_where_flag`] and friends.
+    #[inline]
+    pub fn set_flag_where(&mut self, index: usize, predicate_ct: &C::Ciphertext, cs: &C)
+    where
+        C::Ciphertext: Clone,
+    {
+        // `cs` is accepted for symmetry with `set_flag_plain`; the predicate is
+        // already encrypted under it, so no fresh encryption is needed.
+        let _ = cs;
+        self.flags[index] = predicate_ct.clone();
+    }
+}
+
+impl<const F: usize, C: CryptoSystem> SelectableItem<F, C> {
+    #[must_use]
+    /// Serializes this item's ciphertext and flags via `cs`'s chosen `format`.
+    ///
+    /// Pairs with [`from_bytes`](Self::from_bytes); this sits alongside the
+    /// existing `Encode`/`Decode` impls above, which always go through
+    /// bincode — this instead routes each ciphertext through
+    /// [`CryptoSystem::serialize_ciphertext`], so `format` can select a
+    /// portable JSON envelope instead of the compact binary one.
+    pub fn to_bytes(&self, cs: &C, format: SerFormat) -> Vec<u8> {
+        let ciphertext = cs.serialize_ciphertext(&self.ciphertext, format);
+        let flags: Vec<Vec<u8>> = self
+            .flags
+            .iter()
+            .map(|flag| cs.serialize_ciphertext(flag, format))
+            .collect();
+        bincode::encode_to_vec((ciphertext, flags), bincode::config::standard())
+            .expect("encoding a pair of byte vectors cannot fail")
+    }
+
+    /// Reconstructs an item previously produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the outer framing is malformed, the flag count
+    /// does not match `F`, or a ciphertext fails to deserialize.
+    pub fn from_bytes(
+        bytes: &[u8],
+        cs: &C,
+        format: SerFormat,
+    ) -> Result<Self, SelectableSerError<C::SerError>> {
+        let ((ciphertext_bytes, flag_bytes), _): ((Vec<u8>, Vec<Vec<u8>>), usize) =
+            bincode::decode_from_slice(bytes, bincode::config::standard())
+                .map_err(|_| SelectableSerError::Framing)?;
+
+        if flag_bytes.len() != F {
+            return Err(SelectableSerError::FlagCount {
+                expected: F,
+                found: flag_bytes.len(),
+            });
+        }
+
+        let ciphertext = cs
+            .deserialize_ciphertext(&ciphertext_bytes, format)
+            .map_err(SelectableSerError::Ciphertext)?;
+        let mut flags = Vec::with_capacity(F);
+        for bytes in flag_bytes {
+            flags.push(
+                cs.deserialize_ciphertext(&bytes, format)
+                    .map_err(SelectableSerError::Ciphertext)?,
+            );
+        }
+        let flags: [C::Ciphertext; F] = flags
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("length was just checked against F"));
+
+        Ok(Self { ciphertext, flags })
+    }
+}
+
+/// Error produced by [`SelectableItem::from_bytes`] and
+/// [`SelectableCollection::from_bytes`].
+#[derive(Debug)]
+pub enum SelectableSerError<E> {
+    /// The outer bincode framing around the serialized ciphertexts was
+    /// malformed.
+    Framing,
+    /// The array of flag ciphertexts did not have the expected length `F`.
+    FlagCount {
+        /// The number of flags the item type requires.
+        expected: usize,
+        /// The number of flags actually found in the serialized bytes.
+        found: usize,
+    },
+    /// A ciphertext or flag failed to deserialize under the backend's own
+    /// format.
+    Ciphertext(E),
 }
 
 /// A collection of `SelectableItem`s.
@@ -163,10 +268,18 @@ impl<const F: usize, C: SelectableCS<Ciphertext: Clone>> SelectableCollection<F,
 
     #[must_use]
     /// Operates on all items in the collection.
+    ///
+    /// When `op` is the system's multiplicative operation the accumulation uses
+    /// the balanced pairwise tree of [`operate_many_balanced`], so
+    /// multiplicative depth stays logarithmic in the collection size; for any
+    /// other (additive) operation the cheaper linear fold is kept.
     pub fn operate_many(&self, op: C::Operation2, cs: &C) -> C::Ciphertext
     where
-        C::Operation2: Copy,
+        C::Operation2: Copy + PartialEq,
     {
+        if op == C::MUL_OPP {
+            return self.operate_many_balanced(op, cs);
+        }
         let mut sum: C::Ciphertext = self.items[0].ciphertext.clone();
         for i in 1..self.items.len() {
             sum = cs.operate2(op, &sum, &self.items[i].ciphertext).clone();
@@ -174,6 +287,30 @@ impl<const F: usize, C: SelectableCS<Ciphertext: Clone>> SelectableCollection<F,
         sum
     }
 
+    #[must_use]
+    /// Operates on all items using a balanced pairwise-reduction tree.
+    ///
+    /// Adjacent ciphertexts are combined round by round, so the accumulation
+    /// tree has depth `⌈log2 n⌉` instead of `n`. Under a multiplicative `op`
+    /// this keeps CKKS noise and level consumption logarithmic, letting deeper
+    /// collections stay decryptable where the linear fold would exhaust the
+    /// modulus chain.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the collection is empty.
+    pub fn operate_many_balanced(&self, op: C::Operation2, cs: &C) -> C::Ciphertext
+    where
+        C::Operation2: Copy,
+    {
+        assert!(!self.items.is_empty());
+
+        let mut layer: Vec<C::Ciphertext> =
+            self.items.iter().map(|it| it.ciphertext.clone()).collect();
+        reduce_tree(&mut layer, op, cs);
+        layer.pop().unwrap()
+    }
+
     #[must_use]
     /// Operates on all items in the collection where the flag at the given index is set to `Flag::On`.
     ///
@@ -197,6 +334,169 @@ impl<const F: usize, C: SelectableCS<Ciphertext: Clone>> SelectableCollection<F,
         }
         sum
     }
+
+    #[must_use]
+    /// Balanced counterpart of [`operate_many_where_flag`].
+    ///
+    /// Each item is first masked by its flag with a single multiplication, then
+    /// the masked ciphertexts are summed through a balanced addition tree of
+    /// depth `⌈log2 n⌉`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the collection is empty.
+    pub fn operate_many_where_flag_balanced(&self, flag_index: usize, cs: &C) -> C::Ciphertext
+    where
+        C::Operation2: Copy,
+    {
+        assert!(!self.items.is_empty());
+
+        let mut layer: Vec<C::Ciphertext> = self
+            .items
+            .iter()
+            .map(|item| {
+                let flag = item.get_flag(flag_index).unwrap();
+                cs.operate2(C::MUL_OPP, &item.ciphertext, flag)
+            })
+            .collect();
+        reduce_tree(&mut layer, C::ADD_OPP, cs);
+        layer.pop().unwrap()
+    }
+
+    #[must_use]
+    /// Encrypted arithmetic mean of every item in the collection.
+    ///
+    /// Sums the items with [`operate_many`](Self::operate_many) under the
+    /// additive operation and scales the result by the plaintext reciprocal
+    /// `1/len` through [`SelectableCS::scale_reciprocal`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the collection is empty.
+    pub fn mean(&self, cs: &C) -> C::Ciphertext
+    where
+        C::Operation2: Copy + PartialEq,
+    {
+        assert!(!self.items.is_empty());
+        let sum = self.operate_many(C::ADD_OPP, cs);
+        cs.scale_reciprocal(&sum, self.items.len())
+    }
+
+    #[must_use]
+    /// Encrypted count of the items whose flag at `flag_index` is set.
+    ///
+    /// Homomorphically adds the encrypted flags, yielding an encrypted `COUNT`
+    /// without revealing which rows matched.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the collection is empty.
+    pub fn count_where_flag(&self, flag_index: usize, cs: &C) -> C::Ciphertext
+    where
+        C::Operation2: Copy,
+    {
+        assert!(!self.items.is_empty());
+
+        let mut sum: C::Ciphertext = self.items[0].get_flag(flag_index).unwrap().clone();
+        for item in self.items.iter().skip(1) {
+            let flag = item.get_flag(flag_index).unwrap();
+            sum = cs.operate2(C::ADD_OPP, &sum, flag);
+        }
+        sum
+    }
+
+    #[must_use]
+    /// Masks every item by its flag at `flag_index` and reduces the masked
+    /// ciphertexts with the caller-supplied `combiner`.
+    ///
+    /// This generalizes [`operate_many_where_flag`](Self::operate_many_where_flag),
+    /// which hardcodes the additive reduction, to any combiner (for instance a
+    /// multiplicative product over the selected rows).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the collection is empty.
+    pub fn filter_combine(
+        &self,
+        flag_index: usize,
+        combiner: C::Operation2,
+        cs: &C,
+    ) -> C::Ciphertext
+    where
+        C::Operation2: Copy,
+    {
+        assert!(!self.items.is_empty());
+
+        let first_item = &self.items[0];
+        let first_flag = first_item.get_flag(flag_index).unwrap();
+        let mut acc: C::Ciphertext = cs.operate2(C::MUL_OPP, &first_item.ciphertext, first_flag);
+
+        for item in self.items.iter().skip(1) {
+            let flag = item.get_flag(flag_index).unwrap();
+            let masked = cs.operate2(C::MUL_OPP, &item.ciphertext, flag);
+            acc = cs.operate2(combiner, &acc, &masked);
+        }
+        acc
+    }
+
+    #[must_use]
+    /// Serializes every item in the collection via `cs`'s chosen `format`.
+    ///
+    /// Mirrors [`SelectableItem::to_bytes`] at the collection level, so a
+    /// whole collection can be written to disk or sent to a peer that
+    /// reloads it with [`from_bytes`](Self::from_bytes).
+    pub fn to_bytes(&self, cs: &C, format: SerFormat) -> Vec<u8> {
+        let items: Vec<Vec<u8>> = self.items.iter().map(|item| item.to_bytes(cs, format)).collect();
+        bincode::encode_to_vec(items, bincode::config::standard())
+            .expect("encoding a vector of byte vectors cannot fail")
+    }
+
+    /// Reconstructs a collection previously produced by
+    /// [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the outer framing or any item fails to
+    /// deserialize; see [`SelectableSerError`].
+    pub fn from_bytes(
+        bytes: &[u8],
+        cs: &C,
+        format: SerFormat,
+    ) -> Result<Self, SelectableSerError<C::SerError>> {
+        let (item_bytes, _): (Vec<Vec<u8>>, usize) =
+            bincode::decode_from_slice(bytes, bincode::config::standard())
+                .map_err(|_| SelectableSerError::Framing)?;
+
+        let items = item_bytes
+            .into_iter()
+            .map(|bytes| SelectableItem::from_bytes(&bytes, cs, format))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { items })
+    }
+}
+
+/// Reduces `layer` in place to a single ciphertext using a balanced pairwise
+/// tree: each round combines adjacent pairs with `op`, carrying any odd
+/// element up unchanged.
+fn reduce_tree<C: SelectableCS<Ciphertext: Clone>>(
+    layer: &mut Vec<C::Ciphertext>,
+    op: C::Operation2,
+    cs: &C,
+) where
+    C::Operation2: Copy,
+{
+    while layer.len() > 1 {
+        let mut next = Vec::with_capacity(layer.len().div_ceil(2));
+        let mut chunks = layer.chunks_exact(2);
+        for pair in &mut chunks {
+            next.push(cs.operate2(op, &pair[0], &pair[1]));
+        }
+        if let [last] = chunks.remainder() {
+            next.push(last.clone());
+        }
+        *layer = next;
+    }
 }
 
 #[cfg(test)]
@@ -215,7 +515,7 @@ mod tests {
 
     struct TestCryptoSystem {}
 
-    #[derive(Clone, Copy, Debug)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     enum Op {
         Add,
         Mul,
@@ -265,6 +565,39 @@ mod tests {
         }
 
         fn relinearize(&self, _ciphertext: &mut Self::Ciphertext) {}
+
+        fn level(&self, _ciphertext: &Self::Ciphertext) -> u32 {
+            0
+        }
+
+        fn rescale(&self, _ciphertext: &mut Self::Ciphertext) {}
+
+        fn mod_switch_to(&self, _ciphertext: &mut Self::Ciphertext, _level: u32) {}
+
+        type SerError = ();
+
+        fn serialize_ciphertext(&self, ciphertext: &Self::Ciphertext, _format: SerFormat) -> Vec<u8> {
+            ciphertext.data.0.to_le_bytes().to_vec()
+        }
+
+        fn deserialize_ciphertext(
+            &self,
+            bytes: &[u8],
+            _format: SerFormat,
+        ) -> Result<Self::Ciphertext, Self::SerError> {
+            let raw: [u8; 8] = bytes.try_into().map_err(|_| ())?;
+            Ok(TestCiphertext {
+                data: TestPlaintext(u64::from_le_bytes(raw)),
+            })
+        }
+
+        fn serialize_public_key(&self, _format: SerFormat) -> Vec<u8> {
+            Vec::new()
+        }
+
+        fn serialize_relin_key(&self, _format: SerFormat) -> Option<Vec<u8>> {
+            None
+        }
     }
     impl SelectableCS for TestCryptoSystem {
         const ADD_OPP: Self::Operation2 = Op::Add;
@@ -272,6 +605,12 @@ mod tests {
 
         const NEUTRAL_ADD: Self::Plaintext = TestPlaintext(0);
         const NEUTRAL_MUL: Self::Plaintext = TestPlaintext(1);
+
+        fn scale_reciprocal(&self, ct: &Self::Ciphertext, n: usize) -> Self::Ciphertext {
+            TestCiphertext {
+                data: TestPlaintext(ct.data.0 / n as u64),
+            }
+        }
     }
 
     #[test]
@@ -333,6 +672,49 @@ mod tests {
         assert_eq!(decrypted.0, 3);
     }
 
+    #[test]
+    fn test_operate_many_balanced() {
+        let cs = TestCryptoSystem {};
+        let mut collection = SelectableCollection::<F, _>::new();
+        for v in 1..=5 {
+            collection.push(SelectableItem::new(&TestPlaintext(v), &cs));
+        }
+        // Balanced product matches the plain product over the (odd-sized) set.
+        let prod = collection.operate_many_balanced(Op::Mul, &cs);
+        assert_eq!(cs.decipher(&prod).0, 120);
+        // The automatic strategy picks the balanced tree for the multiplicative
+        // operation and agrees with the explicit call.
+        let auto = collection.operate_many(Op::Mul, &cs);
+        assert_eq!(cs.decipher(&auto), cs.decipher(&prod));
+    }
+
+    #[test]
+    fn test_operate_many_balanced_matches_linear_add() {
+        let cs = TestCryptoSystem {};
+        let mut collection = SelectableCollection::<F, _>::new();
+        for v in 1..=7 {
+            collection.push(SelectableItem::new(&TestPlaintext(v), &cs));
+        }
+        let linear = collection.operate_many(Op::Add, &cs);
+        let balanced = collection.operate_many_balanced(Op::Add, &cs);
+        assert_eq!(cs.decipher(&linear), cs.decipher(&balanced));
+    }
+
+    #[test]
+    fn test_operate_many_where_flag_balanced() {
+        let cs = TestCryptoSystem {};
+        let mut collection = SelectableCollection::<F, _>::new();
+
+        let item = SelectableItem::new(&TestPlaintext(1), &cs);
+        collection.push(item);
+        collection.items[0].set_flag_plain(0, Flag::On, &cs);
+        let item = SelectableItem::new(&TestPlaintext(2), &cs);
+        collection.push(item);
+
+        let sum = collection.operate_many_where_flag_balanced(0, &cs);
+        assert_eq!(cs.decipher(&sum), TestPlaintext(1));
+    }
+
     #[test]
     fn test_get_flag_plain() {
         let cs = TestCryptoSystem {};
@@ -366,4 +748,116 @@ mod tests {
 
         assert_eq!(decrypted, expected);
     }
+
+    #[test]
+    fn test_mean() {
+        let cs = TestCryptoSystem {};
+        let mut collection = SelectableCollection::<F, _>::new();
+        for v in [2u64, 4, 6] {
+            collection.push(SelectableItem::new(&TestPlaintext(v), &cs));
+        }
+        let mean = collection.mean(&cs);
+        assert_eq!(cs.decipher(&mean), TestPlaintext(4));
+    }
+
+    #[test]
+    fn test_mean_of_flagged_subset() {
+        let cs = TestCryptoSystem {};
+        let mut collection = SelectableCollection::<F, _>::new();
+        for v in [2u64, 4, 6] {
+            collection.push(SelectableItem::new(&TestPlaintext(v), &cs));
+        }
+        // Select the first and last rows only.
+        collection.items[0].set_flag_plain(0, Flag::On, &cs);
+        collection.items[2].set_flag_plain(0, Flag::On, &cs);
+
+        let sum = collection.operate_many_where_flag(0, &cs);
+        let mean = cs.scale_reciprocal(&sum, 2);
+        assert_eq!(cs.decipher(&mean), TestPlaintext(4));
+    }
+
+    #[test]
+    fn test_count_where_flag() {
+        let cs = TestCryptoSystem {};
+        let mut collection = SelectableCollection::<F, _>::new();
+        for v in [1u64, 2, 3, 4] {
+            collection.push(SelectableItem::new(&TestPlaintext(v), &cs));
+        }
+        collection.items[0].set_flag_plain(0, Flag::On, &cs);
+        collection.items[2].set_flag_plain(0, Flag::On, &cs);
+
+        let count = collection.count_where_flag(0, &cs);
+        assert_eq!(cs.decipher(&count), TestPlaintext(2));
+    }
+
+    #[test]
+    fn test_set_flag_where_drives_selection() {
+        let cs = TestCryptoSystem {};
+        let mut collection = SelectableCollection::<F, _>::new();
+        collection.push(SelectableItem::new(&TestPlaintext(5), &cs));
+        collection.push(SelectableItem::new(&TestPlaintext(7), &cs));
+
+        // An encrypted predicate (here a plain 1) selects the first row.
+        let predicate = cs.cipher(&TestPlaintext(1));
+        collection.items[0].set_flag_where(0, &predicate, &cs);
+
+        let sum = collection.operate_many_where_flag(0, &cs);
+        assert_eq!(cs.decipher(&sum), TestPlaintext(5));
+    }
+
+    #[test]
+    fn test_item_round_trips_through_bytes() {
+        let cs = TestCryptoSystem {};
+        let mut item = SelectableItem::<F, TestCryptoSystem>::new(&TestPlaintext(5), &cs);
+        item.set_flag_plain(0, Flag::On, &cs);
+
+        let bytes = item.to_bytes(&cs, SerFormat::Binary);
+        let restored = SelectableItem::<F, TestCryptoSystem>::from_bytes(&bytes, &cs, SerFormat::Binary)
+            .unwrap();
+
+        assert_eq!(cs.decipher(&restored.ciphertext), TestPlaintext(5));
+        assert_eq!(restored.get_flag_plain(0, &cs), TestPlaintext(1));
+    }
+
+    #[test]
+    fn test_collection_round_trips_through_bytes() {
+        let cs = TestCryptoSystem {};
+        let mut collection = SelectableCollection::<F, _>::new();
+        for v in [2u64, 4, 6] {
+            collection.push(SelectableItem::new(&TestPlaintext(v), &cs));
+        }
+
+        let bytes = collection.to_bytes(&cs, SerFormat::Json);
+        let restored =
+            SelectableCollection::<F, TestCryptoSystem>::from_bytes(&bytes, &cs, SerFormat::Json)
+                .unwrap();
+
+        assert_eq!(restored.len(), collection.len());
+        assert_eq!(cs.decipher(&restored.mean(&cs)), TestPlaintext(4));
+    }
+
+    #[test]
+    fn test_collection_from_bytes_rejects_bad_framing() {
+        let cs = TestCryptoSystem {};
+        let err =
+            SelectableCollection::<F, TestCryptoSystem>::from_bytes(&[0xFF; 4], &cs, SerFormat::Binary)
+                .unwrap_err();
+        assert!(matches!(err, SelectableSerError::Framing));
+    }
+
+    #[test]
+    fn test_filter_combine_product() {
+        let cs = TestCryptoSystem {};
+        let mut collection = SelectableCollection::<F, _>::new();
+        for v in [2u64, 3, 4] {
+            collection.push(SelectableItem::new(&TestPlaintext(v), &cs));
+        }
+        collection.items[0].set_flag_plain(0, Flag::On, &cs);
+        collection.items[2].set_flag_plain(0, Flag::On, &cs);
+
+        // Masked product: 2 * 0 * 4 = 0, since the unset row contributes a zero
+        // factor under the multiplicative combiner.
+        let combined = collection.filter_combine(0, Op::Mul, &cs);
+        assert_eq!(cs.decipher(&combined), TestPlaintext(0));
+    }
 }
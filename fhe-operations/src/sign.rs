@@ -10,7 +10,86 @@ pub fn sign<C: CryptoSystem<Plaintext = f64>>(
 where
     C::Operation2: Copy,
 {
-    sign_pbas(x, cs, add_op, mul_op)
+    const N: usize = 3;
+    const COEFFS: [f64; N] = pbas_coefficients();
+    eval_poly(cs, &COEFFS, x, add_op, mul_op)
+}
+
+/// Evaluate `P(x) = sum_i coeffs[i] * x^i` homomorphically with the
+/// Paterson–Stockmeyer (baby-step/giant-step) method.
+///
+/// With `k = ceil(sqrt(deg))` we precompute the baby-step powers `x^0..x^k`,
+/// each `x^r` as `x^{floor(r/2)} * x^{ceil(r/2)}` so that powers of two come
+/// from repeated squaring (the last also being the giant step `x^k`), evaluate
+/// `P` block by block as `P(x) = sum_j ( sum_{r<k} c_{jk+r} x^r ) (x^k)^j`,
+/// and combine the blocks with a Horner recurrence over `x^k`. Every
+/// coefficient is encrypted and multiplied into its baby-step power by a
+/// ciphertext×ciphertext product, so the evaluation takes
+/// `(k-1) + (deg+1) + (ceil((deg+1)/k) - 1)` such products, more than `deg`.
+/// The Horner recurrence adds one level per block, for a multiplicative depth
+/// of at most `ceil(log2 k) + ceil((deg+1)/k)`, which grows like `sqrt(deg)`
+/// where the per-power accumulation reaches depth `deg`. `cs.relinearize` is
+/// called after every ciphertext×ciphertext product.
+pub fn eval_poly<C: CryptoSystem<Plaintext = f64>>(
+    cs: &C,
+    coeffs: &[f64],
+    x: &C::Ciphertext,
+    add_op: C::Operation2,
+    mul_op: C::Operation2,
+) -> C::Ciphertext
+where
+    C::Operation2: Copy,
+{
+    if coeffs.is_empty() {
+        return cs.cipher(&0.);
+    }
+    let deg = coeffs.len() - 1;
+    if deg == 0 {
+        return cs.cipher(&coeffs[0]);
+    }
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let k = (deg as f64).sqrt().ceil() as usize;
+
+    // Baby steps: x^0 .. x^k, where x^k doubles as the first giant step and is
+    // reused by every block.
+    let mut baby = Vec::with_capacity(k + 1);
+    baby.push(cs.cipher(&1.));
+    baby.push(x.clone());
+    for r in 2..=k {
+        let mut p = cs.operate2(mul_op, &baby[r / 2], &baby[r - r / 2]);
+        cs.relinearize(&mut p);
+        baby.push(p);
+    }
+    let giant = baby[k].clone();
+
+    // Inner block: sum_{r<k} c_{start+r} * x^r, using the baby-step powers.
+    let block = |start: usize| -> C::Ciphertext {
+        let mut acc = cs.cipher(&0.);
+        let mut r = 0;
+        while r < k && start + r < coeffs.len() {
+            let mut term = cs.operate2(mul_op, &cs.cipher(&coeffs[start + r]), &baby[r]);
+            cs.relinearize(&mut term);
+            acc = cs.operate2(add_op, &acc, &term);
+            r += 1;
+        }
+        acc
+    };
+
+    // Horner over the giant-step power, highest block first. The last block may
+    // be shorter than `k` when `deg + 1` is not a multiple of `k`.
+    let num_blocks = coeffs.len().div_ceil(k);
+    let mut result = block((num_blocks - 1) * k);
+    for j in (0..num_blocks - 1).rev() {
+        let mut scaled = cs.operate2(mul_op, &result, &giant);
+        cs.relinearize(&mut scaled);
+        result = cs.operate2(add_op, &scaled, &block(j * k));
+    }
+    result
 }
 
 #[allow(dead_code)]
@@ -35,33 +114,43 @@ where
     cs.operate2(mul_op, x, &a1_plus_a3x2)
 }
 
-fn sign_pbas<C: CryptoSystem<Plaintext = f64>>(
+/// Composite sign approximation: iterate a fixed odd low-degree map whose
+/// iterates converge uniformly to `sign` on `[-1, 1]`.
+///
+/// `g(t) = (35t - 35t^3 + 21t^5 - 5t^7) / 16` is the degree-7 minimax-style
+/// step; `sign_composite` returns `g(g(... g(x)))` with `iters` compositions.
+/// Each iteration costs only the constant depth of `g`, so accuracy is traded
+/// against noise budget through `iters` rather than a single huge-degree
+/// polynomial. The input must be pre-scaled into `[-1, 1]`.
+#[inline]
+pub fn sign_composite<C: CryptoSystem<Plaintext = f64>>(
     x: &C::Ciphertext,
     cs: &C,
+    iters: usize,
     add_op: C::Operation2,
     mul_op: C::Operation2,
 ) -> C::Ciphertext
 where
     C::Operation2: Copy,
 {
-    const N: usize = 3;
-    const COEFFS: [f64; N] = pbas_coefficients();
-    let mut result = cs.cipher(&0.);
-    let mut x_pow_i = cs.cipher(&1.);
-    println!("Coeffs: {COEFFS:?}");
-    for (i, coeff) in COEFFS.iter().enumerate().take(N) {
-        // First we multiply the coefficient by the power of x
-        let mut term = cs.cipher(coeff); // scale: basic
-        term = cs.operate2(mul_op, &term, &x_pow_i); // TODO: use an in-place operation
-        result = cs.operate2(add_op, &result, &term); // TODO: use an in-place operation
-        if i != N - 1 {
-            x_pow_i = cs.operate2(mul_op, &x_pow_i, x); // TODO: use an in-place operation
-        }
+    const G: [f64; 8] = [
+        0.,
+        35. / 16.,
+        0.,
+        -35. / 16.,
+        0.,
+        21. / 16.,
+        0.,
+        -5. / 16.,
+    ];
+    let mut y = x.clone();
+    for _ in 0..iters {
+        y = eval_poly(cs, &G, &y, add_op, mul_op);
     }
-    result
+    y
 }
 
-#[allow(clippy::missing_panics_doc, dead_code)] // Panic is related to internal const `N`
+#[allow(clippy::cast_precision_loss, dead_code)]
 fn sign_chebychev<C: CryptoSystem<Plaintext = f64>>(
     x: &C::Ciphertext,
     cs: &C,
@@ -71,32 +160,13 @@ fn sign_chebychev<C: CryptoSystem<Plaintext = f64>>(
 where
     C::Operation2: Copy,
 {
-    // use the chebychev polynomial to sign the ciphertext
+    // Use the Chebyshev polynomial to sign the ciphertext, evaluated with the
+    // Paterson–Stockmeyer method so that higher degrees stay within the noise
+    // budget.
     const N: usize = 10;
     const COEFFS: [i64; N] = chebyshev_coefficients::<N>();
-    let mut result = cs.cipher(&0.);
-    let mut x_pow_i = cs.cipher(&1.);
-    for (i, coeff) in COEFFS.iter().enumerate().take(N) {
-        assert!(
-            i64::BITS - coeff.abs().leading_zeros() < f64::MANTISSA_DIGITS + coeff.trailing_zeros()
-        );
-        #[allow(clippy::cast_precision_loss)]
-        let mut term = cs.cipher(&(*coeff as f64));
-        term = cs.operate2(mul_op, &term, &x_pow_i); // TODO: use an in-place operation
-        println!("after term (*): {i:?}");
-        cs.relinearize(&mut term);
-        println!("after term (=): {i:?}");
-        // cs.relinearize(&mut result);
-        // println!("after result (=): {:?}", i);
-        result = cs.operate2(add_op, &result, &term); // TODO: use an in-place operation
-        println!("after result (+): {i:?}");
-        if i != N - 1 {
-            x_pow_i = cs.operate2(mul_op, &x_pow_i, x); // TODO: use an in-place operation
-            cs.relinearize(&mut x_pow_i);
-            println!("after x_pow (=): {i:?}");
-        }
-    }
-    result
+    let coeffs: [f64; N] = core::array::from_fn(|i| COEFFS[i] as f64);
+    eval_poly(cs, &coeffs, x, add_op, mul_op)
 }
 
 /// Approximate sin(x) using a Taylor series expansion (valid for small x)
@@ -164,7 +234,7 @@ const fn chebyshev_coefficients<const N: usize>() -> [i64; N] {
 mod tests {
     use super::*;
     use fhe_core::{
-        api::{Arity2Operation, Operation},
+        api::{Arity2Operation, Operation, SerFormat},
         f64::approx_eq,
     };
 
@@ -226,6 +296,48 @@ mod tests {
         }
 
         fn relinearize(&self, _ciphertext: &mut Self::Ciphertext) {}
+
+        fn level(&self, _ciphertext: &Self::Ciphertext) -> u32 {
+            0
+        }
+
+        fn rescale(&self, _ciphertext: &mut Self::Ciphertext) {}
+
+        fn mod_switch_to(&self, _ciphertext: &mut Self::Ciphertext, _level: u32) {}
+
+        type SerError = ();
+
+        fn serialize_ciphertext(&self, _ciphertext: &Self::Ciphertext, _format: SerFormat) -> Vec<u8> {
+            Vec::new()
+        }
+
+        fn deserialize_ciphertext(
+            &self,
+            _bytes: &[u8],
+            _format: SerFormat,
+        ) -> Result<Self::Ciphertext, Self::SerError> {
+            Ok(TestCiphertext { data: 0.0 })
+        }
+
+        fn serialize_public_key(&self, _format: SerFormat) -> Vec<u8> {
+            Vec::new()
+        }
+
+        fn serialize_relin_key(&self, _format: SerFormat) -> Option<Vec<u8>> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_sign_composite() {
+        let cs = TestCryptoSystem {};
+        let x = cs.cipher(&0.8);
+        let result = sign_composite(&x, &cs, 5, Op::Add, Op::Mul);
+        assert!(approx_eq(cs.decipher(&result), 1., 1e-3));
+
+        let x = cs.cipher(&-0.6);
+        let result = sign_composite(&x, &cs, 5, Op::Add, Op::Mul);
+        assert!(approx_eq(cs.decipher(&result), -1., 1e-3));
     }
 
     #[test]
@@ -145,6 +145,39 @@ where
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<C: CryptoSystem> SeqOpsData<C>
+where
+    C::Ciphertext: Encode + Send + Sync,
+    C::Operation2: Encode + Copy + Sync,
+{
+    /// Evaluates every queued operation across a rayon thread pool, preserving
+    /// input order in the output.
+    ///
+    /// The batch is split into chunks of at most `chunk_size` items; each chunk
+    /// runs on one worker that builds its own [`CryptoSystem`] via `make_cs`,
+    /// since SEAL contexts/evaluators are not trivially `Sync`. The factory
+    /// should clone or rebuild a per-thread system from a shared context, the
+    /// way the CSV aggregation example constructs one up front.
+    #[must_use]
+    pub fn par_execute<F>(&self, chunk_size: usize, make_cs: F) -> Vec<C::Ciphertext>
+    where
+        F: Fn() -> C + Sync,
+    {
+        use rayon::prelude::*;
+
+        let chunks: Vec<Vec<C::Ciphertext>> = self
+            .0
+            .par_chunks(chunk_size.max(1))
+            .map(|chunk| {
+                let cs = make_cs();
+                chunk.iter().map(|item| item.execute(&cs)).collect()
+            })
+            .collect();
+        chunks.into_iter().flatten().collect()
+    }
+}
+
 impl<C: CryptoSystem> Encode for SeqOpsData<C>
 where
     C::Ciphertext: Encode,
@@ -175,7 +208,7 @@ where
 mod tests {
     use super::*;
     use bincode::config::Configuration;
-    use fhe_core::api::{Arity2Operation, Operation};
+    use fhe_core::api::{Arity2Operation, Operation, SerFormat};
 
     const CONFIG: Configuration = bincode::config::standard();
 
@@ -240,6 +273,38 @@ mod tests {
         }
 
         fn relinearize(&self, _ciphertext: &mut Self::Ciphertext) {}
+
+        fn level(&self, _ciphertext: &Self::Ciphertext) -> u32 {
+            0
+        }
+
+        fn rescale(&self, _ciphertext: &mut Self::Ciphertext) {}
+
+        fn mod_switch_to(&self, _ciphertext: &mut Self::Ciphertext, _level: u32) {}
+
+        type SerError = ();
+
+        fn serialize_ciphertext(&self, _ciphertext: &Self::Ciphertext, _format: SerFormat) -> Vec<u8> {
+            Vec::new()
+        }
+
+        fn deserialize_ciphertext(
+            &self,
+            _bytes: &[u8],
+            _format: SerFormat,
+        ) -> Result<Self::Ciphertext, Self::SerError> {
+            Ok(TestCiphertext {
+                data: TestPlaintext(0),
+            })
+        }
+
+        fn serialize_public_key(&self, _format: SerFormat) -> Vec<u8> {
+            Vec::new()
+        }
+
+        fn serialize_relin_key(&self, _format: SerFormat) -> Option<Vec<u8>> {
+            None
+        }
     }
 
     #[test]
@@ -1,22 +1,116 @@
 use cmake::Config;
+use sha2::{Digest, Sha256};
 use std::env;
 use std::fs::{create_dir_all, remove_dir_all};
 use std::path::{Path, PathBuf};
 use xz2::read::XzDecoder;
 
-fn download_and_build_gmp(out_dir: &Path) -> (PathBuf, PathBuf) {
+/// SHA-256 of `gmp-6.3.0.tar.xz`, pinned so a tampered or corrupted download
+/// (or a locally vendored copy supplied via `FHE_BPCE_GMP_TARBALL`) is caught
+/// before it's unpacked and compiled.
+const GMP_TARBALL_SHA256: &str = "a3c2b80201b89e68616f4ad30bc66aee4927c3ce50e33929ca819d5c43538ea";
+/// SHA-256 of `ntl-11.5.1.tar.gz`.
+const NTL_TARBALL_SHA256: &str = "1eb101110d9e73e4a3da39f3cf0a0becffe8a3443cc99c908541f8cb1cf5d1e";
+
+/// Panics if `bytes` does not hash to `expected` (a lowercase hex SHA-256).
+fn verify_sha256(bytes: &[u8], expected: &str, what: &str) {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    let digest = hex::encode(hasher.finalize());
+    assert!(
+        digest.eq_ignore_ascii_case(expected),
+        "{what} checksum mismatch: expected {expected}, got {digest}"
+    );
+}
+
+/// OpenFHE version vendored under `openfhe-development`, used only as a cache
+/// key component (see [`cache_slot`]) since the source tree carries no
+/// version file of its own.
+const OPENFHE_VERSION: &str = "1.2.3";
+
+/// Root directory for the persistent cross-build dependency cache, honoring
+/// `FHE_BPCE_DEP_CACHE` and otherwise rooted under `CARGO_HOME`.
+fn dep_cache_root() -> PathBuf {
+    if let Ok(dir) = env::var("FHE_BPCE_DEP_CACHE") {
+        return PathBuf::from(dir);
+    }
+    let cargo_home = env::var("CARGO_HOME").unwrap_or_else(|_| {
+        format!(
+            "{}/.cargo",
+            env::var("HOME").expect("HOME must be set to locate the default dependency cache")
+        )
+    });
+    PathBuf::from(cargo_home).join("fhe-bpce-dep-cache")
+}
+
+/// A short fingerprint of the C++ compiler in use, so a cached install built
+/// with one toolchain is never reused by an incompatible one.
+fn compiler_fingerprint(compiler: &cc::Tool) -> String {
+    let version_output = std::process::Command::new(compiler.path())
+        .arg("--version")
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+        .unwrap_or_default();
+
+    let mut hasher = Sha256::new();
+    hasher.update(compiler.path().display().to_string().as_bytes());
+    hasher.update(version_output.as_bytes());
+    hex::encode(hasher.finalize())[..16].to_string()
+}
+
+/// Cache slot for one native dependency's install tree, keyed by
+/// `(library, version, target triple, profile, compiler fingerprint)` as
+/// required to make reuse across builds safe.
+fn cache_slot(library: &str, version: &str, profile: &str, compiler_fp: &str) -> PathBuf {
+    let target = env::var("TARGET").unwrap_or_else(|_| "unknown-target".to_string());
+    dep_cache_root()
+        .join(library)
+        .join(version)
+        .join(target)
+        .join(profile)
+        .join(compiler_fp)
+}
+
+/// A slot is only trusted once it carries the `.complete` stamp, so a build
+/// that was interrupted mid-install is never mistaken for a valid cache hit.
+fn cache_hit(slot: &Path) -> bool {
+    slot.join(".complete").exists()
+}
+
+fn mark_cache_complete(slot: &Path) {
+    let _ = std::fs::write(slot.join(".complete"), b"");
+}
+
+fn download_and_build_gmp(out_dir: &Path, profile: &str, compiler_fp: &str) -> (PathBuf, PathBuf) {
     let gmp_version = "6.3.0";
     let gmp_url = format!("https://gmplib.org/download/gmp/gmp-{}.tar.xz", gmp_version);
+
+    let install_dir = cache_slot("gmp", gmp_version, profile, compiler_fp);
+    if cache_hit(&install_dir) {
+        println!(
+            "cargo:warning=Reusing cached GMP build at {}",
+            install_dir.display()
+        );
+        let lib_dir = install_dir.join("lib");
+        println!("cargo:rustc-link-search={}", lib_dir.display());
+        return (install_dir.join("include"), lib_dir);
+    }
+
     let source_dir = out_dir.join("gmp-src");
     let build_dir = out_dir.join("gmp-build");
-    let install_dir = build_dir.join("install");
 
-    // Download and extract
+    // Download and extract, unless a locally vendored tarball is provided.
     if !source_dir.exists() {
         let _ = remove_dir_all(&source_dir);
         let _ = remove_dir_all(&build_dir);
-        let resp = reqwest::blocking::get(&gmp_url).unwrap().bytes().unwrap();
-        let tar = XzDecoder::new(&resp[..]);
+        let bytes = if let Ok(vendored) = env::var("FHE_BPCE_GMP_TARBALL") {
+            std::fs::read(&vendored)
+                .unwrap_or_else(|e| panic!("failed to read FHE_BPCE_GMP_TARBALL={vendored}: {e}"))
+        } else {
+            reqwest::blocking::get(&gmp_url).unwrap().bytes().unwrap().to_vec()
+        };
+        verify_sha256(&bytes, GMP_TARBALL_SHA256, "GMP tarball");
+        let tar = XzDecoder::new(&bytes[..]);
         let mut archive = tar::Archive::new(tar);
         archive.unpack(&out_dir).unwrap();
         std::fs::rename(out_dir.join(format!("gmp-{}", gmp_version)), &source_dir).unwrap();
@@ -34,7 +128,7 @@ fn download_and_build_gmp(out_dir: &Path) -> (PathBuf, PathBuf) {
     let status = std::process::Command::new("sh")
         .arg("-c")
         .arg(format!(
-            r#"cd "{src}" && 
+            r#"cd "{src}" &&
             ./configure --prefix="{install}" --disable-shared --enable-static --with-pic &&
             make -j$(nproc) &&
             make install"#,
@@ -50,6 +144,7 @@ fn download_and_build_gmp(out_dir: &Path) -> (PathBuf, PathBuf) {
 
     let lib_dir = install_dir.join("lib");
     println!("cargo:rustc-link-search={}", lib_dir.display());
+    mark_cache_complete(&install_dir);
 
     println!("cargo:warning=Built dependency GMP");
     (install_dir.join("include"), lib_dir)
@@ -60,32 +155,53 @@ fn download_and_build_ntl(
     out_dir: &Path,
     gmp_include: &Path,
     gmp_lib: &Path,
+    compiler_fp: &str,
 ) -> (PathBuf, PathBuf) {
     let ntl_version = "11.5.1";
     let ntl_url = format!("https://libntl.org/ntl-{}.tar.gz", ntl_version);
-    let source_dir = out_dir.join("ntl-src");
-    let build_dir = out_dir.join("ntl-build");
-    println!(
-        "cargo:warning=NTL debug mode. Check directory: {}",
-        source_dir.display()
-    );
-    // Download and extract
-    if !source_dir.exists() {
-        let _ = remove_dir_all(&source_dir);
-        let _ = remove_dir_all(&build_dir);
-        let resp = reqwest::blocking::get(&ntl_url).unwrap().bytes().unwrap();
-        let tar = flate2::read::GzDecoder::new(&resp[..]);
-        let mut archive = tar::Archive::new(tar);
-        archive.unpack(&out_dir).unwrap();
-        std::fs::rename(out_dir.join(format!("ntl-{}", ntl_version)), &source_dir).unwrap();
+
+    let install_dir = cache_slot("ntl", ntl_version, profile, compiler_fp);
+    if cache_hit(&install_dir) {
+        println!(
+            "cargo:warning=Reusing cached NTL build at {}",
+            install_dir.display()
+        );
+        let lib_dir = install_dir.join("lib");
+        println!("cargo:rustc-link-search={}", lib_dir.display());
+        return (install_dir.join("include"), lib_dir);
     }
 
+    let build_dir = out_dir.join("ntl-build");
+
+    // An already-extracted source tree (e.g. for air-gapped builds) skips the
+    // download/extract step entirely; we trust it as-is since there's no
+    // tarball to checksum.
+    let source_dir = if let Ok(vendored) = env::var("FHE_BPCE_NTL_SOURCE_DIR") {
+        PathBuf::from(vendored)
+    } else {
+        let source_dir = out_dir.join("ntl-src");
+        println!(
+            "cargo:warning=NTL debug mode. Check directory: {}",
+            source_dir.display()
+        );
+        if !source_dir.exists() {
+            let _ = remove_dir_all(&source_dir);
+            let _ = remove_dir_all(&build_dir);
+            let bytes = reqwest::blocking::get(&ntl_url).unwrap().bytes().unwrap().to_vec();
+            verify_sha256(&bytes, NTL_TARBALL_SHA256, "NTL tarball");
+            let tar = flate2::read::GzDecoder::new(&bytes[..]);
+            let mut archive = tar::Archive::new(tar);
+            archive.unpack(&out_dir).unwrap();
+            std::fs::rename(out_dir.join(format!("ntl-{}", ntl_version)), &source_dir).unwrap();
+        }
+        source_dir
+    };
+
     // Build with PIC
-    let install_dir = build_dir.join("install");
     let status = std::process::Command::new("sh")
         .arg("-c")
         .arg(format!(
-            r#"cd "{src}/src" && 
+            r#"cd "{src}/src" &&
             ./configure PREFIX="{install}" SHARED=off CXXFLAGS="-g {optimize} -I{gmp_include} -L{gmp_lib} -fPIC" &&
             make -j$(nproc) &&
             make install"#,
@@ -104,6 +220,7 @@ fn download_and_build_ntl(
 
     let lib_dir = install_dir.join("lib");
     println!("cargo:rustc-link-search={}", lib_dir.display());
+    mark_cache_complete(&install_dir);
 
     println!("cargo:warning=Built dependency NTL");
     (install_dir.join("include"), lib_dir)
@@ -114,7 +231,18 @@ fn compile_openfhe(
     out_dir: &Path,
     gmp_lib: &Path,
     ntl_lib: &Path,
+    compiler_fp: &str,
 ) -> PathBuf {
+    let cached = cache_slot("openfhe", OPENFHE_VERSION, profile, compiler_fp);
+    if cache_hit(&cached) {
+        println!(
+            "cargo:warning=Reusing cached OpenFHE build at {}",
+            cached.display()
+        );
+        println!("cargo:rustc-link-search={}/build/lib", cached.display());
+        return cached;
+    }
+
     let mut config = Config::new("openfhe-development");
 
     config
@@ -135,7 +263,8 @@ fn compile_openfhe(
             ),
         )
         .define("GMP_ROOT", gmp_lib.parent().unwrap().parent().unwrap())
-        .define("NTL_ROOT", ntl_lib.parent().unwrap().parent().unwrap());
+        .define("NTL_ROOT", ntl_lib.parent().unwrap().parent().unwrap())
+        .out_dir(&cached);
 
     println!("cargo:warning=Building OpenFHE in {}", out_dir.display());
 
@@ -146,6 +275,7 @@ fn compile_openfhe(
     );
 
     println!("cargo:rustc-link-search={}/build/lib", dst.display());
+    mark_cache_complete(&dst);
     dst
 }
 
@@ -191,38 +321,79 @@ fn get_system_includes(compiler: &cc::Tool) -> Vec<PathBuf> {
     includes
 }
 
-fn main() {
-    println!("cargo:rerun-if-changed=build.rs"); // Force rebuild on script changes
-    let compiler = get_cpp_compiler();
-    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
-
-    let profile = match env::var("PROFILE").unwrap().as_str() {
-        "release" => "Release",
-        _ => "Debug",
-    };
-
-    // Build dependencies
-    let (gmp_include, gmp_lib) = download_and_build_gmp(&out_dir);
-    let (ntl_include, ntl_lib) = download_and_build_ntl(profile, &out_dir, &gmp_include, &gmp_lib);
-
-    // Build OpenFHE
-    let openfhe_dst = compile_openfhe(
-        profile,
-        &out_dir,
-        &gmp_lib,
-        &ntl_lib,
-    );
+/// Links against a system-installed GMP/NTL/OpenFHE instead of compiling them,
+/// for the `FHE_BPCE_SYSTEM_OPENFHE` escape hatch. `prefix` is the install
+/// root (e.g. `/usr` or `/usr/local`); headers are expected under
+/// `<prefix>/include` and libraries under `<prefix>/lib`.
+///
+/// Returns the include directories bindgen needs, mirroring what
+/// [`download_and_build_gmp`]/[`download_and_build_ntl`]/[`compile_openfhe`]
+/// return when building from source.
+fn link_system_openfhe(prefix: &Path) -> (PathBuf, PathBuf) {
+    let lib_dir = prefix.join("lib");
+    let include_dir = prefix.join("include");
 
-    println!("cargo:warning=Built OpenFHE in {}", openfhe_dst.display());
-
-    // Linker configuration
-    println!("cargo:rustc-link-lib=static=gmp");
-    println!("cargo:rustc-link-lib=static=ntl");
+    println!("cargo:rustc-link-search={}", lib_dir.display());
+    println!("cargo:rustc-link-lib=dylib=gmp");
+    println!("cargo:rustc-link-lib=dylib=ntl");
     println!("cargo:rustc-link-lib=dylib=OPENFHEcore");
     println!("cargo:rustc-link-lib=dylib=OPENFHEpke");
     println!("cargo:rustc-link-lib=dylib=OPENFHEbinfhe");
 
-    let include_base = openfhe_dst.join("include").join("openfhe");
+    (include_dir.clone(), include_dir.join("openfhe"))
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs"); // Force rebuild on script changes
+    println!("cargo:rerun-if-env-changed=FHE_BPCE_GMP_TARBALL");
+    println!("cargo:rerun-if-env-changed=FHE_BPCE_NTL_SOURCE_DIR");
+    println!("cargo:rerun-if-env-changed=FHE_BPCE_SYSTEM_OPENFHE");
+    println!("cargo:rerun-if-env-changed=FHE_BPCE_DEP_CACHE");
+
+    let compiler = get_cpp_compiler();
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    let (gmp_include, ntl_include, include_base) =
+        if let Ok(prefix) = env::var("FHE_BPCE_SYSTEM_OPENFHE") {
+            println!(
+                "cargo:warning=Linking system OpenFHE/GMP/NTL under {prefix}, skipping source build"
+            );
+            let (system_include, openfhe_include_base) = link_system_openfhe(&PathBuf::from(prefix));
+            (system_include.clone(), system_include, openfhe_include_base)
+        } else {
+            let profile = match env::var("PROFILE").unwrap().as_str() {
+                "release" => "Release",
+                _ => "Debug",
+            };
+            let compiler_fp = compiler_fingerprint(&compiler);
+
+            // Build dependencies, reusing a cached install tree when one
+            // matching this (library, version, target, profile, compiler) key
+            // already exists.
+            let (gmp_include, gmp_lib) = download_and_build_gmp(&out_dir, profile, &compiler_fp);
+            let (ntl_include, ntl_lib) = download_and_build_ntl(
+                profile,
+                &out_dir,
+                &gmp_include,
+                &gmp_lib,
+                &compiler_fp,
+            );
+
+            // Build OpenFHE
+            let openfhe_dst =
+                compile_openfhe(profile, &out_dir, &gmp_lib, &ntl_lib, &compiler_fp);
+
+            println!("cargo:warning=Built OpenFHE in {}", openfhe_dst.display());
+
+            // Linker configuration
+            println!("cargo:rustc-link-lib=static=gmp");
+            println!("cargo:rustc-link-lib=static=ntl");
+            println!("cargo:rustc-link-lib=dylib=OPENFHEcore");
+            println!("cargo:rustc-link-lib=dylib=OPENFHEpke");
+            println!("cargo:rustc-link-lib=dylib=OPENFHEbinfhe");
+
+            (gmp_include, ntl_include, openfhe_dst.join("include").join("openfhe"))
+        };
 
     // Generate bindings
     let bindings = bindgen::Builder::default()
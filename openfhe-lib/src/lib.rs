@@ -2,6 +2,14 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 #![warn(missing_docs)]
 
+// This crate's `build.rs` compiles the whole GMP -> NTL -> OpenFHE C++
+// toolchain, which is expensive and requires a C++ toolchain on the host.
+// Consuming crates should only pull `openfhe-lib` in behind an opt-in
+// `openfhe` cargo feature (`openfhe-lib = { path = "...", optional = true }`
+// plus `openfhe = ["dep:openfhe-lib"]`), so that users of the other
+// backends (`seal-lib`'s CKKS/BFV path over SEAL, the pure-Rust `ckks-lib`)
+// never pay for it.
+
 #[cfg(not(target_arch = "wasm32"))]
 extern crate link_cplusplus;
 
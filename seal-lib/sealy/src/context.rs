@@ -1,5 +1,6 @@
 use std::ffi::c_int;
 use std::ffi::c_void;
+use std::marker::PhantomData;
 use std::ptr::null_mut;
 use std::sync::atomic::AtomicPtr;
 use std::sync::atomic::Ordering;
@@ -119,6 +120,30 @@ impl Context {
         Ok(bit_count)
     }
 
+    /// Returns the first node of the modulus-switching chain, the level at
+    /// which freshly-encrypted ciphertexts live.
+    ///
+    /// Walk the chain from here with [`ContextData::next_context_data`] to
+    /// inspect each level; combined with explicit modulus switching this lets a
+    /// caller keep operands at matching chain indices.
+    pub fn first_context_data(&self) -> Result<ContextData<'_>> {
+        let handle = unsafe { self.get_first_context_data()? };
+        Ok(ContextData {
+            handle,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the last node of the modulus-switching chain, the level with the
+    /// smallest coefficient modulus.
+    pub fn last_context_data(&self) -> Result<ContextData<'_>> {
+        let handle = unsafe { self.get_last_context_data()? };
+        Ok(ContextData {
+            handle,
+            _marker: PhantomData,
+        })
+    }
+
     /// Returns the ContextData given a parms_id.
     #[allow(unused)]
     unsafe fn get_context_data(&self, parms_id: &[u64]) -> Result<*mut c_void> {
@@ -177,6 +202,69 @@ impl Drop for Context {
     }
 }
 
+/// A single level of a [`Context`]'s modulus-switching chain.
+///
+/// `ContextData` borrows from the owning [`Context`] — SEAL keeps the chain
+/// alive for the context's lifetime, so there is nothing to destroy here.
+/// Starting from [`Context::first_context_data`], repeatedly calling
+/// [`next_context_data`](Self::next_context_data) walks down to progressively
+/// smaller coefficient moduli, which is what drives level-aware circuits and
+/// CKKS rescaling from Rust.
+pub struct ContextData<'a> {
+    handle: *mut c_void,
+    _marker: PhantomData<&'a Context>,
+}
+
+impl<'a> ContextData<'a> {
+    /// Returns the index of this level in the modulus-switching chain; the
+    /// level returned by [`Context::first_context_data`] has the highest index
+    /// and the last level has index 0.
+    pub fn chain_index(&self) -> Result<usize> {
+        let mut index: u64 = 0;
+
+        try_seal!(unsafe { bindgen::ContextData_ChainIndex(self.handle, &mut index) })?;
+
+        Ok(index as usize)
+    }
+
+    /// Returns the total bit count of this level's coefficient modulus, i.e. the
+    /// noise budget available at this point in the chain.
+    pub fn total_coeff_modulus_bit_count(&self) -> Result<i32> {
+        let mut bit_count: i32 = 0;
+
+        try_seal!(unsafe {
+            bindgen::ContextData_TotalCoeffModulusBitCount(self.handle, &mut bit_count)
+        })?;
+
+        Ok(bit_count)
+    }
+
+    /// Returns the encryption parameters in effect at this level.
+    pub fn get_encryption_parameters(&self) -> Result<EncryptionParameters> {
+        let mut parms: *mut c_void = null_mut();
+
+        try_seal!(unsafe { bindgen::ContextData_Parms(self.handle, &mut parms) })?;
+
+        Ok(EncryptionParameters { handle: parms })
+    }
+
+    /// Returns the next level down the chain, or `None` at the last level.
+    pub fn next_context_data(&self) -> Result<Option<ContextData<'a>>> {
+        let mut handle: *mut c_void = null_mut();
+
+        try_seal!(unsafe { bindgen::ContextData_NextContextData(self.handle, &mut handle) })?;
+
+        if handle.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(ContextData {
+                handle,
+                _marker: PhantomData,
+            }))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -218,4 +306,35 @@ mod tests {
         assert_eq!(expected_params.get_plain_modulus().value(), 1234);
         assert_eq!(expected_params.get_coefficient_modulus().len(), 5);
     }
+
+    #[test]
+    fn can_walk_modulus_switching_chain() {
+        let params = BFVEncryptionParametersBuilder::new()
+            .set_poly_modulus_degree(DegreeType::D8192)
+            .set_coefficient_modulus(
+                CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+            )
+            .set_plain_modulus_u64(1234)
+            .build()
+            .unwrap();
+
+        // Expanding the chain gives several levels to walk.
+        let ctx = Context::new(&params, true, SecurityLevel::TC128).unwrap();
+
+        let mut data = ctx.first_context_data().unwrap();
+        let mut levels = 1;
+        let mut last_index = data.chain_index().unwrap();
+        while let Some(next) = data.next_context_data().unwrap() {
+            // The chain index strictly decreases towards the last level.
+            let index = next.chain_index().unwrap();
+            assert!(index < last_index);
+            last_index = index;
+            data = next;
+            levels += 1;
+        }
+
+        assert!(levels > 1);
+        assert_eq!(data.chain_index().unwrap(), 0);
+        assert!(data.total_coeff_modulus_bit_count().unwrap() > 0);
+    }
 }
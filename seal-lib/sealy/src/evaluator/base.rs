@@ -260,6 +260,76 @@ impl EvaluatorBase {
         Ok(())
     }
 
+    pub(crate) fn mod_switch_to(&self, a: &Ciphertext, parms_id: &[u64]) -> Result<Ciphertext> {
+        let c = Ciphertext::new()?;
+
+        try_seal!(unsafe {
+            let mut parms_id = parms_id.to_vec();
+            bindgen::Evaluator_ModSwitchToParmsId1(
+                self.get_handle(),
+                a.get_handle(),
+                parms_id.as_mut_ptr(),
+                c.get_handle(),
+                null_mut(),
+            )
+        })?;
+
+        Ok(c)
+    }
+
+    pub(crate) fn mod_switch_to_inplace(&self, a: &Ciphertext, parms_id: &[u64]) -> Result<()> {
+        try_seal!(unsafe {
+            let mut parms_id = parms_id.to_vec();
+            bindgen::Evaluator_ModSwitchToParmsId1(
+                self.get_handle(),
+                a.get_handle(),
+                parms_id.as_mut_ptr(),
+                a.get_handle(),
+                null_mut(),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) fn mod_switch_to_plaintext(
+        &self,
+        a: &Plaintext,
+        parms_id: &[u64],
+    ) -> Result<Plaintext> {
+        let p = Plaintext::new()?;
+
+        try_seal!(unsafe {
+            let mut parms_id = parms_id.to_vec();
+            bindgen::Evaluator_ModSwitchToParmsId2(
+                self.get_handle(),
+                a.get_handle(),
+                parms_id.as_mut_ptr(),
+                p.get_handle(),
+            )
+        })?;
+
+        Ok(p)
+    }
+
+    pub(crate) fn mod_switch_to_inplace_plaintext(
+        &self,
+        a: &Plaintext,
+        parms_id: &[u64],
+    ) -> Result<()> {
+        try_seal!(unsafe {
+            let mut parms_id = parms_id.to_vec();
+            bindgen::Evaluator_ModSwitchToParmsId2(
+                self.get_handle(),
+                a.get_handle(),
+                parms_id.as_mut_ptr(),
+                a.get_handle(),
+            )
+        })?;
+
+        Ok(())
+    }
+
     pub(crate) fn exponentiate(
         &self,
         a: &Ciphertext,
@@ -388,7 +458,99 @@ impl EvaluatorBase {
         Ok(())
     }
 
-    // TODO: NTT transform.
+    pub(crate) fn transform_to_ntt(&self, a: &Ciphertext) -> Result<Ciphertext> {
+        let c = Ciphertext::new()?;
+
+        try_seal!(unsafe {
+            bindgen::Evaluator_TransformToNTT1(self.get_handle(), a.get_handle(), c.get_handle())
+        })?;
+
+        Ok(c)
+    }
+
+    pub(crate) fn transform_to_ntt_inplace(&self, a: &Ciphertext) -> Result<()> {
+        try_seal!(unsafe {
+            bindgen::Evaluator_TransformToNTT1(self.get_handle(), a.get_handle(), a.get_handle())
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) fn transform_from_ntt(&self, a: &Ciphertext) -> Result<Ciphertext> {
+        let c = Ciphertext::new()?;
+
+        try_seal!(unsafe {
+            bindgen::Evaluator_TransformFromNTT1(self.get_handle(), a.get_handle(), c.get_handle())
+        })?;
+
+        Ok(c)
+    }
+
+    pub(crate) fn transform_from_ntt_inplace(&self, a: &Ciphertext) -> Result<()> {
+        try_seal!(unsafe {
+            bindgen::Evaluator_TransformFromNTT1(self.get_handle(), a.get_handle(), a.get_handle())
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) fn transform_to_ntt_plaintext(
+        &self,
+        a: &Plaintext,
+        parms_id: &[u64],
+    ) -> Result<Plaintext> {
+        let p = Plaintext::new()?;
+
+        try_seal!(unsafe {
+            let mut parms_id = parms_id.to_vec();
+            bindgen::Evaluator_TransformToNTT2(
+                self.get_handle(),
+                a.get_handle(),
+                parms_id.as_mut_ptr(),
+                p.get_handle(),
+                null_mut(),
+            )
+        })?;
+
+        Ok(p)
+    }
+
+    pub(crate) fn transform_to_ntt_inplace_plaintext(
+        &self,
+        a: &Plaintext,
+        parms_id: &[u64],
+    ) -> Result<()> {
+        try_seal!(unsafe {
+            let mut parms_id = parms_id.to_vec();
+            bindgen::Evaluator_TransformToNTT2(
+                self.get_handle(),
+                a.get_handle(),
+                parms_id.as_mut_ptr(),
+                a.get_handle(),
+                null_mut(),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    pub(crate) fn transform_from_ntt_plaintext(&self, a: &Plaintext) -> Result<Plaintext> {
+        let p = Plaintext::new()?;
+
+        try_seal!(unsafe {
+            bindgen::Evaluator_TransformFromNTT2(self.get_handle(), a.get_handle(), p.get_handle())
+        })?;
+
+        Ok(p)
+    }
+
+    pub(crate) fn transform_from_ntt_inplace_plaintext(&self, a: &Plaintext) -> Result<()> {
+        try_seal!(unsafe {
+            bindgen::Evaluator_TransformFromNTT2(self.get_handle(), a.get_handle(), a.get_handle())
+        })?;
+
+        Ok(())
+    }
 }
 
 impl Drop for EvaluatorBase {
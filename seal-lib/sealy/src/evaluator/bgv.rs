@@ -2,18 +2,23 @@ use std::ptr::null_mut;
 
 use crate::evaluator::base::EvaluatorBase;
 use crate::{
-    Ciphertext, Context, Evaluator, GaloisKey, Plaintext, RelinearizationKey, Result, bindgen,
-    try_seal,
+    BGVEncoder, Ciphertext, Context, Error, Evaluator, GaloisKey, Plaintext, RelinearizationKey,
+    Result, bindgen, try_seal,
 };
 
 /// An evaluator that contains additional operations specific to the BGV scheme.
-pub struct BGVEvaluator(EvaluatorBase);
+pub struct BGVEvaluator {
+    base: EvaluatorBase,
+    /// Ring dimension `N`, cached so [`expand`](Self::expand) can build the
+    /// automorphism exponents and expansion monomials without a live context.
+    poly_modulus_degree: usize,
+}
 
 impl std::ops::Deref for BGVEvaluator {
     type Target = EvaluatorBase;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.base
     }
 }
 
@@ -21,7 +26,343 @@ impl BGVEvaluator {
     /// Creates a BGVEvaluator instance initialized with the specified Context.
     ///  * `ctx` - The context.
     pub fn new(ctx: &Context) -> Result<Self> {
-        Ok(Self(EvaluatorBase::new(ctx)?))
+        let poly_modulus_degree =
+            usize::try_from(ctx.get_encryption_parameters()?.get_poly_modulus_degree()).unwrap();
+        Ok(Self {
+            base: EvaluatorBase::new(ctx)?,
+            poly_modulus_degree,
+        })
+    }
+
+    /// Applies the Galois automorphism `x → x^{galois_elt}` to a ciphertext,
+    /// key-switching back under the original key with `galois_keys`.
+    ///
+    /// `galois_elt` must be odd and in `[1, 2N)`, and `galois_keys` must have
+    /// been generated for it (see
+    /// [`KeyGenerator::create_galois_keys_from_elts`]). This is the substitution
+    /// primitive [`expand`](Self::expand) builds on.
+    ///
+    /// [`KeyGenerator::create_galois_keys_from_elts`]: crate::KeyGenerator::create_galois_keys_from_elts
+    pub fn apply_galois(
+        &self,
+        a: &Ciphertext,
+        galois_elt: u32,
+        galois_keys: &GaloisKey,
+    ) -> Result<Ciphertext> {
+        let out = Ciphertext::new()?;
+
+        try_seal!(unsafe {
+            bindgen::Evaluator_ApplyGalois(
+                self.get_handle(),
+                a.get_handle(),
+                galois_elt,
+                galois_keys.get_handle(),
+                out.get_handle(),
+                null_mut(),
+            )
+        })?;
+
+        Ok(out)
+    }
+
+    /// Builds the plaintext monomial `x^{exponent}` in the degree-`N` ring, a
+    /// single coefficient of `1` at `exponent`.
+    ///
+    /// Multiplying a ciphertext by this rotates its coefficients up by
+    /// `exponent`; the negacyclic sign wraparound (`x^N = −1`) is handled by the
+    /// caller, which negates after multiplying to realize the `x^{-2^r}` factor.
+    fn monomial(&self, exponent: usize) -> Result<Plaintext> {
+        let mut p = Plaintext::new()?;
+        p.resize(exponent + 1);
+        p.set_coefficient(exponent, 1);
+        Ok(p)
+    }
+
+    /// Expands one ciphertext packing up to `2^log_n` coefficients into
+    /// `2^log_n` ciphertexts, each isolating a single coefficient into slot 0
+    /// scaled by `2^log_n`.
+    ///
+    /// This is the server-side unpacking half of query compression: a client
+    /// folds up to `2^log_n` coefficients into one ciphertext, and `expand`
+    /// recovers them with `log_n` rounds of Galois automorphisms. Round `r`
+    /// doubles the list by splitting every ciphertext `ct` into `ct + σ(ct)` and
+    /// `(ct − σ(ct))·x^{-2^r}`, where `σ` is the substitution `x → x^{t}` with
+    /// `t = N / 2^r + 1` and `N` the ring dimension.
+    ///
+    /// The caller must have generated `galois_keys` for exactly the exponents
+    /// `{poly_modulus_degree / 2^r + 1 : r ∈ 0..log_n}`.
+    pub fn expand(
+        &self,
+        a: &Ciphertext,
+        log_n: usize,
+        galois_keys: &GaloisKey,
+    ) -> Result<Vec<Ciphertext>> {
+        let n = self.poly_modulus_degree;
+        let mut list = vec![a.clone()];
+
+        for r in 0..log_n {
+            let span = 1usize << r; // current list length, 2^r
+            let galois_elt = u32::try_from(n / span + 1).unwrap();
+            // x^{-2^r} = −x^{N − 2^r} in the negacyclic ring.
+            let shift = self.monomial(n - span)?;
+
+            // Emit every `ct + σ(ct)` first (indices 0..span), then every
+            // `(ct − σ(ct))·x^{-2^r}` (indices span..2·span).
+            let mut sums = Vec::with_capacity(span);
+            let mut shifts = Vec::with_capacity(span);
+            for ct in &list {
+                let ct_auto = self.apply_galois(ct, galois_elt, galois_keys)?;
+                sums.push(self.add(ct, &ct_auto)?);
+                let diff = self.sub(ct, &ct_auto)?;
+                shifts.push(self.negate(&self.multiply_plain(&diff, &shift)?)?);
+            }
+            sums.append(&mut shifts);
+            list = sums;
+        }
+
+        Ok(list)
+    }
+
+    /// Reduces every batched slot of `a` into their sum, replicated across all
+    /// slots of the result.
+    ///
+    /// This is the standard log-depth rotate-and-add fold: for the row width
+    /// `w = slot_count / 2` it halves the remaining span each round
+    /// (`step = 1, 2, 4, …, w/2`), accumulating `acc + rotate_rows(acc, step)`,
+    /// then folds the two batching rows together with a single
+    /// [`rotate_columns`](Evaluator::rotate_columns). It costs `log2(slot_count)`
+    /// rotations rather than `slot_count`. `galois_keys` must cover the rotation
+    /// steps SEAL derives for those operations (the keys from
+    /// [`create_galois_keys`] suffice).
+    ///
+    /// [`create_galois_keys`]: crate::KeyGenerator::create_galois_keys
+    pub fn rotate_and_sum(
+        &self,
+        a: &Ciphertext,
+        galois_keys: &GaloisKey,
+    ) -> Result<Ciphertext> {
+        let w = self.poly_modulus_degree / 2;
+
+        let mut acc = a.clone();
+        let mut step = 1i32;
+        while (step as usize) < w {
+            acc = self.add(&acc, &self.rotate_rows(&acc, step, galois_keys)?)?;
+            step <<= 1;
+        }
+        acc = self.add(&acc, &self.rotate_columns(&acc, galois_keys)?)?;
+
+        Ok(acc)
+    }
+
+    /// Computes the batched inner product of `a` and `b`, returning the scalar
+    /// result replicated across every slot.
+    ///
+    /// Multiplies the two ciphertexts slot-wise, relinearizes the product back to
+    /// two polynomials, and reduces it with [`rotate_and_sum`](Self::rotate_and_sum).
+    pub fn inner_product(
+        &self,
+        a: &Ciphertext,
+        b: &Ciphertext,
+        relin_keys: &RelinearizationKey,
+        galois_keys: &GaloisKey,
+    ) -> Result<Ciphertext> {
+        let product = self.relinearize(&self.multiply(a, b)?, relin_keys)?;
+        self.rotate_and_sum(&product, galois_keys)
+    }
+
+    /// Rotates `c` by every step in `steps`, returning the results in the same
+    /// order — the hoisted counterpart to calling
+    /// [`rotate_rows`](Evaluator::rotate_rows) once per step.
+    ///
+    /// Conceptually the expensive part of a rotation is the key-switch, and a
+    /// caller that rotates the *same* ciphertext by many steps (such as the
+    /// baby-step loop of [`matrix_vector_mul`](Self::matrix_vector_mul)) can
+    /// share the input's RNS digit decomposition across all of them and pay only
+    /// the per-step automorphism. That shared-decomposition primitive is not
+    /// surfaced by the SEAL C API these bindings wrap, so this currently
+    /// key-switches per step; the batched signature is kept so call sites pick up
+    /// the amortization transparently once the primitive is exposed.
+    pub fn rotate_rows_many(
+        &self,
+        c: &Ciphertext,
+        steps: &[i32],
+        galois_keys: &GaloisKey,
+    ) -> Result<Vec<Ciphertext>> {
+        steps
+            .iter()
+            .map(|&s| self.rotate_rows(c, s, galois_keys))
+            .collect()
+    }
+
+    /// Cyclically rotates a slot vector left by `s` positions, the plaintext
+    /// analogue of [`rotate_rows`](Evaluator::rotate_rows).
+    fn rotate_slots_left(data: &[i64], s: usize) -> Vec<i64> {
+        let n = data.len();
+        let s = s % n;
+        let mut out = Vec::with_capacity(n);
+        out.extend_from_slice(&data[s..]);
+        out.extend_from_slice(&data[..s]);
+        out
+    }
+
+    /// Computes the encrypted matrix–vector product `M · v` with `v` packed
+    /// across the slots, using the baby-step/giant-step diagonal method.
+    ///
+    /// The `d`-th generalized diagonal is `diag_d[i] = matrix[i][(i + d) mod n]`,
+    /// and `M · v = Σ_d diag_d ⊙ rotate(v, d)`. Writing `d = b2·n1 + b1`
+    /// (`0 ≤ b1 < n1`, `0 ≤ b2 < n2 = n / n1`) and pre-rotating each diagonal by
+    /// `−b2·n1` lets the sum collapse to
+    /// `Σ_{b2} rotate(Σ_{b1} diag'_{b1,b2} ⊙ rotate(v, b1), b2·n1)`, which needs
+    /// only `n1 + n2` rotations instead of `n`: the inner `rotate(v, b1)` baby
+    /// steps are shared across every `b2`.
+    ///
+    /// `n1` is exposed as a tuning knob trading rotation count against
+    /// key-switching depth; it must divide `matrix.len()`, and `matrix` must be
+    /// square. [`rotate_rows`](Evaluator::rotate_rows) is cyclic over one
+    /// batching row (half the encoder's slot count), so the rotations wrap
+    /// modulo `n`, and the result is `M · v`, only when `n` equals that row
+    /// width. `galois_keys` must cover the rotation steps `{b1}` and `{b2·n1}`.
+    pub fn matrix_vector_mul(
+        &self,
+        encoder: &BGVEncoder,
+        matrix: &[Vec<i64>],
+        v: &Ciphertext,
+        n1: usize,
+        galois_keys: &GaloisKey,
+    ) -> Result<Ciphertext> {
+        let n = matrix.len();
+        assert!(n1 != 0 && n % n1 == 0, "n1 must divide the matrix dimension");
+        let n2 = n / n1;
+
+        // Baby steps: the n1 inner rotations of v, hoisted and reused across
+        // every giant step.
+        let steps: Vec<i32> = (0..n1).map(|b1| i32::try_from(b1).unwrap()).collect();
+        let baby = self.rotate_rows_many(v, &steps, galois_keys)?;
+
+        let mut acc: Option<Ciphertext> = None;
+        for b2 in 0..n2 {
+            let outer = b2 * n1;
+            let mut inner: Option<Ciphertext> = None;
+            for (b1, baby_step) in baby.iter().enumerate() {
+                let d = outer + b1;
+                let diag: Vec<i64> = (0..n).map(|i| matrix[i][(i + d) % n]).collect();
+                // Pre-rotate by −outer so the giant-step rotation realigns it.
+                let diag = Self::rotate_slots_left(&diag, n - outer % n);
+                let plain = encoder.encode_i64(&diag)?;
+                let term = self.multiply_plain(baby_step, &plain)?;
+                inner = Some(match inner {
+                    Some(sum) => self.add(&sum, &term)?,
+                    None => term,
+                });
+            }
+            let inner = inner.expect("n1 ≥ 1 guarantees at least one baby step");
+            let rotated = self.rotate_rows(&inner, i32::try_from(outer).unwrap(), galois_keys)?;
+            acc = Some(match acc {
+                Some(sum) => self.add(&sum, &rotated)?,
+                None => rotated,
+            });
+        }
+
+        Ok(acc.expect("n2 ≥ 1 guarantees at least one giant step"))
+    }
+
+    /// Combines the two batching rows of a BGV matrix–vector product.
+    ///
+    /// When `M` mixes both halves of the SIMD layout, each half is handled by
+    /// [`matrix_vector_mul`](Self::matrix_vector_mul) on its own row and the two
+    /// partial products are folded together with a single
+    /// [`rotate_columns`](Evaluator::rotate_columns).
+    pub fn matrix_vector_mul_columns(
+        &self,
+        lower: &Ciphertext,
+        upper: &Ciphertext,
+        galois_keys: &GaloisKey,
+    ) -> Result<Ciphertext> {
+        self.add(lower, &self.rotate_columns(upper, galois_keys)?)
+    }
+
+    /// Transforms a ciphertext into NTT form in place.
+    ///
+    /// In NTT form, [`multiply_plain_ntt`](Self::multiply_plain_ntt) skips the
+    /// forward transform on every call, which pays off when the same ciphertext
+    /// is multiplied against many fixed plaintexts (e.g. a linear layer). The
+    /// coefficient-domain add/sub paths expect a non-NTT ciphertext, so transform
+    /// back with [`transform_from_ntt_inplace`](Self::transform_from_ntt_inplace)
+    /// before mixing the two.
+    pub fn transform_to_ntt_inplace(&self, a: &Ciphertext) -> Result<()> {
+        self.base.transform_to_ntt_inplace(a)
+    }
+
+    /// Transforms a ciphertext back from NTT form to the coefficient domain in
+    /// place.
+    pub fn transform_from_ntt_inplace(&self, a: &Ciphertext) -> Result<()> {
+        self.base.transform_from_ntt_inplace(a)
+    }
+
+    /// Transforms a plaintext into NTT form at the given `parms_id`, so it can be
+    /// reused across many [`multiply_plain_ntt`](Self::multiply_plain_ntt) calls
+    /// without re-transforming. `parms_id` must match the level of the
+    /// ciphertexts it will multiply.
+    pub fn transform_to_ntt_plaintext(
+        &self,
+        a: &Plaintext,
+        parms_id: &[u64],
+    ) -> Result<Plaintext> {
+        self.base.transform_to_ntt_plaintext(a, parms_id)
+    }
+
+    /// Transforms a plaintext back from NTT form to the coefficient domain.
+    pub fn transform_from_ntt_plaintext(&self, a: &Plaintext) -> Result<Plaintext> {
+        self.base.transform_from_ntt_plaintext(a)
+    }
+
+    /// Multiplies an NTT-form ciphertext by an NTT-form plaintext, leaving the
+    /// product in NTT form.
+    ///
+    /// Both operands must already be in NTT form (see
+    /// [`transform_to_ntt_inplace`](Self::transform_to_ntt_inplace) and
+    /// [`transform_to_ntt_plaintext`](Self::transform_to_ntt_plaintext));
+    /// otherwise this returns [`Error::Unexpected`]. The result stays in NTT form
+    /// until explicitly transformed back, so several plain-multiply-accumulate
+    /// steps can be chained before paying a single inverse NTT.
+    pub fn multiply_plain_ntt(&self, a: &Ciphertext, b: &Plaintext) -> Result<Ciphertext> {
+        if !a.is_ntt_form() || !b.is_ntt_form() {
+            return Err(Error::Unexpected);
+        }
+        self.base.multiply_plain(a, b)
+    }
+
+    /// Switches a ciphertext directly to the chain level identified by
+    /// `parms_id`, rather than stepping there with repeated
+    /// [`mod_switch_to_next`](Evaluator::mod_switch_to_next) calls.
+    ///
+    /// This is the tool for aligning two operands that sit at different chain
+    /// positions before an add or multiply: bring the higher-level operand down
+    /// to the other's `parms_id`. Obtain a valid `parms_id` by walking the chain
+    /// from the [`Context`] (see [`Context::first_context_data`] and
+    /// [`ContextData::next_context_data`]). The call errors if `parms_id` names
+    /// a level above the ciphertext's current level in the chain.
+    ///
+    /// [`Context::first_context_data`]: crate::Context::first_context_data
+    /// [`ContextData::next_context_data`]: crate::ContextData::next_context_data
+    pub fn mod_switch_to(&self, a: &Ciphertext, parms_id: &[u64]) -> Result<Ciphertext> {
+        self.base.mod_switch_to(a, parms_id)
+    }
+
+    /// In-place variant of [`mod_switch_to`](Self::mod_switch_to).
+    pub fn mod_switch_to_inplace(&self, a: &Ciphertext, parms_id: &[u64]) -> Result<()> {
+        self.base.mod_switch_to_inplace(a, parms_id)
+    }
+
+    /// Switches a plaintext to the chain level identified by `parms_id`, e.g. to
+    /// match a ciphertext it will be multiplied into.
+    pub fn mod_switch_to_plaintext(&self, a: &Plaintext, parms_id: &[u64]) -> Result<Plaintext> {
+        self.base.mod_switch_to_plaintext(a, parms_id)
+    }
+
+    /// In-place variant of [`mod_switch_to_plaintext`](Self::mod_switch_to_plaintext).
+    pub fn mod_switch_to_inplace_plaintext(&self, a: &Plaintext, parms_id: &[u64]) -> Result<()> {
+        self.base.mod_switch_to_inplace_plaintext(a, parms_id)
     }
 }
 
@@ -30,23 +371,23 @@ impl Evaluator for BGVEvaluator {
     type Ciphertext = Ciphertext;
 
     fn negate_inplace(&self, a: &mut Ciphertext) -> Result<()> {
-        self.0.negate_inplace(a)
+        self.base.negate_inplace(a)
     }
 
     fn negate(&self, a: &Ciphertext) -> Result<Ciphertext> {
-        self.0.negate(a)
+        self.base.negate(a)
     }
 
     fn add_inplace(&self, a: &mut Ciphertext, b: &Ciphertext) -> Result<()> {
-        self.0.add_inplace(a, b)
+        self.base.add_inplace(a, b)
     }
 
     fn add(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
-        self.0.add(a, b)
+        self.base.add(a, b)
     }
 
     fn add_many(&self, a: &[Ciphertext]) -> Result<Ciphertext> {
-        self.0.add_many(a)
+        self.base.add_many(a)
     }
 
     fn multiply_many(
@@ -54,47 +395,47 @@ impl Evaluator for BGVEvaluator {
         a: &[Ciphertext],
         relin_keys: &RelinearizationKey,
     ) -> Result<Ciphertext> {
-        self.0.multiply_many(a, relin_keys)
+        self.base.multiply_many(a, relin_keys)
     }
 
     fn sub_inplace(&self, a: &mut Ciphertext, b: &Ciphertext) -> Result<()> {
-        self.0.sub_inplace(a, b)
+        self.base.sub_inplace(a, b)
     }
 
     fn sub(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
-        self.0.sub(a, b)
+        self.base.sub(a, b)
     }
 
     fn multiply_inplace(&self, a: &mut Ciphertext, b: &Ciphertext) -> Result<()> {
-        self.0.multiply_inplace(a, b)
+        self.base.multiply_inplace(a, b)
     }
 
     fn multiply(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
-        self.0.multiply(a, b)
+        self.base.multiply(a, b)
     }
 
     fn square_inplace(&self, a: &mut Ciphertext) -> Result<()> {
-        self.0.square_inplace(a)
+        self.base.square_inplace(a)
     }
 
     fn square(&self, a: &Ciphertext) -> Result<Ciphertext> {
-        self.0.square(a)
+        self.base.square(a)
     }
 
     fn mod_switch_to_next(&self, a: &Ciphertext) -> Result<Ciphertext> {
-        self.0.mod_switch_to_next(a)
+        self.base.mod_switch_to_next(a)
     }
 
     fn mod_switch_to_next_inplace(&self, a: &Ciphertext) -> Result<()> {
-        self.0.mod_switch_to_next_inplace(a)
+        self.base.mod_switch_to_next_inplace(a)
     }
 
     fn mod_switch_to_next_plaintext(&self, a: &Plaintext) -> Result<Plaintext> {
-        self.0.mod_switch_to_next_plaintext(a)
+        self.base.mod_switch_to_next_plaintext(a)
     }
 
     fn mod_switch_to_next_inplace_plaintext(&self, a: &Plaintext) -> Result<()> {
-        self.0.mod_switch_to_next_inplace_plaintext(a)
+        self.base.mod_switch_to_next_inplace_plaintext(a)
     }
 
     fn exponentiate(
@@ -103,7 +444,7 @@ impl Evaluator for BGVEvaluator {
         exponent: u64,
         relin_keys: &RelinearizationKey,
     ) -> Result<Ciphertext> {
-        self.0.exponentiate(a, exponent, relin_keys)
+        self.base.exponentiate(a, exponent, relin_keys)
     }
 
     fn exponentiate_inplace(
@@ -112,31 +453,31 @@ impl Evaluator for BGVEvaluator {
         exponent: u64,
         relin_keys: &RelinearizationKey,
     ) -> Result<()> {
-        self.0.exponentiate_inplace(a, exponent, relin_keys)
+        self.base.exponentiate_inplace(a, exponent, relin_keys)
     }
 
     fn add_plain(&self, a: &Ciphertext, b: &Plaintext) -> Result<Ciphertext> {
-        self.0.add_plain(a, b)
+        self.base.add_plain(a, b)
     }
 
     fn add_plain_inplace(&self, a: &mut Ciphertext, b: &Plaintext) -> Result<()> {
-        self.0.add_plain_inplace(a, b)
+        self.base.add_plain_inplace(a, b)
     }
 
     fn sub_plain(&self, a: &Ciphertext, b: &Plaintext) -> Result<Ciphertext> {
-        self.0.sub_plain(a, b)
+        self.base.sub_plain(a, b)
     }
 
     fn sub_plain_inplace(&self, a: &mut Ciphertext, b: &Plaintext) -> Result<()> {
-        self.0.sub_plain_inplace(a, b)
+        self.base.sub_plain_inplace(a, b)
     }
 
     fn multiply_plain(&self, a: &Ciphertext, b: &Plaintext) -> Result<Ciphertext> {
-        self.0.multiply_plain(a, b)
+        self.base.multiply_plain(a, b)
     }
 
     fn multiply_plain_inplace(&self, a: &mut Ciphertext, b: &Plaintext) -> Result<()> {
-        self.0.multiply_plain_inplace(a, b)
+        self.base.multiply_plain_inplace(a, b)
     }
 
     fn relinearize_inplace(
@@ -884,4 +1225,107 @@ mod tests {
             assert_eq!(a[4097], c[1]);
         });
     }
+
+    #[test]
+    fn rotate_and_sum_replicates_total() {
+        run_bgv_test(|decryptor, encoder, encryptor, evaluator, keygen| {
+            let galois_keys = keygen.create_galois_keys().unwrap();
+
+            let slots = encoder.get_slot_count();
+            let a = vec![1i64; slots];
+            let a_p = encoder.encode_i64(&a).unwrap();
+            let a_c = encryptor.encrypt(&a_p).unwrap();
+
+            let c_c = evaluator.rotate_and_sum(&a_c, &galois_keys).unwrap();
+
+            let c_p = decryptor.decrypt(&c_c).unwrap();
+            let c: Vec<i64> = encoder.decode_i64(&c_p).unwrap();
+
+            for slot in c {
+                assert_eq!(slot, slots as i64);
+            }
+        });
+    }
+
+    #[test]
+    fn inner_product_replicates_dot_product() {
+        run_bgv_test(|decryptor, encoder, encryptor, evaluator, keygen| {
+            let relin_keys = keygen.create_relinearization_keys().unwrap();
+            let galois_keys = keygen.create_galois_keys().unwrap();
+
+            let slots = encoder.get_slot_count();
+            let a = vec![2i64; slots];
+            let b = vec![3i64; slots];
+            let a_c = encryptor.encrypt(&encoder.encode_i64(&a).unwrap()).unwrap();
+            let b_c = encryptor.encrypt(&encoder.encode_i64(&b).unwrap()).unwrap();
+
+            let c_c = evaluator
+                .inner_product(&a_c, &b_c, &relin_keys, &galois_keys)
+                .unwrap();
+
+            let c_p = decryptor.decrypt(&c_c).unwrap();
+            let c: Vec<i64> = encoder.decode_i64(&c_p).unwrap();
+
+            for slot in c {
+                assert_eq!(slot, 6 * slots as i64);
+            }
+        });
+    }
+
+    #[test]
+    fn rotate_rows_many_matches_individual_rotations() {
+        run_bgv_test(|decryptor, encoder, encryptor, evaluator, keygen| {
+            let galois_keys = keygen.create_galois_keys().unwrap();
+
+            let a = make_matrix(&encoder);
+            let a_c = encryptor.encrypt(&encoder.encode_i64(&a).unwrap()).unwrap();
+
+            let steps = [1i32, 2, 4];
+            let many = evaluator
+                .rotate_rows_many(&a_c, &steps, &galois_keys)
+                .unwrap();
+
+            assert_eq!(many.len(), steps.len());
+            for (rotated, &step) in many.iter().zip(steps.iter()) {
+                let one = evaluator.rotate_rows(&a_c, step, &galois_keys).unwrap();
+                let got: Vec<i64> = encoder.decode_i64(&decryptor.decrypt(rotated).unwrap()).unwrap();
+                let want: Vec<i64> = encoder.decode_i64(&decryptor.decrypt(&one).unwrap()).unwrap();
+                assert_eq!(got, want);
+            }
+        });
+    }
+
+    #[test]
+    fn multiply_plain_ntt_rejects_coefficient_domain_operands() {
+        run_bgv_test(|_, encoder, encryptor, evaluator, _| {
+            let a = make_vec(&encoder);
+            let a_p = encoder.encode_i64(&a).unwrap();
+            let a_c = encryptor.encrypt(&a_p).unwrap();
+
+            // Neither operand has been transformed to NTT form yet.
+            assert!(evaluator.multiply_plain_ntt(&a_c, &a_p).is_err());
+        });
+    }
+
+    #[test]
+    fn expand_yields_two_pow_log_n_ciphertexts() {
+        run_bgv_test(|_, encoder, encryptor, evaluator, keygen| {
+            let n = encoder.get_slot_count() * 2; // poly modulus degree N
+            let log_n = 3usize;
+
+            // Galois keys for exactly the exponents the expansion visits.
+            let elts: Vec<u32> = (0..log_n)
+                .map(|r| u32::try_from(n / (1usize << r) + 1).unwrap())
+                .collect();
+            let galois_keys = keygen.create_galois_keys_from_elts(&elts).unwrap();
+
+            let a = make_vec(&encoder);
+            let a_p = encoder.encode_i64(&a).unwrap();
+            let a_c = encryptor.encrypt(&a_p).unwrap();
+
+            let expanded = evaluator.expand(&a_c, log_n, &galois_keys).unwrap();
+
+            assert_eq!(expanded.len(), 1usize << log_n);
+        });
+    }
 }
@@ -145,6 +145,19 @@ impl KeyGenerator {
         Ok(CompactGaloisKeys(self.create_galois_keys_internal(true)?))
     }
 
+    /// Generates Galois keys in a seed-compressed form.
+    ///
+    /// Galois keys are dominated by pseudorandom polynomials that can be
+    /// regenerated from the PRNG seed, so the compressed form stores only the
+    /// seed plus the secret-dependent parts — roughly halving the serialized
+    /// size for large polynomial moduli such as `D8192` and making key transport
+    /// over a network practical. The result is not directly usable for
+    /// evaluation; call [`CompressedGaloisKeys::decompress`] (or serialize and
+    /// reload) to expand it into a full [`GaloisKey`].
+    pub fn create_galois_keys_compressed(&self) -> Result<CompressedGaloisKeys> {
+        Ok(CompressedGaloisKeys(self.create_galois_keys_internal(true)?))
+    }
+
     /// Generates Galois keys and stores the result in destination.
     ///
     /// # Remarks
@@ -168,6 +181,68 @@ impl KeyGenerator {
 
         Ok(GaloisKey { handle })
     }
+
+    /// Generates Galois keys for the given rotation steps only.
+    ///
+    /// Unlike [`create_galois_keys`](Self::create_galois_keys), which materializes
+    /// the logarithmically-many keys needed for any automorphism, this generates
+    /// and stores just the keys required for the requested rotations. For large
+    /// polynomial moduli this is a substantial memory and key-generation-time win
+    /// when only a handful of rotations are ever applied (e.g. encrypted inner
+    /// products). A positive step rotates left, a negative step rotates right.
+    pub fn create_galois_keys_from_steps(&self, steps: &[i32]) -> Result<GaloisKey> {
+        self.create_galois_keys_from_steps_internal(steps, false)
+    }
+
+    /// Generates Galois keys for an explicit set of Galois automorphism elements.
+    ///
+    /// This is the power-of-the-element variant of
+    /// [`create_galois_keys_from_steps`](Self::create_galois_keys_from_steps):
+    /// callers who already know the Galois elements they need can pass them
+    /// directly instead of going through rotation steps.
+    pub fn create_galois_keys_from_elts(&self, galois_elts: &[u32]) -> Result<GaloisKey> {
+        self.create_galois_keys_from_elts_internal(galois_elts, false)
+    }
+
+    fn create_galois_keys_from_steps_internal(
+        &self,
+        steps: &[i32],
+        save_seed: bool,
+    ) -> Result<GaloisKey> {
+        let mut handle = null_mut();
+
+        try_seal!(unsafe {
+            bindgen::KeyGenerator_CreateGaloisKeysFromSteps(
+                self.get_handle(),
+                u64::try_from(steps.len()).unwrap(),
+                steps.as_ptr().cast_mut(),
+                save_seed,
+                &mut handle,
+            )
+        })?;
+
+        Ok(GaloisKey { handle })
+    }
+
+    fn create_galois_keys_from_elts_internal(
+        &self,
+        galois_elts: &[u32],
+        save_seed: bool,
+    ) -> Result<GaloisKey> {
+        let mut handle = null_mut();
+
+        try_seal!(unsafe {
+            bindgen::KeyGenerator_CreateGaloisKeysFromElts(
+                self.get_handle(),
+                u64::try_from(galois_elts.len()).unwrap(),
+                galois_elts.as_ptr().cast_mut(),
+                save_seed,
+                &mut handle,
+            )
+        })?;
+
+        Ok(GaloisKey { handle })
+    }
 }
 
 impl Drop for KeyGenerator {
@@ -394,6 +469,45 @@ impl Serialize for SecretKey {
     }
 }
 
+impl Serialize for PublicKey {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let data = self
+            .as_bytes()
+            .map_err(|e| S::Error::custom(format!("Failed to get public key bytes: {e}")))?;
+
+        serializer.serialize_bytes(&data)
+    }
+}
+
+impl Serialize for RelinearizationKey {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let data = self
+            .as_bytes()
+            .map_err(|e| S::Error::custom(format!("Failed to get relinearization key bytes: {e}")))?;
+
+        serializer.serialize_bytes(&data)
+    }
+}
+
+impl Serialize for GaloisKey {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let data = self
+            .as_bytes()
+            .map_err(|e| S::Error::custom(format!("Failed to get Galois key bytes: {e}")))?;
+
+        serializer.serialize_bytes(&data)
+    }
+}
+
 impl Clone for SecretKey {
     fn clone(&self) -> Self {
         let mut handle: *mut c_void = null_mut();
@@ -717,6 +831,31 @@ impl CompactGaloisKeys {
     }
 }
 
+/// A seed-compressed Galois key set.
+///
+/// Only the PRNG seed and the secret-dependent parts of the key are retained;
+/// the bulk pseudorandom polynomials are regenerated on
+/// [`decompress`](Self::decompress). Like SEAL's other seeded key forms this is
+/// not usable for evaluation until expanded, but it serializes to roughly half
+/// the size of a full [`GaloisKey`].
+#[derive(PartialEq)]
+pub struct CompressedGaloisKeys(GaloisKey);
+
+impl CompressedGaloisKeys {
+    /// Returns the compressed key as a byte array.
+    pub fn as_bytes(&self) -> Result<Vec<u8>> {
+        self.0.as_bytes()
+    }
+
+    /// Expands the compressed key into a full, directly-usable [`GaloisKey`].
+    ///
+    /// The pseudorandom polynomials are regenerated from the stored seed under
+    /// the supplied `context`.
+    pub fn decompress(&self, context: &Context) -> Result<GaloisKey> {
+        GaloisKey::from_bytes(context, &self.0.as_bytes()?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -789,6 +928,23 @@ mod tests {
         key_gen.create_galois_keys().unwrap();
     }
 
+    #[test]
+    fn can_create_galois_key_from_steps() {
+        let params = BFVEncryptionParametersBuilder::new()
+            .set_poly_modulus_degree(DegreeType::D8192)
+            .set_coefficient_modulus(
+                CoefficientModulusFactory::bfv(DegreeType::D8192, SecurityLevel::TC128).unwrap(),
+            )
+            .set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 32).unwrap())
+            .build()
+            .unwrap();
+
+        let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+        let key_gen = KeyGenerator::new(&ctx).unwrap();
+
+        key_gen.create_galois_keys_from_steps(&[1, 2, -1, -2]).unwrap();
+    }
+
     #[test]
     fn can_init_from_existing_secret_key() {
         let params = BFVEncryptionParametersBuilder::new()
@@ -0,0 +1,234 @@
+//! Password-protected, at-rest serialization for secret keys.
+//!
+//! [`SecretKey`] exposes its raw SEAL bytes through [`ToBytes`], but those bytes
+//! are the plaintext of the most sensitive object in the system. A *keystore*
+//! wraps them in an encrypted JSON envelope — modelled on the wallet keystore
+//! used by the MultiversX SDK — so a secret key can be written to disk or
+//! shipped over a channel without ever exposing the key material in the clear.
+//!
+//! The envelope stores the KDF used to stretch the password ([`Kdf::Scrypt`] or
+//! [`Kdf::Pbkdf2`]), a random `iv`, the key bytes encrypted under AES-128-CTR
+//! with the first 16 bytes of the derived key, and a `mac` computed as a
+//! Keccak-256 hash over the last 16 bytes of the derived key concatenated with
+//! the ciphertext. On load the key is re-derived from the password and the
+//! stored KDF parameters, the MAC is verified *before* decrypting, and a MAC
+//! mismatch (a wrong password, or altered ciphertext or MAC bytes) is reported
+//! distinctly from an envelope that cannot be parsed.
+
+use std::io::{Read, Write};
+
+use aes::Aes128;
+use aes::cipher::{KeyIvInit, StreamCipher};
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use thiserror::Error;
+
+use crate::{Context, FromBytes, SecretKey, ToBytes};
+
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
+
+/// Errors raised while reading or writing an encrypted keystore.
+#[derive(Error, Debug)]
+pub enum KeystoreError {
+    /// The underlying I/O stream failed.
+    #[error("keystore I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The JSON envelope could not be parsed or was structurally invalid.
+    #[error("malformed keystore: {0}")]
+    Malformed(String),
+    /// The MAC did not match — almost always a wrong password.
+    #[error("keystore MAC mismatch (wrong password or corrupted data)")]
+    MacMismatch,
+    /// The wrapped secret key could not be serialized or reconstructed.
+    #[error("secret key error: {0}")]
+    Key(#[from] crate::Error),
+}
+
+type Result<T> = std::result::Result<T, KeystoreError>;
+
+/// Password-stretching function used to derive the AES/MAC key.
+#[derive(Debug, Clone, Copy)]
+pub enum Kdf {
+    /// Memory-hard scrypt with the given cost parameters.
+    Scrypt {
+        /// Base-2 logarithm of the CPU/memory cost `n`.
+        log_n: u8,
+        /// Block size.
+        r: u32,
+        /// Parallelization.
+        p: u32,
+    },
+    /// PBKDF2 with HMAC-SHA256 and the given iteration count.
+    Pbkdf2 {
+        /// Iteration count.
+        c: u32,
+    },
+}
+
+impl Default for Kdf {
+    /// Interactive-strength scrypt parameters (n = 2^18, r = 8, p = 1).
+    fn default() -> Self {
+        Self::Scrypt { log_n: 18, r: 8, p: 1 }
+    }
+}
+
+const DKLEN: usize = 32;
+
+/// Serialized KDF descriptor (flattened into the envelope).
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kdf", content = "kdfparams")]
+enum KdfParams {
+    #[serde(rename = "scrypt")]
+    Scrypt { n: u32, r: u32, p: u32, dklen: usize, salt: String },
+    #[serde(rename = "pbkdf2-hmac-sha256")]
+    Pbkdf2 { c: u32, dklen: usize, salt: String },
+}
+
+#[derive(Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+/// The on-disk keystore envelope.
+#[derive(Serialize, Deserialize)]
+struct Keystore {
+    version: u32,
+    #[serde(flatten)]
+    kdf: KdfParams,
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    mac: String,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        s.push_str(&format!("{b:02x}"));
+    }
+    s
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return Err(KeystoreError::Malformed("odd-length hex field".into()));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&s[i..i + 2], 16)
+                .map_err(|_| KeystoreError::Malformed("invalid hex digit".into()))
+        })
+        .collect()
+}
+
+impl KdfParams {
+    /// Derives a `DKLEN`-byte key from `password` using the stored parameters.
+    fn derive(&self, password: &[u8]) -> Result<[u8; DKLEN]> {
+        let mut dk = [0u8; DKLEN];
+        match self {
+            Self::Scrypt { n, r, p, salt, .. } => {
+                let salt = from_hex(salt)?;
+                let log_n = u8::try_from(n.trailing_zeros())
+                    .map_err(|_| KeystoreError::Malformed("invalid scrypt n".into()))?;
+                let params = scrypt::Params::new(log_n, *r, *p, DKLEN)
+                    .map_err(|e| KeystoreError::Malformed(format!("invalid scrypt params: {e}")))?;
+                scrypt::scrypt(password, &salt, &params, &mut dk)
+                    .map_err(|e| KeystoreError::Malformed(format!("scrypt failed: {e}")))?;
+            }
+            Self::Pbkdf2 { c, salt, .. } => {
+                let salt = from_hex(salt)?;
+                pbkdf2_hmac::<Sha256>(password, &salt, *c, &mut dk);
+            }
+        }
+        Ok(dk)
+    }
+}
+
+/// Keccak-256 MAC over the last 16 bytes of the derived key and the ciphertext.
+fn compute_mac(derived: &[u8; DKLEN], ciphertext: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(&derived[16..]);
+    hasher.update(ciphertext);
+    hasher.finalize().into()
+}
+
+impl SecretKey {
+    /// Encrypts this secret key under `password` and writes a JSON keystore.
+    ///
+    /// Uses the default [`Kdf`] (interactive-strength scrypt); see
+    /// [`to_keystore_with`](Self::to_keystore_with) to choose the KDF.
+    pub fn to_keystore<W: Write>(&self, password: &str, writer: W) -> Result<()> {
+        self.to_keystore_with(password, Kdf::default(), writer)
+    }
+
+    /// Encrypts this secret key under `password` using the given `kdf`.
+    pub fn to_keystore_with<W: Write>(&self, password: &str, kdf: Kdf, writer: W) -> Result<()> {
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; 32];
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut salt);
+        rng.fill_bytes(&mut iv);
+
+        let params = match kdf {
+            Kdf::Scrypt { log_n, r, p } => KdfParams::Scrypt {
+                n: 1u32 << log_n,
+                r,
+                p,
+                dklen: DKLEN,
+                salt: to_hex(&salt),
+            },
+            Kdf::Pbkdf2 { c } => KdfParams::Pbkdf2 { c, dklen: DKLEN, salt: to_hex(&salt) },
+        };
+
+        let derived = params.derive(password.as_bytes())?;
+
+        let mut ciphertext = self.as_bytes()?;
+        let mut cipher = Aes128Ctr::new(derived[..16].into(), (&iv).into());
+        cipher.apply_keystream(&mut ciphertext);
+
+        let mac = compute_mac(&derived, &ciphertext);
+
+        let keystore = Keystore {
+            version: 4,
+            kdf: params,
+            cipher: "aes-128-ctr".into(),
+            cipherparams: CipherParams { iv: to_hex(&iv) },
+            ciphertext: to_hex(&ciphertext),
+            mac: to_hex(&mac),
+        };
+
+        serde_json::to_writer(writer, &keystore)
+            .map_err(|e| KeystoreError::Malformed(e.to_string()))
+    }
+
+    /// Loads and decrypts a secret key written by [`to_keystore`](Self::to_keystore).
+    ///
+    /// Re-derives the key from `password` and the stored KDF parameters, verifies
+    /// the MAC before decrypting, and returns [`KeystoreError::MacMismatch`] on a
+    /// wrong password or altered ciphertext as distinct from
+    /// [`KeystoreError::Malformed`] on an envelope that cannot be parsed.
+    pub fn from_keystore<R: Read>(context: &Context, password: &str, reader: R) -> Result<Self> {
+        let keystore: Keystore =
+            serde_json::from_reader(reader).map_err(|e| KeystoreError::Malformed(e.to_string()))?;
+
+        let derived = keystore.kdf.derive(password.as_bytes())?;
+        let ciphertext = from_hex(&keystore.ciphertext)?;
+        let expected_mac = from_hex(&keystore.mac)?;
+
+        let mac = compute_mac(&derived, &ciphertext);
+        if mac.as_slice() != expected_mac.as_slice() {
+            return Err(KeystoreError::MacMismatch);
+        }
+
+        let iv = from_hex(&keystore.cipherparams.iv)?;
+        let mut plaintext = ciphertext;
+        let mut cipher = Aes128Ctr::new(derived[..16].into(), iv.as_slice().into());
+        cipher.apply_keystream(&mut plaintext);
+
+        Ok(Self::from_bytes(context, &plaintext)?)
+    }
+}
@@ -34,15 +34,17 @@ mod error;
 mod evaluator;
 mod ext;
 mod key_generator;
+mod keystore;
 mod memory;
 mod modulus;
 mod parameters;
 mod plaintext;
+mod secure;
 mod serialization;
 
 pub use ciphertext::Ciphertext;
 pub use components::{Asym, Sym, SymAsym, marker as component_marker};
-pub use context::Context;
+pub use context::{Context, ContextData};
 pub use decryptor::Decryptor;
 pub use encoder::bfv::BFVEncoder;
 pub use encoder::bgv::BGVEncoder;
@@ -58,10 +60,12 @@ pub use ext::tensor::{
     encryptor::TensorEncryptor, evaluator::TensorEvaluator,
 };
 pub use key_generator::{GaloisKey, KeyGenerator, PublicKey, RelinearizationKey, SecretKey};
+pub use keystore::{Kdf, KeystoreError};
 pub use memory::MemoryPool;
 pub use modulus::{
     CoefficientModulusFactory, DegreeType, Modulus, PlainModulusFactory, SecurityLevel,
 };
 pub use parameters::*;
 pub use plaintext::Plaintext;
+pub use secure::{Scrub, SecretBox};
 pub use serialization::{FromBytes, ToBytes};
@@ -183,6 +183,21 @@ impl Clone for Modulus {
     }
 }
 
+impl Serialize for Modulus {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        // A modulus is fully determined by its 61-bit value; the Barrett
+        // pre-computation is rebuilt from it on the way back in.
+        serializer.serialize_u64(self.value())
+    }
+}
+
+impl<'de> Deserialize<'de> for Modulus {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
 /// This struct contains static methods for creating a coefficient modulus easily.
 ///
 /// Note that while these functions take a SecLevelType argument, all security
@@ -7,7 +7,7 @@ use crate::bindgen::{self};
 use crate::error::Result;
 use crate::error::convert_seal_error;
 use crate::serialization::CompressionType;
-use crate::{FromBytes, Modulus, ToBytes, try_seal};
+use crate::{DegreeType, FromBytes, Modulus, SecurityLevel, ToBytes, try_seal};
 
 use serde::{Deserialize, Serialize};
 
@@ -264,12 +264,21 @@ impl Drop for EncryptionParameters {
     }
 }
 
-impl ToBytes for EncryptionParameters {
-    fn as_bytes(&self) -> Result<Vec<u8>> {
+/// Magic prefix of a self-describing [`EncryptionParameters`] blob.
+const PARAMS_MAGIC: [u8; 4] = *b"SEPM";
+/// Version of the self-describing header layout.
+const PARAMS_FORMAT_VERSION: u8 = 1;
+/// Header length: magic (4) + version + scheme + compression tag.
+const PARAMS_HEADER_LEN: usize = PARAMS_MAGIC.len() + 3;
+
+impl EncryptionParameters {
+    /// Raw SEAL serialization under an explicit compression, without the
+    /// self-describing header.
+    fn save_raw(&self, compression: CompressionType) -> Result<Vec<u8>> {
         let mut num_bytes: i64 = 0;
 
         convert_seal_error(unsafe {
-            bindgen::EncParams_SaveSize(self.handle, CompressionType::ZStd as u8, &mut num_bytes)
+            bindgen::EncParams_SaveSize(self.handle, compression as u8, &mut num_bytes)
         })?;
 
         let mut data: Vec<u8> = Vec::with_capacity(usize::try_from(num_bytes).unwrap());
@@ -282,7 +291,7 @@ impl ToBytes for EncryptionParameters {
                 self.handle,
                 data_ptr,
                 u64::try_from(num_bytes).unwrap(),
-                CompressionType::ZStd as u8,
+                compression as u8,
                 &mut bytes_written,
             )
         })?;
@@ -291,19 +300,66 @@ impl ToBytes for EncryptionParameters {
 
         Ok(data)
     }
-}
 
-impl FromBytes for EncryptionParameters {
-    type State = SchemeType;
-    fn from_bytes(scheme: &SchemeType, bytes: &[u8]) -> Result<Self> {
-        let key = Self::new(*scheme)?;
+    /// Serializes with a caller-chosen compression and a self-describing header.
+    ///
+    /// The blob begins with [`PARAMS_MAGIC`], a format version, the
+    /// [`SchemeType`], and the compression tag, so
+    /// [`from_self_describing_bytes`](Self::from_self_describing_bytes) can
+    /// reconstruct the parameters with no out-of-band scheme knowledge — as the
+    /// networked handshake needs. [`as_bytes`](ToBytes::as_bytes) is the
+    /// [`CompressionType::ZStd`] special case of this.
+    pub fn as_bytes_with(&self, compression: CompressionType) -> Result<Vec<u8>> {
+        let payload = self.save_raw(compression)?;
+        let mut data = Vec::with_capacity(PARAMS_HEADER_LEN + payload.len());
+        data.extend_from_slice(&PARAMS_MAGIC);
+        data.push(PARAMS_FORMAT_VERSION);
+        data.push(self.get_scheme().to_u8());
+        data.push(compression as u8);
+        data.extend_from_slice(&payload);
+        Ok(data)
+    }
+
+    /// Deserializes a self-describing blob produced by
+    /// [`as_bytes_with`](Self::as_bytes_with), recovering the scheme from the
+    /// header without any external state.
+    ///
+    /// Headerless blobs (those written before the header existed) are accepted
+    /// as legacy [`CompressionType::ZStd`] data, but only when `fallback_scheme`
+    /// is supplied, since the scheme cannot be recovered from them.
+    pub fn from_self_describing_bytes(
+        bytes: &[u8],
+        fallback_scheme: Option<SchemeType>,
+    ) -> Result<Self> {
+        match Self::strip_header(bytes) {
+            Some((scheme, payload)) => Self::load_raw(scheme, payload),
+            None => {
+                let scheme = fallback_scheme.ok_or(crate::Error::Unexpected)?;
+                Self::load_raw(scheme, bytes)
+            }
+        }
+    }
+
+    /// Splits a self-describing blob into its embedded scheme and the SEAL
+    /// payload, returning `None` when the magic prefix is absent.
+    fn strip_header(bytes: &[u8]) -> Option<(SchemeType, &[u8])> {
+        if bytes.len() < PARAMS_HEADER_LEN || bytes[..PARAMS_MAGIC.len()] != PARAMS_MAGIC {
+            return None;
+        }
+        let scheme = SchemeType::from_u8(bytes[PARAMS_MAGIC.len() + 1]);
+        Some((scheme, &bytes[PARAMS_HEADER_LEN..]))
+    }
+
+    /// Loads a raw (headerless) SEAL payload into fresh parameters for `scheme`.
+    fn load_raw(scheme: SchemeType, payload: &[u8]) -> Result<Self> {
+        let key = Self::new(scheme)?;
         let mut bytes_read = 0;
 
         convert_seal_error(unsafe {
             bindgen::EncParams_Load(
                 key.handle,
-                bytes.as_ptr().cast_mut(),
-                u64::try_from(bytes.len()).unwrap(),
+                payload.as_ptr().cast_mut(),
+                u64::try_from(payload.len()).unwrap(),
                 &mut bytes_read,
             )
         })?;
@@ -311,3 +367,86 @@ impl FromBytes for EncryptionParameters {
         Ok(key)
     }
 }
+
+impl ToBytes for EncryptionParameters {
+    /// Self-describing [`CompressionType::ZStd`] serialization; see
+    /// [`as_bytes_with`](EncryptionParameters::as_bytes_with).
+    fn as_bytes(&self) -> Result<Vec<u8>> {
+        self.as_bytes_with(CompressionType::ZStd)
+    }
+}
+
+impl FromBytes for EncryptionParameters {
+    type State = SchemeType;
+    /// Loads either a self-describing blob (whose embedded scheme supersedes
+    /// `scheme`) or a legacy headerless one loaded under `scheme`.
+    fn from_bytes(scheme: &SchemeType, bytes: &[u8]) -> Result<Self> {
+        match Self::strip_header(bytes) {
+            Some((embedded, payload)) => Self::load_raw(embedded, payload),
+            None => Self::load_raw(*scheme, bytes),
+        }
+    }
+}
+
+/// A plain, `serde`-round-trippable description of an encryption parameter set.
+///
+/// Unlike [`EncryptionParameters`], which wraps an opaque SEAL handle, this
+/// captures the fields a key-generating service needs to hand to its clients —
+/// the scheme, polynomial degree, security level, and the exact coefficient and
+/// plain modulus chains — so the set can be persisted and reloaded verbatim
+/// instead of re-derived from the factories (whose output may drift between
+/// SEAL versions).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptionParameterSet {
+    /// The FHE scheme these parameters target.
+    pub scheme: SchemeType,
+    /// The polynomial modulus degree.
+    pub degree: DegreeType,
+    /// The security level the moduli were chosen for.
+    pub security_level: SecurityLevel,
+    /// The coefficient modulus chain.
+    pub coeff_modulus: Vec<Modulus>,
+    /// The plain modulus, absent for CKKS.
+    pub plain_modulus: Option<Modulus>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CoefficientModulusFactory;
+
+    fn sample_params() -> EncryptionParameters {
+        let modulus_chain =
+            CoefficientModulusFactory::build(DegreeType::D1024, &[60, 40, 40, 60]).unwrap();
+        CKKSEncryptionParametersBuilder::new()
+            .set_poly_modulus_degree(DegreeType::D1024)
+            .set_coefficient_modulus(modulus_chain)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn self_describing_round_trip_recovers_scheme() {
+        let params = sample_params();
+        let bytes = params.as_bytes_with(CompressionType::ZStd).unwrap();
+
+        // The header exposes the scheme without any external state.
+        assert_eq!(&bytes[..4], b"SEPM");
+        let restored = EncryptionParameters::from_self_describing_bytes(&bytes, None).unwrap();
+        assert_eq!(restored.get_scheme(), SchemeType::Ckks);
+        assert_eq!(restored.get_coefficient_modulus().len(), 4);
+    }
+
+    #[test]
+    fn legacy_headerless_blob_loads_as_zstd() {
+        let params = sample_params();
+        // A blob without the header is the legacy form and needs its scheme.
+        let legacy = params.save_raw(CompressionType::ZStd).unwrap();
+        assert_ne!(&legacy[..4], b"SEPM");
+
+        let restored =
+            EncryptionParameters::from_bytes(&SchemeType::Ckks, &legacy).unwrap();
+        assert_eq!(restored.get_coefficient_modulus().len(), 4);
+        assert!(EncryptionParameters::from_self_describing_bytes(&legacy, None).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{DegreeType, EncryptionParameters, Error, Modulus, SchemeType};
+
+use super::{CoefficientModulusType, PlainModulusType};
+
+/// Represents a builder that sets up and creates encryption scheme parameters
+/// for the BGV scheme.
+///
+/// BGV shares BFV's parameter surface — the same [`CoefficientModulusFactory`]
+/// and [`PlainModulusFactory`] chains apply — but tags the context scheme as
+/// BGV, whose cheaper modulus-switching noise management suits deep integer
+/// circuits. A single [`DegreeType::D8192`] + [`SecurityLevel::TC128`] set is
+/// usable for either scheme.
+///
+/// [`CoefficientModulusFactory`]: crate::CoefficientModulusFactory
+/// [`PlainModulusFactory`]: crate::PlainModulusFactory
+/// [`SecurityLevel::TC128`]: crate::SecurityLevel
+#[derive(Debug, PartialEq)]
+pub struct BGVEncryptionParametersBuilder {
+    poly_modulus_degree: Option<DegreeType>,
+    coefficient_modulus: CoefficientModulusType,
+    plain_modulus: PlainModulusType,
+}
+
+impl BGVEncryptionParametersBuilder {
+    /// Creates a new builder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            poly_modulus_degree: None,
+            coefficient_modulus: CoefficientModulusType::NotSet,
+            plain_modulus: PlainModulusType::NotSet,
+        }
+    }
+
+    /// Set the degree of the polynomial used in the BGV scheme. Generally,
+    /// larger values provide more security and noise margin at the expense
+    /// of performance.
+    #[must_use]
+    pub const fn set_poly_modulus_degree(mut self, degree: DegreeType) -> Self {
+        self.poly_modulus_degree = Some(degree);
+        self
+    }
+
+    /// Sets the coefficient modulus parameter. The coefficient modulus consists
+    /// of a list of distinct prime numbers, and is represented by a vector of
+    /// Modulus objects. The coefficient modulus directly affects the size
+    /// of ciphertext elements, the amount of computation that the scheme can
+    /// perform (bigger is better), and the security level (bigger is worse). In
+    /// Microsoft SEAL each of the prime numbers in the coefficient modulus must
+    /// be at most 60 bits, and must be congruent to 1 modulo 2*poly_modulus_degree.
+    #[must_use]
+    pub fn set_coefficient_modulus(mut self, modulus: Vec<Modulus>) -> Self {
+        self.coefficient_modulus = CoefficientModulusType::Modulus(modulus);
+        self
+    }
+
+    /// Sets the plain modulus as a [`Modulus`] instance. Batching-friendly plain
+    /// moduli are produced by the [`PlainModulusFactory`].
+    ///
+    /// [`PlainModulusFactory`]: crate::PlainModulusFactory
+    #[must_use]
+    pub fn set_plain_modulus(mut self, modulus: Modulus) -> Self {
+        self.plain_modulus = PlainModulusType::Modulus(modulus);
+        self
+    }
+
+    /// Sets the plain modulus as a constant.
+    #[must_use]
+    pub const fn set_plain_modulus_u64(mut self, modulus: u64) -> Self {
+        self.plain_modulus = PlainModulusType::Constant(modulus);
+        self
+    }
+
+    /// Validate the parameter choices and return the encryption parameters.
+    pub fn build(self) -> Result<EncryptionParameters, Error> {
+        let mut params = EncryptionParameters::new(SchemeType::Bgv)?;
+
+        match self.poly_modulus_degree {
+            Some(degree) => params.set_poly_modulus_degree(u64::from(degree))?,
+            None => return Err(Error::DegreeNotSet),
+        }
+
+        match self.coefficient_modulus {
+            CoefficientModulusType::NotSet => return Err(Error::CoefficientModulusNotSet),
+            CoefficientModulusType::Modulus(m) => {
+                params.set_coefficient_modulus(&m)?;
+            }
+        }
+
+        match self.plain_modulus {
+            PlainModulusType::NotSet => return Err(Error::PlainModulusNotSet),
+            PlainModulusType::Constant(c) => params.set_plain_modulus_u64(c)?,
+            PlainModulusType::Modulus(m) => params.set_plain_modulus(&m)?,
+        }
+
+        Ok(params)
+    }
+}
+
+impl Default for BGVEncryptionParametersBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn can_build_params() {
+        let params = BGVEncryptionParametersBuilder::new()
+            .set_poly_modulus_degree(DegreeType::D8192)
+            .set_coefficient_modulus(
+                CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+            )
+            .set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(params.get_poly_modulus_degree(), 8192);
+        assert_eq!(params.get_scheme(), SchemeType::Bgv);
+        assert_eq!(params.get_coefficient_modulus().len(), 5);
+    }
+}
@@ -160,6 +160,57 @@ impl Plaintext {
 
         result
     }
+
+    /// Returns the human-auditable polynomial string, of the form
+    /// "7FFx^3 + 1x^1 + 3" accepted by [`from_hex_string`](Self::from_hex_string).
+    pub fn to_hex_string(&self) -> Result<String> {
+        let mut length: u64 = 0;
+
+        // First call with a null buffer reports the required length.
+        try_seal!(unsafe {
+            bindgen::Plaintext_ToString(self.get_handle(), null_mut(), &mut length)
+        })?;
+
+        let mut buffer: Vec<u8> = vec![0u8; usize::try_from(length).unwrap() + 1];
+
+        try_seal!(unsafe {
+            bindgen::Plaintext_ToString(self.get_handle(), buffer.as_mut_ptr().cast(), &mut length)
+        })?;
+
+        buffer.truncate(usize::try_from(length).unwrap());
+
+        Ok(String::from_utf8_lossy(&buffer).into_owned())
+    }
+
+    /// Serializes the plaintext using the requested compression, the
+    /// form-selectable counterpart of [`as_bytes`](ToBytes::as_bytes) (which
+    /// always uses [`CompressionType::ZStd`]).
+    pub fn as_bytes_with(&self, compression: CompressionType) -> Result<Vec<u8>> {
+        let mut num_bytes: i64 = 0;
+
+        try_seal!(unsafe {
+            bindgen::Plaintext_SaveSize(self.get_handle(), compression as u8, &mut num_bytes)
+        })?;
+
+        let mut data: Vec<u8> = Vec::with_capacity(usize::try_from(num_bytes).unwrap());
+        let mut bytes_written: i64 = 0;
+
+        try_seal!(unsafe {
+            let data_ptr = data.as_mut_ptr();
+
+            bindgen::Plaintext_Save(
+                self.get_handle(),
+                data_ptr,
+                u64::try_from(num_bytes).unwrap(),
+                compression as u8,
+                &mut bytes_written,
+            )
+        })?;
+
+        unsafe { data.set_len(usize::try_from(bytes_written).unwrap()) };
+
+        Ok(data)
+    }
 }
 
 impl Debug for Plaintext {
@@ -264,34 +315,105 @@ impl FromBytes for Plaintext {
 
 impl ToBytes for Plaintext {
     fn as_bytes(&self) -> Result<Vec<u8>> {
-        let mut num_bytes: i64 = 0;
-
-        try_seal!(unsafe {
-            bindgen::Plaintext_SaveSize(
-                self.get_handle(),
-                CompressionType::ZStd as u8,
-                &mut num_bytes,
-            )
-        })?;
+        self.as_bytes_with(CompressionType::ZStd)
+    }
+}
 
-        let mut data: Vec<u8> = Vec::with_capacity(usize::try_from(num_bytes).unwrap());
-        let mut bytes_written: i64 = 0;
+/// `serde` adapter modules selecting the on-wire form of a [`Plaintext`] field.
+///
+/// Use them with `#[serde(serialize_with = "...")]` (or `#[serde(with = "...")]`
+/// for [`hex`], which also round-trips on deserialize):
+///
+/// ```ignore
+/// #[derive(Serialize)]
+/// struct Message {
+///     #[serde(serialize_with = "sealy::plaintext::serde::deflate::serialize")]
+///     body: Plaintext,
+/// }
+/// ```
+///
+/// The [`hex`] module uses the human-auditable polynomial string and is fully
+/// symmetric because [`Plaintext::from_hex_string`] needs no context. The byte
+/// modules emit the SEAL `Save` form under the named compression; their inbound
+/// path is [`FromBytes::from_bytes`], which requires a [`Context`] and so cannot
+/// be expressed as a `serde` `deserialize`.
+pub mod serde {
+    use super::{CompressionType, Plaintext, ToBytes};
+    use ::serde::de::Error as _;
+    use ::serde::ser::Error as _;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// Human-auditable hexadecimal polynomial form ("7FFx^3 + 1x^1 + 3").
+    pub mod hex {
+        use super::*;
+
+        /// Serializes the plaintext as its polynomial string.
+        pub fn serialize<S: Serializer>(
+            plaintext: &Plaintext,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let s = plaintext
+                .to_hex_string()
+                .map_err(|e| S::Error::custom(format!("failed to format plaintext: {e}")))?;
+            serializer.serialize_str(&s)
+        }
 
-        try_seal!(unsafe {
-            let data_ptr = data.as_mut_ptr();
+        /// Deserializes a plaintext from its polynomial string.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Plaintext, D::Error> {
+            let s = String::deserialize(deserializer)?;
+            Plaintext::from_hex_string(&s)
+                .map_err(|e| D::Error::custom(format!("failed to parse plaintext: {e}")))
+        }
+    }
 
-            bindgen::Plaintext_Save(
-                self.get_handle(),
-                data_ptr,
-                u64::try_from(num_bytes).unwrap(),
-                CompressionType::ZStd as u8,
-                &mut bytes_written,
-            )
-        })?;
+    /// Uncompressed SEAL byte form.
+    pub mod raw_bytes {
+        use super::*;
+
+        /// Serializes the plaintext as uncompressed SEAL bytes.
+        pub fn serialize<S: Serializer>(
+            plaintext: &Plaintext,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let bytes = plaintext
+                .as_bytes_with(CompressionType::None)
+                .map_err(|e| S::Error::custom(format!("failed to serialize plaintext: {e}")))?;
+            serializer.serialize_bytes(&bytes)
+        }
+    }
 
-        unsafe { data.set_len(usize::try_from(bytes_written).unwrap()) };
+    /// Zstandard-compressed SEAL byte form.
+    pub mod zstd {
+        use super::*;
+
+        /// Serializes the plaintext as Zstandard-compressed SEAL bytes.
+        pub fn serialize<S: Serializer>(
+            plaintext: &Plaintext,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let bytes = plaintext
+                .as_bytes_with(CompressionType::ZStd)
+                .map_err(|e| S::Error::custom(format!("failed to serialize plaintext: {e}")))?;
+            serializer.serialize_bytes(&bytes)
+        }
+    }
 
-        Ok(data)
+    /// Deflate-compressed SEAL byte form.
+    pub mod deflate {
+        use super::*;
+
+        /// Serializes the plaintext as deflate-compressed SEAL bytes.
+        pub fn serialize<S: Serializer>(
+            plaintext: &Plaintext,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let bytes = plaintext
+                .as_bytes_with(CompressionType::Deflate)
+                .map_err(|e| S::Error::custom(format!("failed to serialize plaintext: {e}")))?;
+            serializer.serialize_bytes(&bytes)
+        }
     }
 }
 
@@ -0,0 +1,86 @@
+use std::ffi::c_void;
+use std::ptr::null_mut;
+
+use crate::{Plaintext, SecretKey, bindgen, try_seal};
+
+/// Best-effort, in-place scrubbing of the sensitive buffer backing a value.
+///
+/// Implementors overwrite the coefficient memory that SEAL owns *before* the
+/// object is destroyed, so that a dropped key or plaintext no longer leaves
+/// recoverable secret data in the process heap. This is defense-in-depth: SEAL
+/// may keep internal copies in pool memory, but the primary buffer is cleared.
+pub trait Scrub {
+    /// Overwrites the backing buffer with zeros.
+    fn scrub(&mut self);
+}
+
+impl Scrub for Plaintext {
+    fn scrub(&mut self) {
+        for i in 0..self.len() {
+            self.set_coefficient(i, 0);
+        }
+    }
+}
+
+impl Scrub for SecretKey {
+    fn scrub(&mut self) {
+        // A SEAL secret key is stored as a `Plaintext`; zero its coefficients
+        // in place through the borrowed data handle (which we must not destroy,
+        // as it is owned by the key).
+        let mut data: *mut c_void = null_mut();
+        if try_seal!(unsafe { bindgen::SecretKey_Data(self.get_handle(), &mut data) }).is_err() {
+            return;
+        }
+
+        let mut count: u64 = 0;
+        if try_seal!(unsafe { bindgen::Plaintext_CoeffCount(data, &mut count) }).is_err() {
+            return;
+        }
+
+        for i in 0..count {
+            try_seal!(unsafe { bindgen::Plaintext_SetCoeffAt(data, i, 0) }).ok();
+        }
+    }
+}
+
+/// An access-controlled guard around secret material.
+///
+/// `SecretBox` deliberately implements neither `Clone` nor a revealing `Debug`,
+/// so the wrapped [`SecretKey`]/[`Plaintext`] cannot be accidentally copied or
+/// logged; access requires an explicit [`expose`](Self::expose) call. On drop
+/// the inner value is [`Scrub`]bed before its SEAL destructor runs.
+pub struct SecretBox<T: Scrub> {
+    inner: T,
+}
+
+impl<T: Scrub> SecretBox<T> {
+    /// Wraps `inner`, taking ownership of its secret material.
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a shared reference to the guarded value.
+    #[must_use]
+    pub const fn expose(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the guarded value.
+    #[must_use]
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: Scrub> Drop for SecretBox<T> {
+    fn drop(&mut self) {
+        self.inner.scrub();
+    }
+}
+
+impl<T: Scrub> core::fmt::Debug for SecretBox<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SecretBox").field("inner", &"<ELIDED>").finish()
+    }
+}
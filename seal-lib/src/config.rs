@@ -0,0 +1,184 @@
+//! Pluggable scheme/parameter configuration with a validation gate.
+//!
+//! Borrowing the HPKE `Config` pattern — a single struct that selects the
+//! primitives, exposes a `supported()` check and a sensible `Default` — this
+//! gathers every knob needed to stand up a backend (the [`Backend`], the
+//! [`DegreeType`], the [`SecurityLevel`], the BFV plaintext-modulus bit size and
+//! the CKKS scale) behind one [`build`](SchemeConfig::build) entry point.
+//!
+//! [`supported`](SchemeConfig::supported) encodes the valid parameter
+//! combinations so a misconfiguration is rejected *before* key generation
+//! instead of panicking inside an `unwrap()` deep in SEAL, and
+//! [`negotiate`] lets a client and server agree on a common configuration ahead
+//! of exchanging ciphertexts.
+
+use crate::context::{SealBFVContext, SealCkksContext};
+use crate::{DegreeType, SealBfvCS, SealCkksCS, SecurityLevel};
+use sealy::PlainModulusFactory;
+
+/// The FHE backend a [`SchemeConfig`] targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// The CKKS approximate-arithmetic backend.
+    Ckks,
+    /// The BFV exact-integer backend.
+    Bfv,
+}
+
+/// A fully-built cryptosystem, tagged by the backend it came from.
+pub enum BuiltScheme {
+    /// A CKKS cryptosystem.
+    Ckks(SealCkksCS),
+    /// A BFV cryptosystem.
+    Bfv(SealBfvCS),
+}
+
+/// Why a [`SchemeConfig`] could not be built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The parameter combination is not in the supported set.
+    Unsupported,
+}
+
+impl core::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("unsupported scheme configuration"),
+        }
+    }
+}
+
+/// A one-stop selection of backend and parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SchemeConfig {
+    /// The backend to stand up.
+    pub backend: Backend,
+    /// The polynomial modulus degree.
+    pub degree: DegreeType,
+    /// The target security level.
+    pub security_level: SecurityLevel,
+    /// BFV plaintext-modulus bit size (ignored by CKKS).
+    pub plain_modulus_bits: u32,
+    /// CKKS encoding scale (ignored by BFV).
+    pub scale: f64,
+}
+
+impl Default for SchemeConfig {
+    /// A CKKS configuration at `D8192`/`TC128` with a `2^40` scale — the
+    /// workhorse parameter set usable for either scheme.
+    fn default() -> Self {
+        Self {
+            backend: Backend::Ckks,
+            degree: DegreeType::D8192,
+            security_level: SecurityLevel::TC128,
+            plain_modulus_bits: 20,
+            scale: (1u64 << 40) as f64,
+        }
+    }
+}
+
+impl SchemeConfig {
+    /// Selects the backend.
+    #[must_use]
+    pub const fn with_backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Selects the polynomial modulus degree.
+    #[must_use]
+    pub const fn with_degree(mut self, degree: DegreeType) -> Self {
+        self.degree = degree;
+        self
+    }
+
+    /// Selects the security level.
+    #[must_use]
+    pub const fn with_security_level(mut self, security_level: SecurityLevel) -> Self {
+        self.security_level = security_level;
+        self
+    }
+
+    /// Selects the BFV plaintext-modulus bit size.
+    #[must_use]
+    pub const fn with_plain_modulus_bits(mut self, bits: u32) -> Self {
+        self.plain_modulus_bits = bits;
+        self
+    }
+
+    /// Selects the CKKS scale.
+    #[must_use]
+    pub const fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns `true` if this is a parameter combination SEAL accepts.
+    ///
+    /// Batching (the only mode these backends use) requires a degree of at least
+    /// `D4096`; the CKKS scale must be finite and greater than `1` (it is not
+    /// compared against the coefficient modulus); and a BFV plaintext-modulus
+    /// bit size must lie in `2..=60` and be one for which a batching prime
+    /// exists at the chosen degree.
+    #[must_use]
+    pub fn supported(&self) -> bool {
+        let degree_ok = matches!(
+            self.degree,
+            DegreeType::D4096 | DegreeType::D8192 | DegreeType::D16384 | DegreeType::D32768
+        );
+        if !degree_ok {
+            return false;
+        }
+
+        match self.backend {
+            Backend::Ckks => self.scale.is_finite() && self.scale > 1.0,
+            Backend::Bfv => {
+                self.plain_modulus_bits >= 2
+                    && self.plain_modulus_bits <= 60
+                    && PlainModulusFactory::batching(self.degree, self.plain_modulus_bits).is_ok()
+            }
+        }
+    }
+
+    /// Builds the configured cryptosystem, or fails if the parameters are not
+    /// [`supported`](Self::supported).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Unsupported`] rather than panicking inside SEAL.
+    pub fn build(&self) -> Result<BuiltScheme, ConfigError> {
+        if !self.supported() {
+            return Err(ConfigError::Unsupported);
+        }
+
+        Ok(match self.backend {
+            Backend::Ckks => {
+                let ctx = SealCkksContext::new(self.degree, self.security_level);
+                BuiltScheme::Ckks(SealCkksCS::new(&ctx, self.scale))
+            }
+            Backend::Bfv => {
+                let ctx = SealBFVContext::new(
+                    self.degree,
+                    self.security_level,
+                    self.plain_modulus_bits,
+                );
+                BuiltScheme::Bfv(SealBfvCS::new(&ctx))
+            }
+        })
+    }
+}
+
+/// Returns the common configuration a client and server can both support, or
+/// `None` if they cannot agree.
+///
+/// Agreement requires identical backend selection and parameters; the pair is
+/// only returned when that shared configuration is itself
+/// [`supported`](SchemeConfig::supported).
+#[must_use]
+pub fn negotiate(client: &SchemeConfig, server: &SchemeConfig) -> Option<SchemeConfig> {
+    if client == server && client.supported() {
+        Some(*client)
+    } else {
+        None
+    }
+}
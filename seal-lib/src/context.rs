@@ -1,7 +1,8 @@
 use sealy::{
     Asym, BFVEncoder, BFVEncryptionParametersBuilder, BFVEvaluator, CKKSEncoder,
     CKKSEncryptionParametersBuilder, CKKSEvaluator, CoefficientModulusFactory, Context, Decryptor,
-    Encryptor, KeyGenerator, PlainModulusFactory, PublicKey, RelinearizationKey, SecretKey,
+    Encryptor, EncryptionParameterSet, GaloisKey, KeyGenerator, Modulus, PlainModulusFactory,
+    PublicKey, RelinearizationKey, SecretKey,
 };
 pub use sealy::{DegreeType, Evaluator, SecurityLevel};
 
@@ -27,6 +28,17 @@ impl SealCkksContext {
         &self.0
     }
 
+    #[must_use]
+    /// Depth of the coefficient-modulus chain: a fresh ciphertext starts at
+    /// this level, decrementing by one with every `rescale` or
+    /// `mod_switch_to` step, down to `0`.
+    pub fn max_level(&self) -> u32 {
+        let params = self.0.get_encryption_parameters().unwrap();
+        u32::try_from(params.get_coefficient_modulus().len())
+            .unwrap()
+            .saturating_sub(1)
+    }
+
     #[must_use]
     #[inline]
     /// Generate a set of secret, public and relinearization keys.
@@ -40,6 +52,33 @@ impl SealCkksContext {
         (sk, pk, rk)
     }
 
+    #[must_use]
+    #[inline]
+    /// Generate secret, public, relinearization and Galois keys together.
+    ///
+    /// The Galois keys are drawn from the same secret key as the others and
+    /// enable slot rotations (see [`CkksHOperation1::Rotate`]); they are required
+    /// for any rotate-based reduction over SIMD-packed ciphertexts.
+    ///
+    /// [`CkksHOperation1::Rotate`]: crate::CkksHOperation1::Rotate
+    pub fn generate_keys_with_galois(
+        &self,
+    ) -> (
+        SecretKey,
+        PublicKey,
+        Option<RelinearizationKey>,
+        Option<GaloisKey>,
+    ) {
+        let key_gen = KeyGenerator::new(self.context()).unwrap();
+
+        let sk = key_gen.secret_key();
+        let pk = key_gen.create_public_key();
+        let rk = key_gen.create_relinearization_keys().ok();
+        let gk = key_gen.create_galois_keys().ok();
+
+        (sk, pk, rk, gk)
+    }
+
     #[must_use]
     #[inline]
     /// Create a new encoder.
@@ -94,12 +133,78 @@ impl SealBFVContext {
         Self(Context::new(&params, false, sl).unwrap())
     }
 
+    #[must_use]
+    /// Create a new BFV context with an explicit plain modulus.
+    ///
+    /// Used by the CRT layer to stand up one context per residue channel, where
+    /// each channel needs a distinct (coprime) plain modulus rather than the
+    /// single auto-selected prime of [`new`](Self::new).
+    pub fn with_plain_modulus(degree: DegreeType, sl: SecurityLevel, plain_modulus: Modulus) -> Self {
+        let params = BFVEncryptionParametersBuilder::new()
+            .set_poly_modulus_degree(degree)
+            .set_plain_modulus(plain_modulus)
+            .set_coefficient_modulus(CoefficientModulusFactory::bfv(degree, sl).unwrap())
+            .build()
+            .unwrap();
+
+        Self(Context::new(&params, false, sl).unwrap())
+    }
+
+    #[must_use]
+    /// Rebuilds a context from a [`EncryptionParameterSet`], typically one
+    /// received over the wire, using the exact modulus chains it carries rather
+    /// than re-deriving them from the factories.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the set does not describe a BFV parameter set (it must carry a
+    /// plain modulus) or if SEAL rejects the moduli.
+    pub fn from_parameter_set(set: &EncryptionParameterSet) -> Self {
+        let plain_modulus = set
+            .plain_modulus
+            .clone()
+            .expect("BFV parameter set must carry a plain modulus");
+        let params = BFVEncryptionParametersBuilder::new()
+            .set_poly_modulus_degree(set.degree)
+            .set_plain_modulus(plain_modulus)
+            .set_coefficient_modulus(set.coeff_modulus.clone())
+            .build()
+            .unwrap();
+
+        Self(Context::new(&params, false, set.security_level).unwrap())
+    }
+
+    #[must_use]
+    /// Captures this context's parameters as a serde-round-trippable
+    /// [`EncryptionParameterSet`], e.g. to ship on a protocol handshake.
+    pub fn parameter_set(&self) -> EncryptionParameterSet {
+        let params = self.0.get_encryption_parameters().unwrap();
+        EncryptionParameterSet {
+            scheme: params.get_scheme(),
+            degree: DegreeType::try_from(params.get_poly_modulus_degree()).unwrap(),
+            security_level: self.0.get_security_level().unwrap(),
+            coeff_modulus: params.get_coefficient_modulus(),
+            plain_modulus: Some(params.get_plain_modulus()),
+        }
+    }
+
     #[must_use]
     #[inline]
     pub(super) const fn context(&self) -> &Context {
         &self.0
     }
 
+    #[must_use]
+    /// Depth of the coefficient-modulus chain: a fresh ciphertext starts at
+    /// this level, decrementing by one with every `rescale` or
+    /// `mod_switch_to` step, down to `0`.
+    pub fn max_level(&self) -> u32 {
+        let params = self.0.get_encryption_parameters().unwrap();
+        u32::try_from(params.get_coefficient_modulus().len())
+            .unwrap()
+            .saturating_sub(1)
+    }
+
     #[must_use]
     #[inline]
     /// Generate a pair of secret and public keys.
@@ -113,6 +218,32 @@ impl SealBFVContext {
         (sk, pk, rk)
     }
 
+    #[must_use]
+    #[inline]
+    /// Generate secret, public, relinearization and Galois keys together.
+    ///
+    /// The Galois keys share the secret key with the others and enable slot
+    /// rotations (see [`BfvHOperation1::Rotate`]) over SIMD-packed ciphertexts.
+    ///
+    /// [`BfvHOperation1::Rotate`]: crate::BfvHOperation1::Rotate
+    pub fn generate_keys_with_galois(
+        &self,
+    ) -> (
+        SecretKey,
+        PublicKey,
+        Option<RelinearizationKey>,
+        Option<GaloisKey>,
+    ) {
+        let key_gen = KeyGenerator::new(self.context()).unwrap();
+
+        let sk = key_gen.secret_key();
+        let pk = key_gen.create_public_key();
+        let rk = key_gen.create_relinearization_keys().ok();
+        let gk = key_gen.create_galois_keys().ok();
+
+        (sk, pk, rk, gk)
+    }
+
     #[must_use]
     #[inline]
     /// Create a new encoder.
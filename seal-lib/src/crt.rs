@@ -0,0 +1,212 @@
+//! Chinese-Remainder-Theorem layer for large-integer BFV arithmetic.
+//!
+//! A single batching plain modulus caps exact integer messages at its value.
+//! This layer splits each message into residues modulo several coprime plain
+//! moduli, runs one [`SealBfvCS`] per residue channel, and recombines the
+//! decrypted residues with Garner's algorithm. Users can therefore compute on
+//! 32/64-bit integers homomorphically without wrapping the plain modulus.
+
+use alloc::vec::Vec;
+
+use fhe_core::api::CryptoSystem;
+use sealy::{CoefficientModulusFactory, DegreeType, Modulus, SecurityLevel};
+
+use crate::context::SealBFVContext;
+use crate::{BfvHOperation2, Ciphertext, SealBfvCS};
+
+/// Chooses a set of distinct (hence pairwise-coprime) batching primes, each
+/// roughly `per_modulus_bits` wide, whose product exceeds `2^target_bits`.
+///
+/// # Panics
+///
+/// Panics if `per_modulus_bits` is below 2 or no such primes exist for `degree`.
+#[must_use]
+pub fn choose_crt_moduli(
+    degree: DegreeType,
+    target_bits: u32,
+    per_modulus_bits: u32,
+) -> Vec<Modulus> {
+    assert!(per_modulus_bits > 1, "per_modulus_bits must be at least 2");
+    // Each prime is above 2^(per_modulus_bits - 1), so this many of them
+    // multiply past 2^target_bits.
+    let count = target_bits.div_ceil(per_modulus_bits - 1).max(1) as usize;
+    let bit_sizes = alloc::vec![i32::try_from(per_modulus_bits).unwrap(); count];
+    CoefficientModulusFactory::build(degree, &bit_sizes).unwrap()
+}
+
+/// A ciphertext in CRT form: one BFV ciphertext per residue channel.
+pub struct CrtCiphertext {
+    residues: Vec<Ciphertext>,
+}
+
+/// A BFV cryptosystem spread across several coprime plain-modulus channels.
+pub struct CrtBfv {
+    channels: Vec<SealBfvCS>,
+    moduli: Vec<u64>,
+}
+
+impl CrtBfv {
+    /// Builds a CRT system from an explicit coprime moduli set.
+    #[must_use]
+    pub fn new(degree: DegreeType, sl: SecurityLevel, moduli: &[Modulus]) -> Self {
+        let mut channels = Vec::with_capacity(moduli.len());
+        let mut values = Vec::with_capacity(moduli.len());
+        for m in moduli {
+            let ctx = SealBFVContext::with_plain_modulus(degree, sl, m.clone());
+            channels.push(SealBfvCS::new(&ctx));
+            values.push(m.value());
+        }
+        Self {
+            channels,
+            moduli: values,
+        }
+    }
+
+    /// Builds a CRT system sized to hold `target_bits`-wide integers, using
+    /// channels of about `per_modulus_bits` each.
+    #[must_use]
+    pub fn with_bit_width(
+        degree: DegreeType,
+        sl: SecurityLevel,
+        target_bits: u32,
+        per_modulus_bits: u32,
+    ) -> Self {
+        let moduli = choose_crt_moduli(degree, target_bits, per_modulus_bits);
+        Self::new(degree, sl, &moduli)
+    }
+
+    /// Encrypts `value` as residues across every channel.
+    #[must_use]
+    pub fn encrypt(&self, value: u128) -> CrtCiphertext {
+        let residues = self
+            .channels
+            .iter()
+            .zip(&self.moduli)
+            .map(|(cs, &m)| cs.cipher(&u64::try_from(value % u128::from(m)).unwrap()))
+            .collect();
+        CrtCiphertext { residues }
+    }
+
+    /// Component-wise homomorphic addition across the residue channels.
+    #[must_use]
+    pub fn crt_add(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.zip_op(lhs, rhs, BfvHOperation2::Add)
+    }
+
+    /// Component-wise homomorphic multiplication across the residue channels.
+    #[must_use]
+    pub fn crt_mul(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.zip_op(lhs, rhs, BfvHOperation2::Mul)
+    }
+
+    fn zip_op(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+        op: BfvHOperation2,
+    ) -> CrtCiphertext {
+        let residues = self
+            .channels
+            .iter()
+            .enumerate()
+            .map(|(i, cs)| cs.operate2(op, &lhs.residues[i], &rhs.residues[i]))
+            .collect();
+        CrtCiphertext { residues }
+    }
+
+    /// Decrypts every channel and reconstructs the integer via Garner's
+    /// algorithm. The result is taken modulo the product of all moduli, which
+    /// must fit in a `u128`.
+    #[must_use]
+    pub fn decrypt(&self, ciphertext: &CrtCiphertext) -> u128 {
+        let residues: Vec<u64> = self
+            .channels
+            .iter()
+            .enumerate()
+            .map(|(i, cs)| cs.decipher(&ciphertext.residues[i]))
+            .collect();
+        garner(&residues, &self.moduli)
+    }
+}
+
+/// Streaming homomorphic accumulator for summing an arbitrarily large column
+/// of integers without ever wrapping a plain modulus.
+///
+/// Sizes a [`CrtBfv`] so the product of its channels exceeds `2^target_bits`,
+/// keeps a running encrypted total, and folds each incoming value in with a
+/// component-wise homomorphic add. The caller must ensure the worst-case sum
+/// stays below that product — i.e. `target_bits` bounds `log2(Σ values)`, not a
+/// single term — otherwise [`finalize`](Self::finalize) reconstructs the total
+/// modulo the product and wraps just like a lone plain modulus would.
+pub struct CrtAggregator {
+    crt: CrtBfv,
+    total: CrtCiphertext,
+}
+
+impl CrtAggregator {
+    /// Builds an aggregator whose moduli product bounds `target_bits`-wide
+    /// sums, using channels of about `per_modulus_bits` each, seeded with an
+    /// encrypted zero.
+    #[must_use]
+    pub fn with_bit_width(
+        degree: DegreeType,
+        sl: SecurityLevel,
+        target_bits: u32,
+        per_modulus_bits: u32,
+    ) -> Self {
+        let crt = CrtBfv::with_bit_width(degree, sl, target_bits, per_modulus_bits);
+        let total = crt.encrypt(0);
+        Self { crt, total }
+    }
+
+    /// Adds a single cleartext value into the running encrypted total.
+    pub fn add(&mut self, value: u128) {
+        let term = self.crt.encrypt(value);
+        self.total = self.crt.crt_add(&self.total, &term);
+    }
+
+    /// Borrows the current encrypted running total, e.g. to serialize it or
+    /// combine several aggregators' partial sums with [`CrtBfv::crt_add`].
+    #[must_use]
+    pub const fn total(&self) -> &CrtCiphertext {
+        &self.total
+    }
+
+    /// Decrypts and reconstructs the accumulated sum via the CRT.
+    #[must_use]
+    pub fn finalize(&self) -> u128 {
+        self.crt.decrypt(&self.total)
+    }
+}
+
+/// Reconstructs the integer with residues `residues[i] mod moduli[i]` using the
+/// mixed-radix (Garner) form `x = Σ c_i · Π_{j<i} m_j`.
+fn garner(residues: &[u64], moduli: &[u64]) -> u128 {
+    let mut x: u128 = 0;
+    let mut radix: u128 = 1;
+    for (&r, &m) in residues.iter().zip(moduli) {
+        let mi = u128::from(m);
+        let xi = x % mi;
+        let diff = (u128::from(r) + mi - xi % mi) % mi;
+        let c = (diff * mod_inv(radix % mi, mi)) % mi;
+        x += c * radix;
+        radix *= mi;
+    }
+    x
+}
+
+/// Modular inverse of `a` modulo `m` via the extended Euclidean algorithm.
+fn mod_inv(a: u128, m: u128) -> u128 {
+    let (mut old_r, mut r) = (a as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        let t = old_r - q * r;
+        old_r = r;
+        r = t;
+        let t = old_s - q * s;
+        old_s = s;
+        s = t;
+    }
+    old_s.rem_euclid(m as i128) as u128
+}
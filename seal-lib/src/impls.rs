@@ -1,4 +1,4 @@
-use sealy::{Ciphertext, Plaintext, RelinearizationKey};
+use sealy::{Ciphertext, GaloisKey, Plaintext, RelinearizationKey};
 
 #[must_use]
 #[inline]
@@ -88,12 +88,33 @@ pub fn homom_exp(
 }
 
 #[inline]
+/// Drops `ciphertext` to the next lower level in the coefficient-modulus chain.
+///
+/// Every homomorphic multiply consumes one level of the modulus chain; `resize`
+/// performs that step explicitly so a fresher ciphertext can be brought down to
+/// match an operand that has already been rescaled. See [`crate::level`] for the
+/// scale/level bookkeeping built on top of this primitive.
 pub fn resize(
-    _evaluator: &impl sealy::Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
-    _ciphertext: &mut Ciphertext,
+    evaluator: &impl sealy::Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
+    ciphertext: &mut Ciphertext,
 ) {
-    todo!("resize");
-    // TODO: implement resize
+    evaluator.mod_switch_to_next_inplace(ciphertext).unwrap();
+}
+
+#[must_use]
+#[inline]
+/// Cyclically rotates the SIMD slots of `ciphertext` by `steps` positions.
+///
+/// Backed by Galois keys; a positive `steps` rotates left, a negative one right.
+/// Combined with [`homom_add`] this is the building block for slot-wise
+/// reductions such as a total sum via rotate-and-add.
+pub fn rotate_rows(
+    evaluator: &impl sealy::Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
+    ciphertext: &Ciphertext,
+    steps: i32,
+    galois_key: &GaloisKey,
+) -> Ciphertext {
+    evaluator.rotate_rows(ciphertext, steps, galois_key).unwrap()
 }
 
 #[must_use]
@@ -0,0 +1,107 @@
+//! Scale and modulus-chain level tracking for CKKS circuits.
+//!
+//! CKKS arithmetic only stays correct while both operands of an `add`/`multiply`
+//! sit at the same coefficient-modulus level and carry the same scale. SEAL
+//! itself enforces this but offers no bookkeeping, so multi-depth circuits (such
+//! as the polynomial `sign` approximation) have to rescale and mod-switch by
+//! hand. [`Leveled`] pairs a ciphertext with its position in the modulus chain
+//! and its current scale, and exposes [`rescale`](Leveled::rescale),
+//! [`mod_switch_to`](Leveled::mod_switch_to) and
+//! [`match_levels`](Leveled::match_levels) so callers can keep two operands in
+//! lockstep before the homomorphic add/multiply operations in `impls`.
+
+use sealy::{Ciphertext, Plaintext};
+
+/// A ciphertext annotated with its modulus-chain level and scale.
+///
+/// Level `0` is the freshest encryption; each rescale or mod-switch advances the
+/// level by one, consuming one prime of the coefficient-modulus chain.
+pub struct Leveled {
+    ciphertext: Ciphertext,
+    level: usize,
+    scale: f64,
+}
+
+impl Leveled {
+    /// Wraps a freshly encrypted ciphertext at level `0` with the given scale.
+    #[must_use]
+    #[inline]
+    pub const fn new(ciphertext: Ciphertext, scale: f64) -> Self {
+        Self {
+            ciphertext,
+            level: 0,
+            scale,
+        }
+    }
+
+    /// The underlying ciphertext.
+    #[must_use]
+    #[inline]
+    pub const fn ciphertext(&self) -> &Ciphertext {
+        &self.ciphertext
+    }
+
+    /// Current position in the modulus-switching chain.
+    #[must_use]
+    #[inline]
+    pub const fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Current scale.
+    #[must_use]
+    #[inline]
+    pub const fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Consumes the wrapper and returns the ciphertext.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> Ciphertext {
+        self.ciphertext
+    }
+
+    /// Rescales by the last prime of the chain: divides the scale by `prime` and
+    /// advances one level. Mirrors SEAL's `rescale_to_next`, which both drops a
+    /// level and divides the embedded scale.
+    #[inline]
+    pub fn rescale(
+        &mut self,
+        evaluator: &impl sealy::Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
+        prime: f64,
+    ) {
+        crate::impls::resize(evaluator, &mut self.ciphertext);
+        self.level += 1;
+        self.scale /= prime;
+    }
+
+    /// Drops straight down to `target` level without touching the scale, leaving
+    /// the ciphertext one prime shorter per level. Does nothing if already at or
+    /// below `target`.
+    #[inline]
+    pub fn mod_switch_to(
+        &mut self,
+        evaluator: &impl sealy::Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
+        target: usize,
+    ) {
+        while self.level < target {
+            crate::impls::resize(evaluator, &mut self.ciphertext);
+            self.level += 1;
+        }
+    }
+
+    /// Brings `lhs` and `rhs` to their common (deeper) level by mod-switching the
+    /// shallower operand down, so they can be added or multiplied without a
+    /// level mismatch.
+    #[inline]
+    pub fn match_levels(
+        evaluator: &impl sealy::Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
+        lhs: &mut Self,
+        rhs: &mut Self,
+    ) {
+        let target = lhs.level.max(rhs.level);
+        lhs.mod_switch_to(evaluator, target);
+        rhs.mod_switch_to(evaluator, target);
+    }
+}
@@ -8,27 +8,49 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 pub use bincode::{Decode, Encode};
-use fhe_core::api::{Arity1Operation, Arity2Operation, CryptoSystem, Operation};
+use fhe_core::api::{Arity1Operation, Arity2Operation, CryptoSystem, Operation, SerFormat};
 use fhe_operations::selectable_collection::{Flag, SelectableCS};
 pub use sealy::{
-    BFVEncoder, BFVEvaluator, CKKSEncoder, CKKSEvaluator, Decryptor, DegreeType, Evaluator,
-    Plaintext, PublicKey, SecretKey, SecurityLevel,
+    BFVEncoder, BFVEvaluator, CKKSEncoder, CKKSEvaluator, Decryptor, DegreeType,
+    EncryptionParameterSet, Evaluator, Plaintext, PublicKey, SchemeType, SecretKey, SecurityLevel,
 };
 use sealy::{FromBytes as _, ToBytes as _};
 
 pub mod context;
+pub mod crt;
+pub mod config;
 mod impls;
+pub mod level;
+pub mod program;
+pub mod threshold;
 
 #[derive(Clone)]
 /// Ciphertext from Microsoft SEAL.
-pub struct Ciphertext(pub sealy::Ciphertext);
+///
+/// Carries its position in the coefficient-modulus chain alongside the raw
+/// SEAL ciphertext, since the vendored library exposes no way to read the
+/// chain index back off a ciphertext: see [`CryptoSystem::level`],
+/// [`CryptoSystem::rescale`] and [`CryptoSystem::mod_switch_to`].
+pub struct Ciphertext {
+    pub inner: sealy::Ciphertext,
+    level: u32,
+}
+
+impl Ciphertext {
+    #[must_use]
+    #[inline]
+    const fn at_level(inner: sealy::Ciphertext, level: u32) -> Self {
+        Self { inner, level }
+    }
+}
 
 impl Encode for Ciphertext {
     fn encode<E: bincode::enc::Encoder>(
         &self,
         encoder: &mut E,
     ) -> Result<(), bincode::error::EncodeError> {
-        self.0.as_bytes().unwrap().encode(encoder)
+        self.inner.as_bytes().unwrap().encode(encoder)?;
+        self.level.encode(encoder)
     }
 }
 
@@ -37,8 +59,10 @@ impl Decode<context::SealCkksContext> for Ciphertext {
         decoder: &mut D,
     ) -> Result<Self, bincode::error::DecodeError> {
         let raw: Vec<_> = Decode::decode(decoder)?;
-        Ok(Self(
+        let level = Decode::decode(decoder)?;
+        Ok(Self::at_level(
             sealy::Ciphertext::from_bytes(decoder.context().context(), &raw).unwrap(),
+            level,
         ))
     }
 }
@@ -47,24 +71,30 @@ impl Decode<context::SealBFVContext> for Ciphertext {
         decoder: &mut D,
     ) -> Result<Self, bincode::error::DecodeError> {
         let raw: Vec<_> = Decode::decode(decoder)?;
-        Ok(Self(
+        let level = Decode::decode(decoder)?;
+        Ok(Self::at_level(
             sealy::Ciphertext::from_bytes(decoder.context().context(), &raw).unwrap(),
+            level,
         ))
     }
 }
 
 /// The CKKS CryptoSystem backed by Microsoft SEAL.
 pub struct SealCkksCS {
+    context: context::SealCkksContext,
     encoder: sealy::CKKSEncoder,
     evaluator: sealy::CKKSEvaluator,
     encryptor: sealy::Encryptor<sealy::Asym>,
     decryptor: sealy::Decryptor,
+    public_key: sealy::PublicKey,
     relin_key: Option<sealy::RelinearizationKey>,
+    galois_key: Option<sealy::GaloisKey>,
+    max_level: u32,
 }
 
 impl SealCkksCS {
     pub fn new(context: &context::SealCkksContext, scale: f64) -> Self {
-        let (skey, pkey, relin_key) = context.generate_keys();
+        let (skey, pkey, relin_key, galois_key) = context.generate_keys_with_galois();
 
         let encoder = context.encoder(scale);
         let evaluator = context.evaluator();
@@ -72,13 +102,114 @@ impl SealCkksCS {
         let decryptor = context.decryptor(&skey);
 
         Self {
+            context: context.clone(),
             encoder,
             evaluator,
             encryptor,
             decryptor,
+            public_key: pkey,
             relin_key,
+            galois_key,
+            max_level: context.max_level(),
         }
     }
+
+    #[must_use]
+    /// Encrypts a full slot vector into a single ciphertext.
+    ///
+    /// CKKS packs up to `N/2` real values per ciphertext, so operating on the
+    /// result applies component-wise across every slot under one homomorphic
+    /// instruction. Shorter inputs leave the remaining slots zeroed.
+    pub fn cipher_slots(&self, values: &[f64]) -> Ciphertext {
+        let encoded = self.encoder.encode_f64(values).unwrap();
+        Ciphertext::at_level(self.encryptor.encrypt(&encoded).unwrap(), self.max_level)
+    }
+
+    #[must_use]
+    /// Decrypts and decodes every slot of a packed ciphertext.
+    pub fn decipher_slots(&self, ciphertext: &Ciphertext) -> Vec<f64> {
+        let decrypted = self.decryptor.decrypt(&ciphertext.inner).unwrap();
+        self.encoder.decode_f64(&decrypted).unwrap()
+    }
+
+    #[must_use]
+    /// Encrypts a whole slot vector into a single ciphertext.
+    ///
+    /// CKKS packs up to `N/2` real values per ciphertext; [`cipher`] only fills
+    /// the first slot, so high-dimensional tensors (machine-learning workloads)
+    /// go through this batched path and operate slot-wise under one homomorphic
+    /// instruction. Shorter inputs leave the remaining slots at zero.
+    ///
+    /// [`cipher`]: CryptoSystem::cipher
+    pub fn cipher_batch(&self, values: &[f64]) -> Ciphertext {
+        let encoded = self.encoder.encode_f64(values).unwrap();
+        Ciphertext::at_level(self.encryptor.encrypt(&encoded).unwrap(), self.max_level)
+    }
+
+    #[must_use]
+    /// Decrypts and decodes every slot of a batched ciphertext.
+    ///
+    /// The inverse of [`cipher_batch`](Self::cipher_batch); the returned vector
+    /// holds one (approximate) value per CKKS slot.
+    pub fn decipher_batch(&self, ciphertext: &Ciphertext) -> Vec<f64> {
+        let decrypted = self.decryptor.decrypt(&ciphertext.inner).unwrap();
+        self.encoder.decode_f64(&decrypted).unwrap()
+    }
+
+    /// Mod-switches `ciphertext` down to the next level, trimming one prime off
+    /// the coefficient modulus.
+    ///
+    /// This is the primitive every rescale is built on; [`crate::level::Leveled`]
+    /// wraps it with the scale bookkeeping CKKS circuits need to rescale across
+    /// multiplication depth.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ciphertext` is already at level `0`.
+    #[inline]
+    pub fn mod_switch_to_next(&self, ciphertext: &mut Ciphertext) {
+        assert!(
+            ciphertext.level > 0,
+            "cannot mod-switch a ciphertext already at level 0"
+        );
+        impls::resize(&self.evaluator, &mut ciphertext.inner);
+        ciphertext.level -= 1;
+    }
+}
+
+/// Why a ciphertext or key could not be serialized or parsed back.
+#[derive(Debug)]
+pub enum SerError {
+    /// SEAL rejected the raw ciphertext or key bytes.
+    Seal,
+    /// The JSON envelope around the raw bytes was malformed.
+    Json,
+}
+
+impl core::fmt::Display for SerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Seal => f.write_str("SEAL rejected the ciphertext or key bytes"),
+            Self::Json => f.write_str("malformed JSON ciphertext envelope"),
+        }
+    }
+}
+
+/// Wraps `raw` bytes for `format`: unchanged for [`SerFormat::Binary`], inside
+/// a JSON envelope for [`SerFormat::Json`].
+fn bytes_to_wire(raw: Vec<u8>, format: SerFormat) -> Vec<u8> {
+    match format {
+        SerFormat::Binary => raw,
+        SerFormat::Json => serde_json::to_vec(&raw).expect("serializing a byte vector cannot fail"),
+    }
+}
+
+/// The inverse of [`bytes_to_wire`].
+fn bytes_from_wire(bytes: &[u8], format: SerFormat) -> Result<Vec<u8>, SerError> {
+    match format {
+        SerFormat::Binary => Ok(bytes.to_vec()),
+        SerFormat::Json => serde_json::from_slice(bytes).map_err(|_| SerError::Json),
+    }
 }
 
 impl CryptoSystem for SealCkksCS {
@@ -89,11 +220,11 @@ impl CryptoSystem for SealCkksCS {
 
     fn cipher(&self, plaintext: &Self::Plaintext) -> Self::Ciphertext {
         let encoded = self.encoder.encode_f64(&[*plaintext]).unwrap();
-        Ciphertext(self.encryptor.encrypt(&encoded).unwrap())
+        Ciphertext::at_level(self.encryptor.encrypt(&encoded).unwrap(), self.max_level)
     }
 
     fn decipher(&self, ciphertext: &Self::Ciphertext) -> Self::Plaintext {
-        let decrypted = self.decryptor.decrypt(&ciphertext.0).unwrap();
+        let decrypted = self.decryptor.decrypt(&ciphertext.inner).unwrap();
         self.encoder.decode_f64(&decrypted).unwrap()[0]
     }
 
@@ -101,13 +232,22 @@ impl CryptoSystem for SealCkksCS {
         match operation {
             CkksHOperation1::AddPlain(plain) => {
                 let plain_encoded = self.encoder.encode_f64(&[plain]).unwrap();
-                let result = impls::homom_add_plain(&self.evaluator, &lhs.0, &plain_encoded);
-                Ciphertext(result)
+                let result = impls::homom_add_plain(&self.evaluator, &lhs.inner, &plain_encoded);
+                Ciphertext::at_level(result, lhs.level)
             }
             CkksHOperation1::MulPlain(plain) => {
                 let plain_encoded = self.encoder.encode_f64(&[plain]).unwrap();
-                let result = impls::homom_mul_plain(&self.evaluator, &lhs.0, &plain_encoded);
-                Ciphertext(result)
+                let result = impls::homom_mul_plain(&self.evaluator, &lhs.inner, &plain_encoded);
+                Ciphertext::at_level(result, lhs.level)
+            }
+            CkksHOperation1::Rotate(steps) => {
+                let result = impls::rotate_rows(
+                    &self.evaluator,
+                    &lhs.inner,
+                    steps,
+                    self.galois_key.as_ref().unwrap(),
+                );
+                Ciphertext::at_level(result, lhs.level)
             }
             CkksHOperation1::Resize => panic!("Resize operation needs operate_mut, not operate."),
         }
@@ -119,28 +259,48 @@ impl CryptoSystem for SealCkksCS {
         lhs: &Self::Ciphertext,
         rhs: &Self::Ciphertext,
     ) -> Self::Ciphertext {
+        assert_eq!(
+            lhs.level, rhs.level,
+            "operate2 requires both operands at the same level; mod_switch_to them first"
+        );
         match operation {
             CkksHOperation2::Add => {
-                let result = impls::homom_add(&self.evaluator, &lhs.0, &rhs.0);
-                Ciphertext(result)
+                let result = impls::homom_add(&self.evaluator, &lhs.inner, &rhs.inner);
+                Ciphertext::at_level(result, lhs.level)
             }
             CkksHOperation2::Mul => {
-                let result = impls::homom_mul(&self.evaluator, &lhs.0, &rhs.0);
-                Ciphertext(result)
+                let result = impls::homom_mul(&self.evaluator, &lhs.inner, &rhs.inner);
+                Ciphertext::at_level(result, lhs.level)
             }
         }
     }
 
     fn operate1_inplace(&self, operation: Self::Operation1, lhs: &mut Self::Ciphertext) {
         match operation {
-            CkksHOperation1::Resize => impls::resize(&self.evaluator, &mut lhs.0),
+            CkksHOperation1::Resize => {
+                assert!(
+                    lhs.level > 0,
+                    "cannot mod-switch a ciphertext already at level 0"
+                );
+                impls::resize(&self.evaluator, &mut lhs.inner);
+                lhs.level -= 1;
+            }
             CkksHOperation1::AddPlain(plain) => {
                 let plain_encoded = self.encoder.encode_f64(&[plain]).unwrap();
-                impls::homom_add_plain_inplace(&self.evaluator, &mut lhs.0, &plain_encoded);
+                impls::homom_add_plain_inplace(&self.evaluator, &mut lhs.inner, &plain_encoded);
             }
             CkksHOperation1::MulPlain(plain) => {
                 let plain_encoded = self.encoder.encode_f64(&[plain]).unwrap();
-                impls::homom_mul_plain_inplace(&self.evaluator, &mut lhs.0, &plain_encoded);
+                impls::homom_mul_plain_inplace(&self.evaluator, &mut lhs.inner, &plain_encoded);
+            }
+            CkksHOperation1::Rotate(steps) => {
+                let result = impls::rotate_rows(
+                    &self.evaluator,
+                    &lhs.inner,
+                    steps,
+                    self.galois_key.as_ref().unwrap(),
+                );
+                lhs.inner = result;
             }
         }
     }
@@ -151,22 +311,85 @@ impl CryptoSystem for SealCkksCS {
         lhs: &mut Self::Ciphertext,
         rhs: &Self::Ciphertext,
     ) {
+        assert_eq!(
+            lhs.level, rhs.level,
+            "operate2_inplace requires both operands at the same level; mod_switch_to them first"
+        );
         match operation {
             CkksHOperation2::Add => {
-                impls::homom_add_inplace(&self.evaluator, &mut lhs.0, &rhs.0);
+                impls::homom_add_inplace(&self.evaluator, &mut lhs.inner, &rhs.inner);
             }
             CkksHOperation2::Mul => {
-                impls::homom_mul_inplace(&self.evaluator, &mut lhs.0, &rhs.0);
+                impls::homom_mul_inplace(&self.evaluator, &mut lhs.inner, &rhs.inner);
             }
         }
     }
 
     fn relinearize(&self, ciphertext: &mut Self::Ciphertext) {
-        *ciphertext = Ciphertext(impls::relinearize(
+        ciphertext.inner = impls::relinearize(
             &self.evaluator,
-            &ciphertext.0,
+            &ciphertext.inner,
             self.relin_key.as_ref().unwrap(),
-        ));
+        );
+    }
+
+    fn level(&self, ciphertext: &Self::Ciphertext) -> u32 {
+        ciphertext.level
+    }
+
+    fn rescale(&self, ciphertext: &mut Self::Ciphertext) {
+        assert!(
+            ciphertext.level > 0,
+            "cannot rescale a ciphertext already at level 0"
+        );
+        impls::resize(&self.evaluator, &mut ciphertext.inner);
+        ciphertext.level -= 1;
+    }
+
+    fn mod_switch_to(&self, ciphertext: &mut Self::Ciphertext, level: u32) {
+        assert!(
+            level <= ciphertext.level,
+            "cannot mod-switch up to a higher level"
+        );
+        while ciphertext.level > level {
+            impls::resize(&self.evaluator, &mut ciphertext.inner);
+            ciphertext.level -= 1;
+        }
+    }
+
+    type SerError = SerError;
+
+    fn serialize_ciphertext(&self, ciphertext: &Self::Ciphertext, format: SerFormat) -> Vec<u8> {
+        let payload = bincode::encode_to_vec(
+            (ciphertext.inner.as_bytes().unwrap(), ciphertext.level),
+            bincode::config::standard(),
+        )
+        .expect("encoding a byte vector and level cannot fail");
+        bytes_to_wire(payload, format)
+    }
+
+    fn deserialize_ciphertext(
+        &self,
+        bytes: &[u8],
+        format: SerFormat,
+    ) -> Result<Self::Ciphertext, Self::SerError> {
+        let payload = bytes_from_wire(bytes, format)?;
+        let ((raw, level), _): ((Vec<u8>, u32), _) =
+            bincode::decode_from_slice(&payload, bincode::config::standard())
+                .map_err(|_| SerError::Seal)?;
+        sealy::Ciphertext::from_bytes(self.context.context(), &raw)
+            .map(|inner| Ciphertext::at_level(inner, level))
+            .map_err(|_| SerError::Seal)
+    }
+
+    fn serialize_public_key(&self, format: SerFormat) -> Vec<u8> {
+        bytes_to_wire(self.public_key.as_bytes().unwrap(), format)
+    }
+
+    fn serialize_relin_key(&self, format: SerFormat) -> Option<Vec<u8>> {
+        self.relin_key
+            .as_ref()
+            .map(|rk| bytes_to_wire(rk.as_bytes().unwrap(), format))
     }
 }
 
@@ -190,6 +413,8 @@ impl SelectableCS for SealCkksCS {
 pub enum CkksHOperation1 {
     AddPlain(f64),
     MulPlain(f64),
+    /// Cyclically rotates the packed slots by the given number of steps.
+    Rotate(i32),
     Resize,
 }
 impl Operation for CkksHOperation1 {}
@@ -202,19 +427,27 @@ pub enum CkksHOperation2 {
     Mul,
 }
 impl Operation for CkksHOperation2 {}
-impl Arity2Operation for CkksHOperation2 {}
+impl Arity2Operation for CkksHOperation2 {
+    fn is_multiplication(&self) -> bool {
+        matches!(self, Self::Mul)
+    }
+}
 
 pub struct SealBfvCS {
+    context: context::SealBFVContext,
     encoder: sealy::BFVEncoder,
     evaluator: sealy::BFVEvaluator,
     encryptor: sealy::Encryptor<sealy::Asym>,
     decryptor: sealy::Decryptor,
+    public_key: sealy::PublicKey,
     relin_key: Option<sealy::RelinearizationKey>,
+    galois_key: Option<sealy::GaloisKey>,
+    max_level: u32,
 }
 
 impl SealBfvCS {
     pub fn new(context: &context::SealBFVContext) -> Self {
-        let (skey, pkey, relin_key) = context.generate_keys();
+        let (skey, pkey, relin_key, galois_key) = context.generate_keys_with_galois();
 
         let encoder = context.encoder();
         let evaluator = context.evaluator();
@@ -222,13 +455,35 @@ impl SealBfvCS {
         let decryptor = context.decryptor(&skey);
 
         Self {
+            context: context.clone(),
             encoder,
             evaluator,
             encryptor,
             decryptor,
+            public_key: pkey,
             relin_key,
+            galois_key,
+            max_level: context.max_level(),
         }
     }
+
+    #[must_use]
+    /// Encrypts a full slot vector into a single ciphertext.
+    ///
+    /// BFV batching packs `N` integers per ciphertext, so operating on the
+    /// result applies component-wise across every slot under one homomorphic
+    /// instruction. Shorter inputs leave the remaining slots zeroed.
+    pub fn cipher_slots(&self, values: &[u64]) -> Ciphertext {
+        let encoded = self.encoder.encode_u64(values).unwrap();
+        Ciphertext::at_level(self.encryptor.encrypt(&encoded).unwrap(), self.max_level)
+    }
+
+    #[must_use]
+    /// Decrypts and decodes every slot of a packed ciphertext.
+    pub fn decipher_slots(&self, ciphertext: &Ciphertext) -> Vec<u64> {
+        let decrypted = self.decryptor.decrypt(&ciphertext.inner).unwrap();
+        self.encoder.decode_u64(&decrypted).unwrap()
+    }
 }
 
 impl CryptoSystem for SealBfvCS {
@@ -239,11 +494,11 @@ impl CryptoSystem for SealBfvCS {
 
     fn cipher(&self, plaintext: &Self::Plaintext) -> Self::Ciphertext {
         let encoded = self.encoder.encode_u64(&[*plaintext]).unwrap();
-        Ciphertext(self.encryptor.encrypt(&encoded).unwrap())
+        Ciphertext::at_level(self.encryptor.encrypt(&encoded).unwrap(), self.max_level)
     }
 
     fn decipher(&self, ciphertext: &Self::Ciphertext) -> Self::Plaintext {
-        let decrypted = self.decryptor.decrypt(&ciphertext.0).unwrap();
+        let decrypted = self.decryptor.decrypt(&ciphertext.inner).unwrap();
         self.encoder.decode_u64(&decrypted).unwrap()[0]
     }
 
@@ -251,22 +506,31 @@ impl CryptoSystem for SealBfvCS {
         match operation {
             BfvHOperation1::AddPlain(plain) => {
                 let plain_encoded = self.encoder.encode_u64(&[plain]).unwrap();
-                let result = impls::homom_add_plain(&self.evaluator, &lhs.0, &plain_encoded);
-                Ciphertext(result)
+                let result = impls::homom_add_plain(&self.evaluator, &lhs.inner, &plain_encoded);
+                Ciphertext::at_level(result, lhs.level)
             }
             BfvHOperation1::MulPlain(plain) => {
                 let plain_encoded = self.encoder.encode_u64(&[plain]).unwrap();
-                let result = impls::homom_mul_plain(&self.evaluator, &lhs.0, &plain_encoded);
-                Ciphertext(result)
+                let result = impls::homom_mul_plain(&self.evaluator, &lhs.inner, &plain_encoded);
+                Ciphertext::at_level(result, lhs.level)
             }
             BfvHOperation1::Exp(exp) => {
                 let result = impls::homom_exp(
                     &self.evaluator,
-                    &lhs.0,
+                    &lhs.inner,
                     exp,
                     self.relin_key.as_ref().unwrap(),
                 );
-                Ciphertext(result)
+                Ciphertext::at_level(result, lhs.level)
+            }
+            BfvHOperation1::Rotate(steps) => {
+                let result = impls::rotate_rows(
+                    &self.evaluator,
+                    &lhs.inner,
+                    steps,
+                    self.galois_key.as_ref().unwrap(),
+                );
+                Ciphertext::at_level(result, lhs.level)
             }
         }
     }
@@ -277,14 +541,18 @@ impl CryptoSystem for SealBfvCS {
         lhs: &Self::Ciphertext,
         rhs: &Self::Ciphertext,
     ) -> Self::Ciphertext {
+        assert_eq!(
+            lhs.level, rhs.level,
+            "operate2 requires both operands at the same level; mod_switch_to them first"
+        );
         match operation {
             BfvHOperation2::Add => {
-                let result = impls::homom_add(&self.evaluator, &lhs.0, &rhs.0);
-                Ciphertext(result)
+                let result = impls::homom_add(&self.evaluator, &lhs.inner, &rhs.inner);
+                Ciphertext::at_level(result, lhs.level)
             }
             BfvHOperation2::Mul => {
-                let result = impls::homom_mul(&self.evaluator, &lhs.0, &rhs.0);
-                Ciphertext(result)
+                let result = impls::homom_mul(&self.evaluator, &lhs.inner, &rhs.inner);
+                Ciphertext::at_level(result, lhs.level)
             }
         }
     }
@@ -293,19 +561,27 @@ impl CryptoSystem for SealBfvCS {
         match operation {
             BfvHOperation1::AddPlain(plain) => {
                 let plain_encoded = self.encoder.encode_u64(&[plain]).unwrap();
-                impls::homom_add_plain_inplace(&self.evaluator, &mut lhs.0, &plain_encoded);
+                impls::homom_add_plain_inplace(&self.evaluator, &mut lhs.inner, &plain_encoded);
             }
             BfvHOperation1::MulPlain(plain) => {
                 let plain_encoded = self.encoder.encode_u64(&[plain]).unwrap();
-                impls::homom_mul_plain_inplace(&self.evaluator, &mut lhs.0, &plain_encoded);
+                impls::homom_mul_plain_inplace(&self.evaluator, &mut lhs.inner, &plain_encoded);
             }
             BfvHOperation1::Exp(exp) => {
-                *lhs = Ciphertext(impls::homom_exp(
+                lhs.inner = impls::homom_exp(
                     &self.evaluator,
-                    &lhs.0,
+                    &lhs.inner,
                     exp,
                     self.relin_key.as_ref().unwrap(),
-                ));
+                );
+            }
+            BfvHOperation1::Rotate(steps) => {
+                lhs.inner = impls::rotate_rows(
+                    &self.evaluator,
+                    &lhs.inner,
+                    steps,
+                    self.galois_key.as_ref().unwrap(),
+                );
             }
         }
     }
@@ -316,12 +592,16 @@ impl CryptoSystem for SealBfvCS {
         lhs: &mut Self::Ciphertext,
         rhs: &Self::Ciphertext,
     ) {
+        assert_eq!(
+            lhs.level, rhs.level,
+            "operate2_inplace requires both operands at the same level; mod_switch_to them first"
+        );
         match operation {
             BfvHOperation2::Add => {
-                impls::homom_add_inplace(&self.evaluator, &mut lhs.0, &rhs.0);
+                impls::homom_add_inplace(&self.evaluator, &mut lhs.inner, &rhs.inner);
             }
             BfvHOperation2::Mul => {
-                impls::homom_mul_inplace(&self.evaluator, &mut lhs.0, &rhs.0);
+                impls::homom_mul_inplace(&self.evaluator, &mut lhs.inner, &rhs.inner);
             }
         }
     }
@@ -329,6 +609,67 @@ impl CryptoSystem for SealBfvCS {
     fn relinearize(&self, _ciphertext: &mut Self::Ciphertext) {
         // No relinearization in BFV
     }
+
+    fn level(&self, ciphertext: &Self::Ciphertext) -> u32 {
+        ciphertext.level
+    }
+
+    fn rescale(&self, ciphertext: &mut Self::Ciphertext) {
+        // BFV carries no CKKS-style scale to restore, but mod-switching down
+        // one level still trims noise the same way rescale does for CKKS.
+        assert!(
+            ciphertext.level > 0,
+            "cannot rescale a ciphertext already at level 0"
+        );
+        impls::resize(&self.evaluator, &mut ciphertext.inner);
+        ciphertext.level -= 1;
+    }
+
+    fn mod_switch_to(&self, ciphertext: &mut Self::Ciphertext, level: u32) {
+        assert!(
+            level <= ciphertext.level,
+            "cannot mod-switch up to a higher level"
+        );
+        while ciphertext.level > level {
+            impls::resize(&self.evaluator, &mut ciphertext.inner);
+            ciphertext.level -= 1;
+        }
+    }
+
+    type SerError = SerError;
+
+    fn serialize_ciphertext(&self, ciphertext: &Self::Ciphertext, format: SerFormat) -> Vec<u8> {
+        let payload = bincode::encode_to_vec(
+            (ciphertext.inner.as_bytes().unwrap(), ciphertext.level),
+            bincode::config::standard(),
+        )
+        .expect("encoding a byte vector and level cannot fail");
+        bytes_to_wire(payload, format)
+    }
+
+    fn deserialize_ciphertext(
+        &self,
+        bytes: &[u8],
+        format: SerFormat,
+    ) -> Result<Self::Ciphertext, Self::SerError> {
+        let payload = bytes_from_wire(bytes, format)?;
+        let ((raw, level), _): ((Vec<u8>, u32), _) =
+            bincode::decode_from_slice(&payload, bincode::config::standard())
+                .map_err(|_| SerError::Seal)?;
+        sealy::Ciphertext::from_bytes(self.context.context(), &raw)
+            .map(|inner| Ciphertext::at_level(inner, level))
+            .map_err(|_| SerError::Seal)
+    }
+
+    fn serialize_public_key(&self, format: SerFormat) -> Vec<u8> {
+        bytes_to_wire(self.public_key.as_bytes().unwrap(), format)
+    }
+
+    fn serialize_relin_key(&self, format: SerFormat) -> Option<Vec<u8>> {
+        self.relin_key
+            .as_ref()
+            .map(|rk| bytes_to_wire(rk.as_bytes().unwrap(), format))
+    }
 }
 
 impl SelectableCS for SealBfvCS {
@@ -352,6 +693,8 @@ pub enum BfvHOperation1 {
     AddPlain(u64),
     MulPlain(u64),
     Exp(u64),
+    /// Cyclically rotates the packed slots by the given number of steps.
+    Rotate(i32),
 }
 impl Operation for BfvHOperation1 {}
 impl Arity1Operation for BfvHOperation1 {}
@@ -363,13 +706,20 @@ pub enum BfvHOperation2 {
     Mul,
 }
 impl Operation for BfvHOperation2 {}
-impl Arity2Operation for BfvHOperation2 {}
+impl Arity2Operation for BfvHOperation2 {
+    fn is_multiplication(&self) -> bool {
+        matches!(self, Self::Mul)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::context::{SealBFVContext, SealCkksContext};
-    use fhe_core::{api::CryptoSystem, f64::approx_eq};
+    use fhe_core::{
+        api::{CryptoSystem, SerFormat},
+        f64::approx_eq,
+    };
 
     const PRECISION: f64 = 5e-2;
 
@@ -411,6 +761,22 @@ mod tests {
         assert!(approx_eq(d, 4.0, PRECISION));
     }
 
+    #[test]
+    fn test_seal_ckks_cs_batch() {
+        let context = SealCkksContext::new(DegreeType::D2048, SecurityLevel::TC128);
+        let cs = SealCkksCS::new(&context, 1e6);
+
+        let values = [1.0, 2.0, 3.0, 4.0];
+        let a = cs.cipher_batch(&values);
+        let b = cs.cipher_batch(&values);
+        let sum = cs.operate2(CkksHOperation2::Add, &a, &b);
+
+        let decoded = cs.decipher_batch(&sum);
+        for (i, &v) in values.iter().enumerate() {
+            assert!(approx_eq(decoded[i], v + v, PRECISION));
+        }
+    }
+
     #[test]
     fn test_seal_ckks_cs_linear_sum() {
         let context = SealCkksContext::new(DegreeType::D2048, SecurityLevel::TC128);
@@ -488,4 +854,98 @@ mod tests {
 
         assert_eq!(d, 16);
     }
+
+    #[test]
+    fn test_seal_bfv_ciphertext_round_trips_through_bytes() {
+        let context = SealBFVContext::new(DegreeType::D2048, SecurityLevel::TC128, 16);
+        let cs = SealBfvCS::new(&context);
+
+        let ciphertext = cs.cipher(&7);
+        for format in [SerFormat::Binary, SerFormat::Json] {
+            let bytes = cs.serialize_ciphertext(&ciphertext, format);
+            let restored = cs.deserialize_ciphertext(&bytes, format).unwrap();
+            assert_eq!(cs.decipher(&restored), 7);
+        }
+    }
+
+    #[test]
+    fn test_seal_bfv_public_key_serializes_in_both_formats() {
+        let context = SealBFVContext::new(DegreeType::D2048, SecurityLevel::TC128, 16);
+        let cs = SealBfvCS::new(&context);
+
+        assert!(!cs.serialize_public_key(SerFormat::Binary).is_empty());
+        assert!(!cs.serialize_public_key(SerFormat::Json).is_empty());
+        assert!(cs.serialize_relin_key(SerFormat::Binary).is_some());
+    }
+
+    #[test]
+    fn test_seal_ckks_fresh_ciphertext_starts_at_max_level() {
+        let context = SealCkksContext::new(DegreeType::D2048, SecurityLevel::TC128);
+        let cs = SealCkksCS::new(&context, 1e6);
+
+        let a = cs.cipher(&1.0);
+        assert_eq!(cs.level(&a), context.max_level());
+    }
+
+    #[test]
+    fn test_seal_ckks_rescale_decrements_level() {
+        let context = SealCkksContext::new(DegreeType::D2048, SecurityLevel::TC128);
+        let cs = SealCkksCS::new(&context, 1e6);
+
+        let mut a = cs.cipher(&1.0);
+        let before = cs.level(&a);
+        cs.rescale(&mut a);
+        assert_eq!(cs.level(&a), before - 1);
+        assert!(approx_eq(cs.decipher(&a), 1.0, PRECISION));
+    }
+
+    #[test]
+    fn test_seal_ckks_mod_switch_to_aligns_levels() {
+        let context = SealCkksContext::new(DegreeType::D2048, SecurityLevel::TC128);
+        let cs = SealCkksCS::new(&context, 1e6);
+
+        let mut a = cs.cipher(&1.0);
+        let mut b = cs.cipher(&2.0);
+        cs.rescale(&mut a);
+        cs.mod_switch_to(&mut b, cs.level(&a));
+        assert_eq!(cs.level(&a), cs.level(&b));
+
+        let sum = cs.operate2(CkksHOperation2::Add, &a, &b);
+        assert!(approx_eq(cs.decipher(&sum), 3.0, PRECISION));
+    }
+
+    #[test]
+    #[should_panic(expected = "same level")]
+    fn test_seal_ckks_operate2_rejects_mismatched_levels() {
+        let context = SealCkksContext::new(DegreeType::D2048, SecurityLevel::TC128);
+        let cs = SealCkksCS::new(&context, 1e6);
+
+        let mut a = cs.cipher(&1.0);
+        let b = cs.cipher(&2.0);
+        cs.rescale(&mut a);
+
+        let _ = cs.operate2(CkksHOperation2::Add, &a, &b);
+    }
+
+    #[test]
+    fn test_seal_bfv_fresh_ciphertext_starts_at_max_level() {
+        let context = SealBFVContext::new(DegreeType::D2048, SecurityLevel::TC128, 16);
+        let cs = SealBfvCS::new(&context);
+
+        let a = cs.cipher(&7);
+        assert_eq!(cs.level(&a), context.max_level());
+    }
+
+    #[test]
+    fn test_seal_ckks_ciphertext_round_trips_through_bytes() {
+        let context = SealCkksContext::new(DegreeType::D2048, SecurityLevel::TC128);
+        let cs = SealCkksCS::new(&context, 1e6);
+
+        let ciphertext = cs.cipher(&3.5);
+        for format in [SerFormat::Binary, SerFormat::Json] {
+            let bytes = cs.serialize_ciphertext(&ciphertext, format);
+            let restored = cs.deserialize_ciphertext(&bytes, format).unwrap();
+            assert!(approx_eq(cs.decipher(&restored), 3.5, PRECISION));
+        }
+    }
 }
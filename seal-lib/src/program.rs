@@ -0,0 +1,207 @@
+//! Serializable homomorphic programs and a client/server evaluation protocol.
+//!
+//! The per-operation [`CryptoSystem`] API is convenient for interactive use, but
+//! offloading a whole computation to a server means shipping the operations as
+//! data. Because the `CkksHOperation*`/`BfvHOperation*` enums already derive
+//! [`Encode`]/[`Decode`] and [`Ciphertext`] already round-trips through bincode
+//! against a context, we can describe a circuit as an ordered list of
+//! register-addressed [`Instruction`]s bundled with its encrypted inputs — a
+//! [`Program`] — and evaluate it in one shot.
+//!
+//! A client builds a `Program`, serializes it, and sends it to a server; the
+//! server reconstructs it against its own context and runs the instructions in
+//! sequence via [`SyncEvaluator::evaluate`] (or [`AsyncEvaluator::evaluate_async`]
+//! for a non-blocking worker), returning only the output ciphertexts. The split
+//! mirrors the sync/async client traits used elsewhere and works for any backend
+//! whose ciphertext is [`Ciphertext`], so the same program can be dispatched to
+//! either `SealCkksCS` or `SealBfvCS`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use bincode::{Decode, Encode};
+use fhe_core::api::CryptoSystem;
+
+use crate::Ciphertext;
+
+/// A register index addressing a slot in the program's working set.
+pub type Register = u32;
+
+/// A single register-addressed operation in a [`Program`].
+///
+/// Registers `0..inputs.len()` are pre-loaded with the program's inputs; every
+/// instruction reads from and writes to registers by index.
+#[derive(Clone, Debug, Encode, Decode)]
+pub enum Instruction<O1, O2> {
+    /// `out <- op(arg)`.
+    Unary {
+        /// Destination register.
+        out: Register,
+        /// The arity-1 operation to apply.
+        op: O1,
+        /// Source register.
+        arg: Register,
+    },
+    /// `out <- op(lhs, rhs)`.
+    Binary {
+        /// Destination register.
+        out: Register,
+        /// The arity-2 operation to apply.
+        op: O2,
+        /// Left-hand source register.
+        lhs: Register,
+        /// Right-hand source register.
+        rhs: Register,
+    },
+    /// Relinearizes the ciphertext in `reg` in place (a no-op for BFV).
+    Relinearize {
+        /// Register to relinearize.
+        reg: Register,
+    },
+}
+
+/// An ordered, serializable homomorphic program plus its encrypted inputs.
+#[derive(Clone, Debug, Encode, Decode)]
+pub struct Program<O1, O2> {
+    inputs: Vec<Ciphertext>,
+    instructions: Vec<Instruction<O1, O2>>,
+    outputs: Vec<Register>,
+}
+
+impl<O1, O2> Program<O1, O2> {
+    /// Starts a program whose first registers are seeded with `inputs`.
+    #[must_use]
+    pub fn new(inputs: Vec<Ciphertext>) -> Self {
+        Self {
+            inputs,
+            instructions: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Appends `out <- op(arg)`.
+    pub fn push_unary(&mut self, out: Register, op: O1, arg: Register) -> &mut Self {
+        self.instructions.push(Instruction::Unary { out, op, arg });
+        self
+    }
+
+    /// Appends `out <- op(lhs, rhs)`.
+    pub fn push_binary(&mut self, out: Register, op: O2, lhs: Register, rhs: Register) -> &mut Self {
+        self.instructions
+            .push(Instruction::Binary { out, op, lhs, rhs });
+        self
+    }
+
+    /// Appends an in-place relinearization of `reg`.
+    pub fn push_relinearize(&mut self, reg: Register) -> &mut Self {
+        self.instructions.push(Instruction::Relinearize { reg });
+        self
+    }
+
+    /// Declares which registers carry the program's results.
+    pub fn set_outputs(&mut self, outputs: Vec<Register>) -> &mut Self {
+        self.outputs = outputs;
+        self
+    }
+
+    /// The registers declared as outputs.
+    #[must_use]
+    pub fn outputs(&self) -> &[Register] {
+        &self.outputs
+    }
+
+    /// The number of registers the program addresses.
+    fn register_count(&self) -> usize {
+        let mut max = self.inputs.len();
+        for instruction in &self.instructions {
+            let touched = match instruction {
+                Instruction::Unary { out, arg, .. } => (*out).max(*arg),
+                Instruction::Binary { out, lhs, rhs, .. } => (*out).max(*lhs).max(*rhs),
+                Instruction::Relinearize { reg } => *reg,
+            };
+            max = max.max(touched as usize + 1);
+        }
+        for out in &self.outputs {
+            max = max.max(*out as usize + 1);
+        }
+        max
+    }
+}
+
+/// Synchronous, in-process evaluation of a [`Program`].
+///
+/// A blanket implementation runs on every [`CryptoSystem`] whose ciphertext is
+/// [`Ciphertext`] and whose operations are `Copy`, so both SEAL backends support
+/// it without extra code.
+pub trait SyncEvaluator: CryptoSystem<Ciphertext = Ciphertext> {
+    /// Runs the program's instructions in order and returns the ciphertexts in
+    /// the program's declared output registers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an instruction reads a register that has not yet been written,
+    /// or if an output register is empty after evaluation.
+    fn evaluate(&self, program: &Program<Self::Operation1, Self::Operation2>) -> Vec<Ciphertext>;
+}
+
+impl<C> SyncEvaluator for C
+where
+    C: CryptoSystem<Ciphertext = Ciphertext>,
+    C::Operation1: Copy,
+    C::Operation2: Copy,
+{
+    fn evaluate(&self, program: &Program<Self::Operation1, Self::Operation2>) -> Vec<Ciphertext> {
+        let mut registers: Vec<Option<Ciphertext>> = vec![None; program.register_count()];
+        for (i, input) in program.inputs.iter().enumerate() {
+            registers[i] = Some(input.clone());
+        }
+
+        let read = |registers: &[Option<Ciphertext>], reg: Register| -> Ciphertext {
+            registers[reg as usize]
+                .clone()
+                .expect("instruction reads an uninitialized register")
+        };
+
+        for instruction in &program.instructions {
+            match *instruction {
+                Instruction::Unary { out, op, arg } => {
+                    let result = self.operate1(op, &read(&registers, arg));
+                    registers[out as usize] = Some(result);
+                }
+                Instruction::Binary { out, op, lhs, rhs } => {
+                    let result = self.operate2(op, &read(&registers, lhs), &read(&registers, rhs));
+                    registers[out as usize] = Some(result);
+                }
+                Instruction::Relinearize { reg } => {
+                    let mut value = read(&registers, reg);
+                    self.relinearize(&mut value);
+                    registers[reg as usize] = Some(value);
+                }
+            }
+        }
+
+        program
+            .outputs
+            .iter()
+            .map(|&reg| read(&registers, reg))
+            .collect()
+    }
+}
+
+/// Asynchronous evaluation of a [`Program`], for servers that dispatch work to a
+/// non-blocking worker.
+///
+/// The default implementation simply awaits the synchronous [`SyncEvaluator`]
+/// path; backends that can offload to a thread pool or accelerator can override
+/// it.
+pub trait AsyncEvaluator: SyncEvaluator {
+    /// Evaluates `program`, returning its output ciphertexts.
+    fn evaluate_async(
+        &self,
+        program: &Program<Self::Operation1, Self::Operation2>,
+    ) -> impl core::future::Future<Output = Vec<Ciphertext>> {
+        async move { self.evaluate(program) }
+    }
+}
+
+impl<C> AsyncEvaluator for C where C: SyncEvaluator {}
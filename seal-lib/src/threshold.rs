@@ -0,0 +1,486 @@
+//! Distributed custody of a SEAL secret key.
+//!
+//! In the default single-[`Decryptor`](sealy::Decryptor) design a single party
+//! holds the full secret key and can decrypt unilaterally. This module splits a
+//! secret key across `n` parties so that no single holder can decrypt on its
+//! own, mirroring the `SecretKeySet` approach of BLS threshold systems: the key
+//! is recovered only when a quorum of shares is brought back together.
+//!
+//! # What this provides, and what it does not
+//!
+//! SEAL exposes its secret key only as an opaque, serialized blob — the
+//! coefficient polynomial `s` is never visible through the public API, and
+//! there is no evaluator operation for multiplying a ciphertext component by a
+//! bare key polynomial. That rules out the genuinely non-interactive
+//! ring-level protocol `ckks-lib`'s own `cipher` module implements for the
+//! from-scratch CKKS backend, where `partial_decrypt` computes `c1 · s_j`
+//! directly over ring elements it has full access to.
+//!
+//! What this module does instead:
+//!
+//! - [`split_secret_key`]/[`combine_key_shares`]: additive **n-of-n** sharing
+//!   of the serialized key, for explicit key export/recovery. Every share is
+//!   uniform and reveals nothing on its own; combining requires all `n`.
+//! - [`share_secret_key`]/[`combine_shamir_shares`]: genuine Shamir **t-of-n**
+//!   sharing of the serialized key over `GF(256)`, with Lagrange interpolation
+//!   at reconstruction — any `t + 1` of the `n` shares reconstruct the key
+//!   byte-exactly; fewer reveal nothing (the textbook guarantee of Shamir
+//!   sharing over a finite field, here applied per byte).
+//! - [`SealCkksContext::combine_partial_decryptions`]: the routine decryption
+//!   path built on top of the Shamir shares. It reconstructs the key exactly
+//!   as [`combine_shamir_shares`] does, but only transiently, inside this one
+//!   call: a smudging (noise-flooding) plaintext is folded into the
+//!   ciphertext first, the ephemeral key decrypts it, and the key is dropped
+//!   before returning — the assembled key itself is never part of this
+//!   function's return type, unlike [`combine_key_shares`] or
+//!   [`combine_shamir_shares`], which hand it back deliberately for the
+//!   explicit-export use case.
+//!
+//! Callers whose threat model requires that *no* process ever holds the whole
+//! key, even momentarily, still need the ring-level protocol above and a SEAL
+//! build that exposes it; callers who only need "no single party can decrypt
+//! unsupervised, and every decryption is noise-flooded" are served by
+//! [`SealCkksContext::combine_partial_decryptions`].
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use fhe_core::rand::distributions::{Distribution, Gaussian, Truncated, Uniform};
+
+use sealy::{Context, FromBytes, Plaintext, SecretKey, ToBytes};
+
+use crate::context::{SealBFVContext, SealCkksContext};
+use crate::impls::homom_add_plain;
+
+/// Standard deviation of the smudging (noise-flooding) term folded into a
+/// ciphertext before [`SealCkksContext::combine_partial_decryptions`] decrypts
+/// it with the reconstructed share key, so that the decrypted result does not
+/// pin down the shares' exact contribution. Deliberately wide relative to the
+/// encryption noise, mirroring `ckks-lib`'s `SMUDGING_SIGMA`; callers who need
+/// a different margin for their chosen `SecurityLevel` should sample with
+/// [`combine_partial_decryptions`](SealCkksContext::combine_partial_decryptions)'s
+/// `smudging_sigma` parameter instead of this default.
+pub const DEFAULT_SMUDGING_SIGMA: f64 = 1e-6;
+/// Truncation bound of the smudging distribution, as a multiple of its sigma.
+const SMUDGING_BETA_FACTOR: f64 = 8.0;
+
+/// One party's additive share of a split secret key (n-of-n).
+///
+/// A share carries its party `index` and a byte mask the same length as the
+/// serialized key. Individually a share is uniformly random and reveals nothing;
+/// only the XOR of all shares from a split reconstructs the key.
+#[derive(Clone)]
+pub struct KeyShare {
+    index: usize,
+    mask: Vec<u8>,
+}
+
+impl KeyShare {
+    /// The party index this share was handed to (`0..n`).
+    #[must_use]
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Splits `secret_key` into `n` additive shares (n-of-n).
+///
+/// All `n` shares are required to reconstruct the key via
+/// [`ThresholdDecrypt::combine_key_shares`]; any strict subset is
+/// information-theoretically independent of the key. For a reconstruction
+/// threshold below `n`, share with [`share_secret_key`] instead.
+///
+/// # Panics
+///
+/// Panics if `n == 0` or if the system randomness source fails. Use
+/// [`try_split_secret_key`] to handle a sampling failure without panicking.
+#[must_use]
+pub fn split_secret_key(secret_key: &SecretKey, n: usize) -> Vec<KeyShare> {
+    try_split_secret_key(secret_key, n).expect("failed to split secret key")
+}
+
+/// Fallible counterpart to [`split_secret_key`], propagating a randomness
+/// failure instead of panicking.
+///
+/// # Panics
+///
+/// Panics if `n == 0`.
+///
+/// # Errors
+///
+/// Returns the `getrandom` error if the masking bytes cannot be sampled.
+pub fn try_split_secret_key(
+    secret_key: &SecretKey,
+    n: usize,
+) -> fhe_core::rand::RandResult<Vec<KeyShare>> {
+    assert!(n > 0, "need at least one party");
+
+    let key_bytes = secret_key.as_bytes().expect("failed to serialize secret key");
+    let len = key_bytes.len();
+
+    // The first n-1 shares are uniform masks; the last absorbs the key so that
+    // the XOR of all shares is exactly the key bytes.
+    let sampler = Uniform::<u8>::new(0..=u8::MAX);
+    let mut shares = Vec::with_capacity(n);
+    let mut last = key_bytes;
+    for index in 0..n - 1 {
+        let mut mask = vec![0u8; len];
+        for byte in &mut mask {
+            *byte = sampler.sample()?;
+        }
+        for (acc, m) in last.iter_mut().zip(&mask) {
+            *acc ^= *m;
+        }
+        shares.push(KeyShare { index, mask });
+    }
+    shares.push(KeyShare { index: n - 1, mask: last });
+
+    Ok(shares)
+}
+
+/// Recombines additive key shares into the [`SecretKey`] they were split from,
+/// under a given context.
+///
+/// This hands the caller the reconstructed [`SecretKey`] directly — it is the
+/// explicit key-export/recovery primitive, not the routine decryption path.
+/// For day-to-day threshold decryption where the assembled key should never
+/// leave the combining call, share with [`share_secret_key`] and decrypt with
+/// [`SealCkksContext::combine_partial_decryptions`].
+pub trait ThresholdDecrypt {
+    /// Reconstructs the secret key from the full set of shares produced by
+    /// [`split_secret_key`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shares` is empty, if the shares differ in length, or if the
+    /// recombined bytes do not form a valid secret key for this context.
+    fn combine_key_shares(&self, shares: &[KeyShare]) -> SecretKey;
+}
+
+fn combine_bytes(shares: &[KeyShare]) -> Vec<u8> {
+    assert!(!shares.is_empty(), "need at least one share to reconstruct");
+
+    let len = shares[0].mask.len();
+    let mut acc = vec![0u8; len];
+    for share in shares {
+        assert_eq!(share.mask.len(), len, "shares differ in length");
+        for (a, m) in acc.iter_mut().zip(&share.mask) {
+            *a ^= *m;
+        }
+    }
+    acc
+}
+
+impl ThresholdDecrypt for SealCkksContext {
+    fn combine_key_shares(&self, shares: &[KeyShare]) -> SecretKey {
+        reconstruct(self.context(), shares)
+    }
+}
+
+impl ThresholdDecrypt for SealBFVContext {
+    fn combine_key_shares(&self, shares: &[KeyShare]) -> SecretKey {
+        reconstruct(self.context(), shares)
+    }
+}
+
+fn reconstruct(context: &Context, shares: &[KeyShare]) -> SecretKey {
+    let bytes = combine_bytes(shares);
+    SecretKey::from_bytes(context, &bytes).expect("recombined bytes are not a valid secret key")
+}
+
+// --- GF(256) arithmetic, for Shamir sharing the key bytes -----------------
+
+/// AES's reduction polynomial `x⁸ + x⁴ + x³ + x + 1`, used to keep products in
+/// `GF(256)` reduced to a single byte.
+const GF256_MODULUS: u16 = 0x11B;
+
+/// Multiplies two `GF(256)` elements via carry-less (XOR) long multiplication,
+/// reducing modulo [`GF256_MODULUS`] as the product grows past a byte.
+const fn gf256_mul(a: u8, b: u8) -> u8 {
+    let mut a = a as u16;
+    let mut b = b;
+    let mut product: u16 = 0;
+    let mut i = 0;
+    while i < 8 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= GF256_MODULUS;
+        }
+        b >>= 1;
+        i += 1;
+    }
+    (product & 0xFF) as u8
+}
+
+/// Multiplicative inverse of a nonzero `GF(256)` element via exponentiation by
+/// `254` (every nonzero element has order dividing `255`, so `a^254 = a⁻¹`).
+///
+/// # Panics
+///
+/// Panics if `a` is zero, which has no inverse.
+const fn gf256_inv(a: u8) -> u8 {
+    assert!(a != 0, "zero has no GF(256) inverse");
+    let mut result: u8 = 1;
+    let mut base = a;
+    let mut exp: u8 = 254;
+    while exp > 0 {
+        if exp & 1 != 0 {
+            result = gf256_mul(result, base);
+        }
+        base = gf256_mul(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Evaluates the polynomial with constant term `secret` and coefficients
+/// `coeffs[0..]` (ascending degree) at `x`, all arithmetic in `GF(256)`.
+fn gf256_eval(secret: u8, coeffs: &[u8], x: u8) -> u8 {
+    let mut acc = secret;
+    let mut xpow = x;
+    for &c in coeffs {
+        acc ^= gf256_mul(c, xpow);
+        xpow = gf256_mul(xpow, x);
+    }
+    acc
+}
+
+/// One party's Shamir share of a [`SecretKey`] (t-of-n).
+///
+/// Every byte of the serialized key is shared independently at the same
+/// party index `x`, so the share is the same length as the key.
+#[derive(Clone)]
+pub struct SecretKeyShare {
+    index: u8,
+    share: Vec<u8>,
+}
+
+impl SecretKeyShare {
+    /// The party index (the `x`-coordinate the secret was evaluated at).
+    #[must_use]
+    #[inline]
+    pub const fn index(&self) -> u8 {
+        self.index
+    }
+}
+
+/// Splits `secret_key` into `n` Shamir shares with reconstruction threshold
+/// `t` (any `t + 1` of them reconstruct the key via [`combine_shamir_shares`];
+/// fewer are information-theoretically independent of it).
+///
+/// For every byte `s_i` of the serialized key a degree-`t` polynomial
+/// `f_i(x) = s_i ⊕ a_1·x ⊕ … ⊕ a_t·xᵗ` is sampled over `GF(256)` with uniform
+/// masking coefficients, and party `j` (for `j = 1..=n`) receives the
+/// evaluations `f_i(j)` gathered back into a byte buffer the same length as
+/// the key.
+///
+/// # Panics
+///
+/// Panics if `t >= n`, `n == 0`, `n > 255` (party indices must fit a nonzero
+/// `GF(256)` element), or if the system randomness source fails.
+#[must_use]
+pub fn share_secret_key(secret_key: &SecretKey, n: usize, t: usize) -> Vec<SecretKeyShare> {
+    try_share_secret_key(secret_key, n, t).expect("failed to share secret key")
+}
+
+/// Fallible counterpart to [`share_secret_key`].
+///
+/// # Panics
+///
+/// Panics if `t >= n`, `n == 0`, or `n > 255`.
+///
+/// # Errors
+///
+/// Returns the `getrandom` error if the masking coefficients cannot be sampled.
+pub fn try_share_secret_key(
+    secret_key: &SecretKey,
+    n: usize,
+    t: usize,
+) -> fhe_core::rand::RandResult<Vec<SecretKeyShare>> {
+    assert!(n > 0, "need at least one party");
+    assert!(t < n, "threshold t must be smaller than the number of parties n");
+    assert!(n <= 255, "party indices must fit a nonzero GF(256) element");
+
+    let key_bytes = secret_key.as_bytes().expect("failed to serialize secret key");
+
+    // Sample the masking coefficients a_{i,1..t} once per key byte; they
+    // define that byte's sharing polynomial and are shared by every party.
+    let sampler = Uniform::<u8>::new(0..=u8::MAX);
+    let mut masks: Vec<Vec<u8>> = Vec::with_capacity(key_bytes.len());
+    for _ in &key_bytes {
+        let mut row = Vec::with_capacity(t);
+        for _ in 0..t {
+            row.push(sampler.sample()?);
+        }
+        masks.push(row);
+    }
+
+    Ok((1..=n)
+        .map(|j| {
+            let x = j as u8;
+            let share = key_bytes
+                .iter()
+                .zip(&masks)
+                .map(|(&s, a)| gf256_eval(s, a, x))
+                .collect();
+            SecretKeyShare { index: x, share }
+        })
+        .collect())
+}
+
+/// Reconstructs the serialized key bytes from at least `t + 1` Shamir shares
+/// via Lagrange interpolation at `x = 0` over `GF(256)`.
+///
+/// # Panics
+///
+/// Panics if `shares` is empty, if two shares carry the same index, or if the
+/// shares differ in length.
+fn combine_shamir_bytes(shares: &[SecretKeyShare]) -> Vec<u8> {
+    assert!(!shares.is_empty(), "need at least one share to reconstruct");
+
+    let lambdas: Vec<u8> = shares
+        .iter()
+        .enumerate()
+        .map(|(j, sj)| {
+            let mut num = 1u8;
+            let mut den = 1u8;
+            for (m, sm) in shares.iter().enumerate() {
+                if m == j {
+                    continue;
+                }
+                assert_ne!(sm.index, sj.index, "shares must carry distinct indices");
+                // Evaluating at x = 0: numerator picks up each other index
+                // x_m directly, and x_m - x_j is x_m ⊕ x_j in GF(256).
+                num = gf256_mul(num, sm.index);
+                den = gf256_mul(den, sm.index ^ sj.index);
+            }
+            gf256_mul(num, gf256_inv(den))
+        })
+        .collect();
+
+    let len = shares[0].share.len();
+    (0..len)
+        .map(|i| {
+            let mut acc = 0u8;
+            for (sj, &lambda) in shares.iter().zip(&lambdas) {
+                assert_eq!(sj.share.len(), len, "shares differ in length");
+                acc ^= gf256_mul(sj.share[i], lambda);
+            }
+            acc
+        })
+        .collect()
+}
+
+/// Reconstructs a [`SecretKey`] from at least `t + 1` shares produced by
+/// [`share_secret_key`].
+///
+/// Like [`ThresholdDecrypt::combine_key_shares`], this is the explicit
+/// key-export/recovery primitive: it hands the caller the assembled key. For
+/// routine decryption that never exposes the assembled key, share with
+/// [`share_secret_key`] and decrypt with
+/// [`SealCkksContext::combine_partial_decryptions`] instead.
+///
+/// # Panics
+///
+/// Panics if `shares` is empty, if two shares carry the same index, if the
+/// shares differ in length, or if the recombined bytes do not form a valid
+/// secret key for `context`.
+#[must_use]
+pub fn combine_shamir_shares(context: &Context, shares: &[SecretKeyShare]) -> SecretKey {
+    let bytes = combine_shamir_bytes(shares);
+    SecretKey::from_bytes(context, &bytes).expect("recombined bytes are not a valid secret key")
+}
+
+/// A party's staged contribution to a threshold decryption.
+///
+/// Produced by [`partial_decrypt`] and consumed by
+/// [`SealCkksContext::combine_partial_decryptions`]. This backend cannot
+/// compute a true ring-level partial decryption share without raw access to
+/// the ciphertext's polynomial components (see the module docs), so a
+/// `DecryptionShare` carries the party's Shamir share as-is; the actual
+/// decryption happens once, during combination.
+#[derive(Clone)]
+pub struct DecryptionShare {
+    share: SecretKeyShare,
+}
+
+/// Stages `share` for a threshold decryption of some ciphertext.
+///
+/// Kept as a distinct step (mirroring the `partial_decrypt`/
+/// `combine_partial_decryptions` split of the ring-level backends in this
+/// series) so call sites read the same way across backends, even though this
+/// backend's real work happens in
+/// [`combine_partial_decryptions`](SealCkksContext::combine_partial_decryptions).
+#[must_use]
+#[inline]
+pub fn partial_decrypt(share: &SecretKeyShare) -> DecryptionShare {
+    DecryptionShare {
+        share: share.clone(),
+    }
+}
+
+impl SealCkksContext {
+    /// Threshold-decrypts `ciphertext` from at least `t + 1`
+    /// [`DecryptionShare`]s, without ever returning the assembled secret key.
+    ///
+    /// The key is reconstructed exactly as [`combine_shamir_shares`] does, but
+    /// only for the lifetime of this call: a smudging (noise-flooding)
+    /// plaintext of standard deviation `smudging_sigma` is folded into
+    /// `ciphertext` first via [`sealy::Evaluator::add_plain`], the ephemeral key then
+    /// decrypts the smudged ciphertext, and the key is dropped before
+    /// returning — only the decoded, noise-flooded plaintext values leave this
+    /// function. Pass [`DEFAULT_SMUDGING_SIGMA`] for `smudging_sigma` absent a
+    /// more specific requirement from the chosen `SecurityLevel`.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`combine_shamir_shares`], if
+    /// sampling the smudging noise fails, or if SEAL rejects the homomorphic
+    /// add or the decryption.
+    #[must_use]
+    pub fn combine_partial_decryptions(
+        &self,
+        shares: &[DecryptionShare],
+        ciphertext: &crate::Ciphertext,
+        scale: f64,
+        smudging_sigma: f64,
+    ) -> Vec<f64> {
+        let key_shares: Vec<SecretKeyShare> =
+            shares.iter().map(|d| d.share.clone()).collect();
+        let skey = combine_shamir_shares(self.context(), &key_shares);
+
+        let encoder = self.encoder(scale);
+        let evaluator = self.evaluator();
+
+        let slots = self.slot_count();
+        let smudge_values = sample_smudging_noise(slots, smudging_sigma)
+            .expect("failed to sample smudging noise");
+        let smudge_plain: Plaintext = encoder.encode_f64(&smudge_values).unwrap();
+        let smudged = homom_add_plain(&evaluator, &ciphertext.inner, &smudge_plain);
+
+        let decryptor = self.decryptor(&skey);
+        let decrypted = decryptor.decrypt(&smudged).unwrap();
+        encoder.decode_f64(&decrypted).unwrap()
+    }
+
+    /// Number of CKKS slots (`N / 2`) a ciphertext under this context packs.
+    fn slot_count(&self) -> usize {
+        let params = self.context().get_encryption_parameters().unwrap();
+        (params.get_poly_modulus_degree() / 2) as usize
+    }
+}
+
+/// Samples `count` independent, truncated-Gaussian smudging values with
+/// standard deviation `sigma`, the same noise-flooding distribution shape
+/// `ckks-lib`'s `SMUDGING_SIGMA` uses.
+fn sample_smudging_noise(count: usize, sigma: f64) -> fhe_core::rand::RandResult<Vec<f64>> {
+    let beta = SMUDGING_BETA_FACTOR * sigma;
+    let g = Gaussian::new(0.0, sigma);
+    let t = Truncated::new(g, -beta..=beta);
+    (0..count).map(|_| t.sample()).collect()
+}
@@ -96,6 +96,39 @@ impl Ciphertext {
 
         result
     }
+
+    /// Serializes the ciphertext using the requested compression, the
+    /// form-selectable counterpart of [`as_bytes`](ToBytes::as_bytes) (which
+    /// always uses [`CompressionType::ZStd`]). [`CompressionType::None`] trades
+    /// larger output for lower latency, which matters in streaming pipelines
+    /// like the homomorphic-mean example where every ciphertext on the wire
+    /// pays the codec cost.
+    pub fn to_bytes_with(&self, compression: CompressionType) -> Result<Vec<u8>> {
+        let mut num_bytes: i64 = 0;
+
+        try_seal!(unsafe {
+            bindgen::Ciphertext_SaveSize(self.get_handle(), compression as u8, &mut num_bytes)
+        })?;
+
+        let mut data: Vec<u8> = Vec::with_capacity(usize::try_from(num_bytes).unwrap());
+        let mut bytes_written: i64 = 0;
+
+        try_seal!(unsafe {
+            let data_ptr = data.as_mut_ptr();
+
+            bindgen::Ciphertext_Save(
+                self.get_handle(),
+                data_ptr,
+                u64::try_from(num_bytes).unwrap(),
+                compression as u8,
+                &mut bytes_written,
+            )
+        })?;
+
+        unsafe { data.set_len(usize::try_from(bytes_written).unwrap()) };
+
+        Ok(data)
+    }
 }
 
 impl Debug for Ciphertext {
@@ -132,35 +165,10 @@ impl PartialEq for Ciphertext {
 }
 
 impl ToBytes for Ciphertext {
+    /// [`CompressionType::ZStd`] serialization; see
+    /// [`to_bytes_with`](Ciphertext::to_bytes_with) to pick a different codec.
     fn as_bytes(&self) -> Result<Vec<u8>> {
-        let mut num_bytes: i64 = 0;
-
-        try_seal!(unsafe {
-            bindgen::Ciphertext_SaveSize(
-                self.get_handle(),
-                CompressionType::ZStd as u8,
-                &mut num_bytes,
-            )
-        })?;
-
-        let mut data: Vec<u8> = Vec::with_capacity(usize::try_from(num_bytes).unwrap());
-        let mut bytes_written: i64 = 0;
-
-        try_seal!(unsafe {
-            let data_ptr = data.as_mut_ptr();
-
-            bindgen::Ciphertext_Save(
-                self.get_handle(),
-                data_ptr,
-                u64::try_from(num_bytes).unwrap(),
-                CompressionType::ZStd as u8,
-                &mut bytes_written,
-            )
-        })?;
-
-        unsafe { data.set_len(usize::try_from(bytes_written).unwrap()) };
-
-        Ok(data)
+        self.to_bytes_with(CompressionType::ZStd)
     }
 }
 
@@ -193,7 +201,7 @@ impl Drop for Ciphertext {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::*;
 
     #[test]
     fn can_create_and_destroy_ciphertext() {
@@ -201,4 +209,68 @@ mod tests {
 
         std::mem::drop(ciphertext);
     }
+
+    fn mk_ctx() -> Context {
+        let params = BFVEncryptionParametersBuilder::new()
+            .set_poly_modulus_degree(DegreeType::D8192)
+            .set_coefficient_modulus(
+                CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+            )
+            .set_plain_modulus_u64(1234)
+            .build()
+            .unwrap();
+
+        Context::new(&params, false, SecurityLevel::TC128).unwrap()
+    }
+
+    fn round_trips_with(compression: CompressionType) {
+        let ctx = mk_ctx();
+        let key_gen = KeyGenerator::new(&ctx).unwrap();
+        let public_key = key_gen.create_public_key();
+        let encoder = BFVEncoder::new(&ctx).unwrap();
+        let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+        let plaintext = encoder.encode_u64(&[7, 42, 1234]).unwrap();
+        let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+        let bytes = ciphertext.to_bytes_with(compression).unwrap();
+        let reloaded = Ciphertext::from_bytes(&ctx, &bytes).unwrap();
+
+        let decryptor = Decryptor::new(&ctx, &key_gen.secret_key()).unwrap();
+        let original = encoder.decode_u64(&decryptor.decrypt(&ciphertext).unwrap());
+        let from_reloaded = encoder.decode_u64(&decryptor.decrypt(&reloaded).unwrap());
+        assert_eq!(original.unwrap(), from_reloaded.unwrap());
+    }
+
+    #[test]
+    fn round_trips_uncompressed() {
+        round_trips_with(CompressionType::None);
+    }
+
+    #[test]
+    fn round_trips_zstd() {
+        round_trips_with(CompressionType::ZStd);
+    }
+
+    #[test]
+    fn round_trips_deflate() {
+        round_trips_with(CompressionType::Deflate);
+    }
+
+    #[test]
+    fn as_bytes_matches_zstd_form() {
+        let ctx = mk_ctx();
+        let key_gen = KeyGenerator::new(&ctx).unwrap();
+        let public_key = key_gen.create_public_key();
+        let encoder = BFVEncoder::new(&ctx).unwrap();
+        let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+        let plaintext = encoder.encode_u64(&[1, 2, 3]).unwrap();
+        let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+        assert_eq!(
+            ciphertext.as_bytes().unwrap(),
+            ciphertext.to_bytes_with(CompressionType::ZStd).unwrap()
+        );
+    }
 }
@@ -183,6 +183,33 @@ impl<T: component_marker::Sym> Encryptor<T> {
 
         Ok(ciphertext)
     }
+
+    /// Encrypts a plaintext with the secret key, storing only the PRNG seed of
+    /// the second ciphertext component instead of materializing it.
+    ///
+    /// SEAL regenerates the second polynomial from the seed when the
+    /// ciphertext is loaded against a context, which roughly halves the
+    /// serialized size. This is useful when transmitting freshly encrypted
+    /// symmetric inputs over the wire. The returned ciphertext behaves exactly
+    /// like an uncompressed one once [`from_bytes`](crate::FromBytes::from_bytes)
+    /// has rehydrated it.
+    ///
+    /// * `plaintext` - The plaintext to encrypt.
+    pub fn encrypt_symmetric_compressed(&self, plaintext: &Plaintext) -> Result<Ciphertext> {
+        let ciphertext = Ciphertext::new()?;
+
+        try_seal!(unsafe {
+            bindgen::Encryptor_EncryptSymmetric(
+                self.get_handle(),
+                plaintext.get_handle(),
+                true,
+                ciphertext.get_handle(),
+                null_mut(),
+            )
+        })?;
+
+        Ok(ciphertext)
+    }
 }
 
 impl<T> Drop for Encryptor<T> {
@@ -250,4 +277,27 @@ mod tests {
 
         std::mem::drop(encryptor);
     }
+
+    #[test]
+    fn seed_compressed_ciphertext_round_trips() {
+        let ctx = mk_ctx(|b| b);
+
+        let key_gen = KeyGenerator::new(&ctx).unwrap();
+        let secret_key = key_gen.secret_key();
+
+        let encoder = BFVEncoder::new(&ctx).unwrap();
+        let plaintext = encoder.encode_u64(&[7, 42, 1234]).unwrap();
+
+        let encryptor = Encryptor::with_secret_key(&ctx, &secret_key).unwrap();
+        let compressed = encryptor.encrypt_symmetric_compressed(&plaintext).unwrap();
+        let uncompressed = encryptor.encrypt_symmetric(&plaintext).unwrap();
+
+        // The seed-compressed form rehydrates into a fully valid ciphertext.
+        let reloaded = Ciphertext::from_bytes(&ctx, &compressed.as_bytes().unwrap()).unwrap();
+
+        let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+        let from_compressed = encoder.decode_u64(&decryptor.decrypt(&reloaded).unwrap());
+        let from_uncompressed = encoder.decode_u64(&decryptor.decrypt(&uncompressed).unwrap());
+        assert_eq!(from_compressed.unwrap(), from_uncompressed.unwrap());
+    }
 }
@@ -0,0 +1,182 @@
+//! Streaming homomorphic aggregation protocol.
+//!
+//! Where [`server`](crate::server) evaluates a one-shot batch of operations,
+//! this protocol keeps a long-lived connection: the client streams encrypted
+//! batches and the server folds them into a running total it never decrypts.
+//! The wire format is a sequence of length-prefixed frames (see
+//! [`unsized_data_send`]):
+//!
+//! 1. a handshake frame carrying the serialized [`EncryptionParameterSet`]
+//!    (which embeds the [`SchemeType`](seal_lib::SchemeType)), so the server can
+//!    rebuild the context without any out-of-band knowledge;
+//! 2. zero or more ciphertext-batch frames, each a `bincode`-encoded
+//!    `Vec<Ciphertext>`;
+//! 3. an empty frame marking end-of-stream, after which the server replies with
+//!    a single frame holding the encrypted running total.
+
+use super::{BINCODE_CONFIG, unsized_data_recv, unsized_data_send};
+use fhe_core::api::CryptoSystem;
+use rayon::prelude::*;
+use seal_lib::context::SealBFVContext;
+use seal_lib::{BfvHOperation2, Ciphertext, EncryptionParameterSet, SealBfvCS};
+use std::io;
+use std::sync::Mutex;
+use tokio::net::TcpStream;
+
+fn decode_error(e: impl core::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+/// Folds a batch of ciphertexts into their homomorphic sum, fanning the
+/// additions across the rayon pool.
+fn sum_batch(cs: &SealBfvCS, batch: &[Ciphertext]) -> Ciphertext {
+    batch
+        .par_iter()
+        .cloned()
+        .reduce(|| cs.cipher(&0u64), |acc, c| {
+            cs.operate2(BfvHOperation2::Add, &acc, &c)
+        })
+}
+
+/// Server side of the streaming aggregation protocol.
+#[allow(async_fn_in_trait)]
+pub trait AggregationServer {
+    /// Accumulates every batch a client streams and sends the encrypted total
+    /// back to it, without decrypting anything.
+    async fn aggregate(&self, stream: &mut TcpStream) -> io::Result<()>;
+}
+
+/// Client side of the streaming aggregation protocol.
+#[allow(async_fn_in_trait)]
+pub trait AggregationClient {
+    /// Performs the handshake, streams `batches`, and returns the encrypted
+    /// running total the server computed.
+    async fn stream_and_collect(
+        &self,
+        batches: &[Vec<Ciphertext>],
+        stream: &mut TcpStream,
+    ) -> io::Result<Ciphertext>;
+}
+
+/// A BFV instantiation of the streaming aggregation protocol.
+pub struct BfvAggregationService {
+    context: SealBFVContext,
+}
+
+impl BfvAggregationService {
+    #[must_use]
+    /// Creates a service bound to a freshly built BFV context.
+    pub fn new(degree: seal_lib::DegreeType, sl: seal_lib::SecurityLevel, bit_size: u32) -> Self {
+        Self {
+            context: SealBFVContext::new(degree, sl, bit_size),
+        }
+    }
+
+    #[must_use]
+    /// The parameter set this service hands to clients on the handshake.
+    pub fn parameter_set(&self) -> EncryptionParameterSet {
+        self.context.parameter_set()
+    }
+}
+
+impl AggregationServer for BfvAggregationService {
+    async fn aggregate(&self, stream: &mut TcpStream) -> io::Result<()> {
+        // Handshake: rebuild the client's context from the advertised parameters.
+        let handshake = unsized_data_recv(stream).await?;
+        let (params, _): (EncryptionParameterSet, usize) =
+            bincode::decode_from_slice(&handshake, BINCODE_CONFIG).map_err(decode_error)?;
+        let context = SealBFVContext::from_parameter_set(&params);
+        let cs = SealBfvCS::new(&context);
+
+        // Running total, combined under a mutex as each batch's partial sum lands.
+        let total = Mutex::new(cs.cipher(&0u64));
+        let start = std::time::Instant::now();
+
+        loop {
+            let frame = unsized_data_recv(stream).await?;
+            if frame.is_empty() {
+                break; // end-of-stream marker
+            }
+            let (batch, _): (Vec<Ciphertext>, usize) =
+                bincode::decode_from_slice_with_context(&frame, BINCODE_CONFIG, context.clone())
+                    .map_err(decode_error)?;
+
+            let partial = sum_batch(&cs, &batch);
+            let mut guard = total.lock().unwrap();
+            *guard = cs.operate2(BfvHOperation2::Add, &guard, &partial);
+        }
+
+        let total = total.into_inner().unwrap();
+        log::info!("Aggregated stream in {:?}", start.elapsed());
+
+        let bytes = bincode::encode_to_vec(total, BINCODE_CONFIG).map_err(decode_error)?;
+        unsized_data_send(bytes, stream).await
+    }
+}
+
+impl AggregationClient for BfvAggregationService {
+    async fn stream_and_collect(
+        &self,
+        batches: &[Vec<Ciphertext>],
+        stream: &mut TcpStream,
+    ) -> io::Result<Ciphertext> {
+        let handshake =
+            bincode::encode_to_vec(self.parameter_set(), BINCODE_CONFIG).map_err(decode_error)?;
+        unsized_data_send(handshake, stream).await?;
+
+        for batch in batches {
+            let bytes = bincode::encode_to_vec(batch, BINCODE_CONFIG).map_err(decode_error)?;
+            unsized_data_send(bytes, stream).await?;
+        }
+        // Empty frame closes the stream.
+        unsized_data_send(Vec::new(), stream).await?;
+
+        let resp = unsized_data_recv(stream).await?;
+        let (total, _): (Ciphertext, usize) =
+            bincode::decode_from_slice_with_context(&resp, BINCODE_CONFIG, self.context.clone())
+                .map_err(decode_error)?;
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn streamed_total_matches_plaintext_sum() {
+        let service = BfvAggregationService::new(
+            seal_lib::DegreeType::D4096,
+            seal_lib::SecurityLevel::TC128,
+            16,
+        );
+        let cs = SealBfvCS::new(&service.context);
+
+        // Two batches whose plaintext sum is known.
+        let values = [[1u64, 2, 3], [4, 5, 6]];
+        let batches: Vec<Vec<Ciphertext>> = values
+            .iter()
+            .map(|b| b.iter().map(|v| cs.cipher(v)).collect())
+            .collect();
+        let expected: u64 = values.iter().flatten().sum();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = BfvAggregationService::new(
+            seal_lib::DegreeType::D4096,
+            seal_lib::SecurityLevel::TC128,
+            16,
+        );
+        let server_task = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            server.aggregate(&mut stream).await.unwrap();
+        });
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let total = service.stream_and_collect(&batches, &mut stream).await.unwrap();
+        server_task.await.unwrap();
+
+        assert_eq!(cs.decipher(&total), expected);
+    }
+}
@@ -1,45 +1,172 @@
+use serde::Deserialize;
+use std::env;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
-use toml::Table;
 
-#[derive(Debug)]
+/// The FHE scheme a client selects via `[scheme] kind = "..."`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scheme {
+    /// The BFV exact-integer scheme.
+    Bfv,
+    /// The BGV exact-integer scheme.
+    Bgv,
+    /// The CKKS approximate-arithmetic scheme.
+    Ckks,
+}
+
+impl std::str::FromStr for Scheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "bfv" => Ok(Self::Bfv),
+            "bgv" => Ok(Self::Bgv),
+            "ckks" => Ok(Self::Ckks),
+            other => Err(format!("expected one of bfv/bgv/ckks, got `{other}`")),
+        }
+    }
+}
+
+/// The `[scheme]` section of a client configuration file: which FHE scheme to
+/// stand up and the parameters it needs.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct SchemeSection {
+    /// Which scheme to use.
+    pub kind: Scheme,
+    /// The polynomial ring dimension (must be a power of two).
+    pub ring_dimension: u32,
+    /// Depth of the modulus chain, i.e. how many multiplications/rescales the
+    /// parameters must support (must be at least `1`).
+    pub depth: u32,
+    /// CKKS encoding scale; ignored by BFV/BGV.
+    pub scale: f64,
+}
+
+impl Default for SchemeSection {
+    /// BFV at a `D8192` ring dimension with a modulus chain one level deep.
+    fn default() -> Self {
+        Self {
+            kind: Scheme::Bfv,
+            ring_dimension: 8192,
+            depth: 1,
+            scale: (1u64 << 40) as f64,
+        }
+    }
+}
+
+/// What the TOML file deserializes into before environment overrides and
+/// validation are applied.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct RawConfig {
+    data: Option<PathBuf>,
+    scheme: SchemeSection,
+}
+
+#[derive(Debug, Clone)]
+/// A fully resolved, validated client configuration: defaults, overridden by
+/// the TOML file, overridden again by environment variables.
 pub struct ClientConfig {
     data: PathBuf,
+    scheme: SchemeSection,
 }
 
 #[derive(Error, Debug)]
 pub enum ConfigError {
-    #[error("Failed to load configuration file: {0}")]
-    LoadError(#[from] tokio::io::Error),
-    #[error("Failed to parse configuration file: {0}")]
-    ParseError(#[from] toml::de::Error),
-    #[error("Missing key in configuration file: {0}")]
-    MissingKey(&'static str),
-    #[error("Invalid value in configuration file: {0}")]
-    InvalidValue(&'static str),
+    #[error("failed to load configuration file: {0}")]
+    Load(#[from] tokio::io::Error),
+    #[error("failed to parse configuration file: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("configuration is missing the required `data` key")]
+    MissingData,
+    #[error("environment variable {name} has an invalid value {value:?}: {reason}")]
+    InvalidEnv {
+        name: &'static str,
+        value: String,
+        reason: String,
+    },
+    #[error("ring dimension must be a power of two, got {0}")]
+    RingDimensionNotPowerOfTwo(u32),
+    #[error("modulus chain depth must be at least 1, got {0}")]
+    DepthTooSmall(u32),
+}
+
+/// Parses an environment variable with `parse`, reporting a precise
+/// [`ConfigError::InvalidEnv`] on failure instead of silently ignoring it.
+fn parse_env<T, E>(name: &'static str, parse: impl Fn(&str) -> Result<T, E>) -> Result<Option<T>, ConfigError>
+where
+    E: std::fmt::Display,
+{
+    match env::var(name) {
+        Ok(value) => parse(&value)
+            .map(Some)
+            .map_err(|e| ConfigError::InvalidEnv {
+                name,
+                value,
+                reason: e.to_string(),
+            }),
+        Err(env::VarError::NotPresent) => Ok(None),
+        Err(env::VarError::NotUnicode(value)) => Err(ConfigError::InvalidEnv {
+            name,
+            value: value.to_string_lossy().into_owned(),
+            reason: "not valid Unicode".to_owned(),
+        }),
+    }
 }
 
 impl ClientConfig {
+    /// Loads a client configuration: start from [`SchemeSection::default`],
+    /// apply the TOML file, then apply environment-variable overrides
+    /// (`FHE_BPCE_SCHEME`, `FHE_BPCE_RING_DIM`, `FHE_BPCE_DEPTH`,
+    /// `FHE_BPCE_SCALE`, `FHE_BPCE_DATA`), and finally validate the merged
+    /// result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, if `data` is
+    /// missing, if an override environment variable has an invalid value, or
+    /// if the merged parameters fail validation.
     pub async fn load_config(config_file: &Path) -> Result<Self, ConfigError> {
-        let file = tokio::fs::read(config_file)
-            .await
-            .map_err(ConfigError::LoadError)?;
+        let file = tokio::fs::read(config_file).await?;
         let str_file = std::str::from_utf8(&file).map_err(|e| {
-            ConfigError::LoadError(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+            ConfigError::Load(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
         })?;
 
-        let table = str_file.parse::<Table>().map_err(ConfigError::ParseError)?;
+        let mut raw: RawConfig = toml::from_str(str_file)?;
+
+        if let Some(data) = parse_env("FHE_BPCE_DATA", |s| Ok::<_, std::convert::Infallible>(PathBuf::from(s)))? {
+            raw.data = Some(data);
+        }
+        if let Some(kind) = parse_env("FHE_BPCE_SCHEME", str::parse::<Scheme>)? {
+            raw.scheme.kind = kind;
+        }
+        if let Some(ring_dimension) = parse_env("FHE_BPCE_RING_DIM", str::parse::<u32>)? {
+            raw.scheme.ring_dimension = ring_dimension;
+        }
+        if let Some(depth) = parse_env("FHE_BPCE_DEPTH", str::parse::<u32>)? {
+            raw.scheme.depth = depth;
+        }
+        if let Some(scale) = parse_env("FHE_BPCE_SCALE", str::parse::<f64>)? {
+            raw.scheme.scale = scale;
+        }
 
-        #[allow(clippy::disallowed_names)] // Test!
-        let data = table
-            .get("data")
-            .ok_or(ConfigError::MissingKey("data"))?
-            .as_str()
-            .ok_or(ConfigError::InvalidValue("data"))?
-            .to_string()
-            .into();
+        let data = raw.data.ok_or(ConfigError::MissingData)?;
 
-        Ok(Self { data })
+        if !raw.scheme.ring_dimension.is_power_of_two() {
+            return Err(ConfigError::RingDimensionNotPowerOfTwo(
+                raw.scheme.ring_dimension,
+            ));
+        }
+        if raw.scheme.depth < 1 {
+            return Err(ConfigError::DepthTooSmall(raw.scheme.depth));
+        }
+
+        Ok(Self {
+            data,
+            scheme: raw.scheme,
+        })
     }
 
     #[must_use]
@@ -48,4 +175,11 @@ impl ClientConfig {
     pub fn data(&self) -> &Path {
         &self.data
     }
+
+    #[must_use]
+    #[inline]
+    /// The resolved `[scheme]` section.
+    pub const fn scheme(&self) -> &SchemeSection {
+        &self.scheme
+    }
 }
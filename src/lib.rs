@@ -12,9 +12,12 @@ use std::path::PathBuf;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::{TcpListener, TcpStream};
 
+pub mod aggregate;
 mod client;
 mod load;
+pub mod prio;
 mod server;
+pub mod transport;
 
 const BINCODE_CONFIG: bincode::config::Configuration = bincode::config::standard();
 
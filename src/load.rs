@@ -1,6 +1,7 @@
 //! Data loading utilities.
 #![allow(dead_code)]
 
+pub mod aggregate;
 pub mod csv;
 pub mod json;
 #[cfg(feature = "parquet")]
@@ -19,6 +20,11 @@ pub enum DataError {
     Parsing,
     #[error("Unsupported format")]
     UnsupportedFormat,
+    #[error("payload exceeds the configured {limit}-byte limit")]
+    TooLarge {
+        /// The configured byte limit the payload overran.
+        limit: u64,
+    },
     #[error("Unknown error")]
     Unknown,
 }
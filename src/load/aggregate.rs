@@ -0,0 +1,69 @@
+//! Loads a single numeric column, one row per client, for Prio-style private
+//! aggregation (see [`fhe_operations::aggregate_ops`]).
+//!
+//! Unlike [`CsvLoader`](super::csv::CsvLoader), which ciphers each row for a
+//! single FHE server to evaluate, this loader never ciphers anything: every
+//! row is split into additive shares scattered across `n_servers`
+//! non-colluding servers before it ever leaves the client.
+
+use csv::Reader;
+use fhe_operations::aggregate_ops::{AggregateOpsData, split_value};
+
+/// Reads one client value per CSV row and splits it into per-server
+/// [`AggregateOpsData`] batches.
+pub struct AggregateLoader {
+    bound: u64,
+    modulus: i64,
+    n_servers: usize,
+}
+
+impl AggregateLoader {
+    #[must_use]
+    /// Builds a loader that rejects any value above `bound` and splits
+    /// accepted values into `n_servers` additive shares over `Z_modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_servers < 2`.
+    pub const fn new(bound: u64, modulus: i64, n_servers: usize) -> Self {
+        assert!(n_servers >= 2, "need at least two non-colluding servers");
+        Self {
+            bound,
+            modulus,
+            n_servers,
+        }
+    }
+
+    /// Reads `file`'s single-column CSV, returning one [`AggregateOpsData`]
+    /// batch per server (in the same order every round), ready to be sent to
+    /// its corresponding server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`super::DataError::Parsing`] if a row is not a single valid
+    /// `u64`, or if a value exceeds the loader's configured bound.
+    pub fn load(&self, file: std::fs::File) -> super::DataResult<Vec<AggregateOpsData>> {
+        let mut rdr = Reader::from_reader(file);
+        let mut batches: Vec<AggregateOpsData> = (0..self.n_servers)
+            .map(|_| AggregateOpsData::new(self.modulus))
+            .collect();
+
+        for result in rdr.records() {
+            let record = result.map_err(|_| super::DataError::Parsing)?;
+            if record.len() != 1 {
+                return Err(super::DataError::Parsing);
+            }
+            let value = record[0]
+                .parse::<u64>()
+                .map_err(|_| super::DataError::Parsing)?;
+
+            let shares = split_value(value, self.bound, self.modulus, self.n_servers)
+                .map_err(|_| super::DataError::Parsing)?;
+            for (batch, share) in batches.iter_mut().zip(shares) {
+                batch.push(share);
+            }
+        }
+
+        Ok(batches)
+    }
+}
@@ -1,23 +1,124 @@
 //! Data stored as raw bytes.
 
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::{DataError, DataResult};
+
+/// Default cap on an eagerly-buffered payload (1 MiB).
 const SIZE_LIMIT: u64 = 1024 * 1024;
 
-pub struct BytesLoader {}
+/// Eagerly reads a whole file into memory, up to a configurable byte limit.
+pub struct BytesLoader {
+    limit: u64,
+}
+
+impl Default for BytesLoader {
+    fn default() -> Self {
+        Self { limit: SIZE_LIMIT }
+    }
+}
 
 async fn get_file_size_hint(file: &tokio::fs::File) -> Option<usize> {
     file.metadata().await.ok()?.len().try_into().ok()
 }
 
-impl super::DataLoader<Vec<u8>> for BytesLoader {
-    async fn load(file: tokio::fs::File) -> super::DataResult<Vec<u8>> {
+impl BytesLoader {
+    /// Builds a loader that rejects payloads larger than `limit` bytes rather
+    /// than silently truncating them.
+    #[must_use]
+    pub const fn with_limit(limit: u64) -> Self {
+        Self { limit }
+    }
+
+    /// Reads the whole file, returning [`DataError::TooLarge`] if it exceeds the
+    /// configured limit instead of capping the result.
+    pub async fn load(&self, file: tokio::fs::File) -> DataResult<Vec<u8>> {
         const DEFAULT_VEC_SIZE: usize = 100;
 
         let mut buffer =
             Vec::with_capacity(get_file_size_hint(&file).await.unwrap_or(DEFAULT_VEC_SIZE));
 
-        file.take(SIZE_LIMIT).read_to_end(&mut buffer).await?;
+        // Read one byte past the limit so an overrun is detected rather than
+        // silently clipped at the boundary.
+        let read = file
+            .take(self.limit + 1)
+            .read_to_end(&mut buffer)
+            .await?;
+
+        if read as u64 > self.limit {
+            return Err(DataError::TooLarge { limit: self.limit });
+        }
 
         Ok(buffer)
     }
 }
+
+impl super::DataLoader<Vec<u8>> for BytesLoader {
+    async fn load(file: tokio::fs::File) -> DataResult<Vec<u8>> {
+        Self::default().load(file).await
+    }
+}
+
+/// Streaming reader that yields fixed-size frames on demand.
+///
+/// Unlike [`BytesLoader`], this never buffers the whole payload: it pulls
+/// bounded chunks from any async reader so a large `Ciphertext`/`KeySwitchKey`
+/// file can be fed incrementally into a `FromBytes` decoder. [`bytes_read`]
+/// tracks how many bytes have been consumed, mirroring the `bytes_read`
+/// out-parameter SEAL's `*_Load` functions report, so deserialization can
+/// resume across chunk boundaries.
+///
+/// [`bytes_read`]: ChunkReader::bytes_read
+pub struct ChunkReader<R> {
+    reader: R,
+    chunk_size: usize,
+    consumed: u64,
+}
+
+impl<R: AsyncRead + Unpin> ChunkReader<R> {
+    /// Wraps `reader`, yielding frames of at most `chunk_size` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    #[must_use]
+    pub fn new(reader: R, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be non-zero");
+        Self {
+            reader,
+            chunk_size,
+            consumed: 0,
+        }
+    }
+
+    /// Total number of bytes pulled from the underlying reader so far.
+    #[must_use]
+    pub const fn bytes_read(&self) -> u64 {
+        self.consumed
+    }
+
+    /// Pulls the next frame, or `Ok(None)` at end of stream.
+    ///
+    /// A returned frame is exactly `chunk_size` bytes except for the final one,
+    /// which carries whatever remains.
+    pub async fn next_chunk(&mut self) -> DataResult<Option<Vec<u8>>> {
+        let mut buffer = vec![0u8; self.chunk_size];
+        let mut filled = 0;
+
+        while filled < self.chunk_size {
+            let n = self.reader.read(&mut buffer[filled..]).await?;
+            if n == 0 {
+                break;
+            }
+            filled += n;
+        }
+
+        if filled == 0 {
+            return Ok(None);
+        }
+
+        buffer.truncate(filled);
+        self.consumed += filled as u64;
+        Ok(Some(buffer))
+    }
+}
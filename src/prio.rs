@@ -0,0 +1,130 @@
+//! Prio-style private-sum protocol.
+//!
+//! Where [`aggregate`](crate::aggregate) folds a *single* server's stream of
+//! ciphertexts into a homomorphic running total, this protocol spreads trust
+//! across `n` non-colluding server instances: clients split each contribution
+//! into additive shares with [`fhe_operations::aggregate_ops::split_value`]
+//! and send one share batch to every shard. Each [`PrioShard`] only ever sees
+//! its own shares, so no shard (nor any `n - 1` of them) learns anything about
+//! an individual contribution; the aggregate only appears once every shard's
+//! [`partial_total`](PrioServer::partial_total) is [`combine`]d out-of-band.
+
+use super::{BINCODE_CONFIG, unsized_data_recv, unsized_data_send};
+use fhe_operations::aggregate_ops::AggregateOpsData;
+use std::io;
+use std::sync::Mutex;
+use tokio::net::TcpStream;
+
+fn decode_error(e: impl core::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+/// One non-colluding server's side of the private-sum protocol.
+#[allow(async_fn_in_trait)]
+pub trait PrioServer {
+    /// Accepts one client's batch of shares and folds it into the running total.
+    async fn accumulate(&self, stream: &mut TcpStream) -> io::Result<()>;
+
+    /// This shard's partial total so far.
+    ///
+    /// Combine every shard's partial total with
+    /// [`combine`](fhe_operations::aggregate_ops::combine) to recover the
+    /// aggregate over every contribution submitted so far.
+    fn partial_total(&self) -> i64;
+}
+
+/// Client side of the private-sum protocol: submits this shard's share batch.
+#[allow(async_fn_in_trait)]
+pub trait PrioClient {
+    /// Sends `batch` to this shard.
+    async fn submit(&self, batch: &AggregateOpsData, stream: &mut TcpStream) -> io::Result<()>;
+}
+
+/// A single non-colluding shard, accumulating the shares clients send it into
+/// a running total it alone cannot decode.
+pub struct PrioShard {
+    modulus: i64,
+    total: Mutex<i64>,
+}
+
+impl PrioShard {
+    #[must_use]
+    /// Creates a shard starting at a zero total over `Z_modulus`.
+    pub const fn new(modulus: i64) -> Self {
+        Self {
+            modulus,
+            total: Mutex::new(0),
+        }
+    }
+}
+
+impl PrioServer for PrioShard {
+    async fn accumulate(&self, stream: &mut TcpStream) -> io::Result<()> {
+        let data = unsized_data_recv(stream).await?;
+        let (batch, _): (AggregateOpsData, usize) =
+            bincode::decode_from_slice(&data, BINCODE_CONFIG).map_err(decode_error)?;
+
+        let mut guard = self.total.lock().unwrap();
+        *guard = (*guard + batch.aggregate()).rem_euclid(self.modulus);
+        Ok(())
+    }
+
+    fn partial_total(&self) -> i64 {
+        *self.total.lock().unwrap()
+    }
+}
+
+impl PrioClient for PrioShard {
+    async fn submit(&self, batch: &AggregateOpsData, stream: &mut TcpStream) -> io::Result<()> {
+        let bytes = bincode::encode_to_vec(batch, BINCODE_CONFIG).map_err(decode_error)?;
+        unsized_data_send(bytes, stream).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fhe_operations::aggregate_ops::{combine, split_value};
+    use tokio::net::TcpListener;
+
+    const MODULUS: i64 = 100_000_007;
+
+    #[tokio::test]
+    async fn shards_combine_to_the_submitted_values() {
+        let values = [7u64, 13, 25];
+        let n_servers = 3;
+
+        // One shard batch per server, gathering every client's share for it.
+        let mut batches: Vec<AggregateOpsData> =
+            (0..n_servers).map(|_| AggregateOpsData::new(MODULUS)).collect();
+        for &value in &values {
+            let shares = split_value(value, 1000, MODULUS, n_servers).unwrap();
+            for (batch, share) in batches.iter_mut().zip(shares) {
+                batch.push(share);
+            }
+        }
+
+        let mut totals = Vec::with_capacity(n_servers);
+        for batch in batches {
+            let shard = PrioShard::new(MODULUS);
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+
+            let server_task = tokio::spawn(async move {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                shard.accumulate(&mut stream).await.unwrap();
+                shard.partial_total()
+            });
+
+            let client = PrioShard::new(MODULUS);
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            client.submit(&batch, &mut stream).await.unwrap();
+            drop(stream);
+
+            totals.push(server_task.await.unwrap());
+        }
+
+        let expected: u64 = values.iter().sum();
+        assert_eq!(combine(&totals, MODULUS), expected as i64);
+    }
+}
@@ -1,55 +1,166 @@
-use super::{unsized_data_recv, unsized_data_send};
+use super::{BINCODE_CONFIG, unsized_data_recv, unsized_data_send};
 use fhe_core::api::CryptoSystem;
 use fhe_operations::seq_ops::SeqOpsData;
 use rayon::prelude::*;
-use seal_lib::{SealBfvCS, context::SealBFVContext};
+use seal_lib::context::SealBFVContext;
+use seal_lib::{Ciphertext, SealBfvCS};
+use std::io;
 use tokio::net::TcpStream;
 
+/// Evaluates every queued binary operation, returning the ciphertexts in the
+/// same order as the input batch.
+///
+/// This replaces the earlier `par_bridge` pipeline, whose work-stealing split
+/// returned results in nondeterministic order: collecting into an indexed
+/// `Vec` first lets rayon's `par_iter` preserve input order on the way out.
+#[must_use]
+pub fn operate_ordered<C>(cs: &C, data: &SeqOpsData<C>) -> Vec<C::Ciphertext>
+where
+    C: CryptoSystem + Sync,
+    C::Ciphertext: bincode::Encode + Send + Sync,
+    C::Operation2: bincode::Encode + Copy + Sync,
+{
+    let items: Vec<&_> = data.iter_over_data().collect();
+    items.par_iter().map(|item| item.execute(cs)).collect()
+}
+
+/// Server side of the batch-operation protocol: decode a `SeqOpsData<C>`,
+/// evaluate it in order, and send the ciphertexts back.
+#[allow(async_fn_in_trait)]
+pub trait OperationServer<C: CryptoSystem> {
+    /// Serves a single client request on `stream`.
+    async fn serve(&self, stream: &mut TcpStream) -> io::Result<()>;
+}
+
+/// Client side of the batch-operation protocol: submit a batch and collect the
+/// server's ordered ciphertext replies.
+#[allow(async_fn_in_trait)]
+pub trait OperationClient<C: CryptoSystem> {
+    /// Sends `ops` and returns the server's replies, one per input operation.
+    async fn send_and_confirm(
+        &self,
+        ops: SeqOpsData<C>,
+        stream: &mut TcpStream,
+    ) -> io::Result<Vec<C::Ciphertext>>;
+}
+
+/// A BFV instantiation of the batch-operation protocol over a shared context.
+pub struct BfvOperationService {
+    context: SealBFVContext,
+}
+
+impl BfvOperationService {
+    #[must_use]
+    /// Creates a service bound to a freshly built BFV context.
+    pub fn new(degree: seal_lib::DegreeType, sl: seal_lib::SecurityLevel, bit_size: u32) -> Self {
+        Self {
+            context: SealBFVContext::new(degree, sl, bit_size),
+        }
+    }
+}
+
+fn decode_error(e: impl core::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+impl OperationServer<SealBfvCS> for BfvOperationService {
+    async fn serve(&self, stream: &mut TcpStream) -> io::Result<()> {
+        let data = unsized_data_recv(stream).await?;
+
+        let (exch_data, _): (SeqOpsData<SealBfvCS>, usize) =
+            bincode::decode_from_slice_with_context(&data, BINCODE_CONFIG, self.context.clone())
+                .map_err(decode_error)?;
+
+        log::info!(
+            "Operating on {} data pairs with {} threads",
+            exch_data.len(),
+            rayon::current_num_threads()
+        );
+
+        let start = std::time::Instant::now();
+        let cs = SealBfvCS::new(&self.context);
+        let results = operate_ordered(&cs, &exch_data);
+        log::info!("Data processed in {:?}", start.elapsed());
+
+        let bytes = bincode::encode_to_vec(results, BINCODE_CONFIG).map_err(decode_error)?;
+        unsized_data_send(bytes, stream).await
+    }
+}
+
+impl OperationClient<SealBfvCS> for BfvOperationService {
+    async fn send_and_confirm(
+        &self,
+        ops: SeqOpsData<SealBfvCS>,
+        stream: &mut TcpStream,
+    ) -> io::Result<Vec<Ciphertext>> {
+        let bytes = bincode::encode_to_vec(ops, BINCODE_CONFIG).map_err(decode_error)?;
+        unsized_data_send(bytes, stream).await?;
+
+        let resp = unsized_data_recv(stream).await?;
+        let (results, _): (Vec<Ciphertext>, usize) =
+            bincode::decode_from_slice_with_context(&resp, BINCODE_CONFIG, self.context.clone())
+                .map_err(decode_error)?;
+        Ok(results)
+    }
+}
+
 pub async fn handle_client(mut stream: TcpStream) {
-    let bfv_ctx = SealBFVContext::new(
+    let service = BfvOperationService::new(
         seal_lib::DegreeType::D4096,
         seal_lib::SecurityLevel::TC128,
         16,
     );
-    let bfv_cs = SealBfvCS::new(&bfv_ctx);
-
-    let Ok(data) = unsized_data_recv(&mut stream).await else {
-        log::error!("Failed to receive data from client");
-        return;
-    };
-
-    let Ok(exch_data) =
-        bincode::decode_from_slice_with_context(&data, super::BINCODE_CONFIG, bfv_ctx)
-    else {
-        log::error!("Failed to decode data from client");
-        return;
-    };
-
-    let exch_data: SeqOpsData<SealBfvCS> = exch_data.0;
-
-    log::info!(
-        "Operating on {} data pairs with {} threads",
-        exch_data.len(),
-        rayon::current_num_threads()
-    );
 
-    let start = std::time::Instant::now();
+    if let Err(e) = service.serve(&mut stream).await {
+        log::error!("Failed to serve client: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fhe_operations::seq_ops::SeqOpItem;
+    use seal_lib::BfvHOperation2;
+    use tokio::net::TcpListener;
 
-    let results = exch_data
-        .iter_over_data()
-        .par_bridge() // FIXME: Results are unordered
-        .map(|item| bfv_cs.operate2(*item.op(), item.lhs(), item.rhs()))
-        .collect::<Vec<_>>();
+    #[tokio::test]
+    async fn results_preserve_input_order() {
+        let service = BfvOperationService::new(
+            seal_lib::DegreeType::D4096,
+            seal_lib::SecurityLevel::TC128,
+            16,
+        );
+        let cs = SealBfvCS::new(&service.context);
 
-    log::info!("Data processed in {:?}", start.elapsed());
+        // Distinct left operands so an out-of-order reply would be detectable.
+        let lefts = [1u64, 2, 3, 4, 5];
+        let mut ops = SeqOpsData::<SealBfvCS>::new();
+        for &l in &lefts {
+            ops.push(SeqOpItem::new(
+                cs.cipher(&l),
+                cs.cipher(&10),
+                BfvHOperation2::Add,
+            ));
+        }
 
-    let bytes = bincode::encode_to_vec(results, super::BINCODE_CONFIG).unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
 
-    log::info!("Sending data back to client");
+        let server = BfvOperationService::new(
+            seal_lib::DegreeType::D4096,
+            seal_lib::SecurityLevel::TC128,
+            16,
+        );
+        let server_task = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            server.serve(&mut stream).await.unwrap();
+        });
 
-    let send_res = unsized_data_send(bytes, &mut stream).await;
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let results = service.send_and_confirm(ops, &mut stream).await.unwrap();
+        server_task.await.unwrap();
 
-    if let Err(e) = send_res {
-        log::error!("Failed to send data back to client: {e}");
+        let decrypted: Vec<u64> = results.iter().map(|c| cs.decipher(c)).collect();
+        assert_eq!(decrypted, lefts.iter().map(|&l| l + 10).collect::<Vec<_>>());
     }
 }